@@ -158,6 +158,21 @@ impl App {
             panic!("App::update() was called while a plugin was building.");
         }
 
+        #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+        {
+            bevy_ecs::schedule::PanicContext::clear();
+            crate::panic_handler::prepare_crash_report(self.world());
+        }
+        // The panic hooks run once, before unwinding: `resume_unwind` doesn't call them again and
+        // propagates the same payload. With `panic = "abort"`, nothing is caught and the process
+        // aborts as before.
+        #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+        if let Err(payload) = catch_unwind(AssertUnwindSafe(|| self.sub_apps.update())) {
+            crate::panic_handler::write_crash_report(self.world(), payload.as_ref());
+            resume_unwind(payload);
+        }
+
+        #[cfg(any(not(feature = "std"), target_arch = "wasm32"))]
         self.sub_apps.update();
     }
 
@@ -8,12 +8,42 @@
 
 use crate::{App, Plugin};
 
+#[cfg(feature = "std")]
+use {
+    alloc::{format, string::String, vec::Vec},
+    bevy_ecs::{resource::Resource, schedule::PanicContext, world::World},
+    core::{
+        any::Any,
+        fmt::Write as _,
+        sync::atomic::{AtomicBool, Ordering},
+    },
+    std::{backtrace::Backtrace, eprintln, path::PathBuf, sync::Mutex},
+};
+
 /// Adds sensible panic handlers to Apps. This plugin is part of the `DefaultPlugins`. Adding
 /// this plugin will setup a panic hook appropriate to your target platform:
 /// * On Wasm, uses [`console_error_panic_hook`](https://crates.io/crates/console_error_panic_hook), logging
 ///   to the browser console.
 /// * Other platforms are currently not setup.
 ///
+/// On other platforms than Wasm, it can also write a crash report when a panic happens during
+/// [`App::update`], if a directory is set in the [`CrashReportSettings`]:
+///
+/// ```no_run
+/// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, CrashReportSettings};
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .insert_resource(CrashReportSettings {
+///         directory: Some("crash_reports".into()),
+///         ..Default::default()
+///     })
+///     .run();
+/// ```
+///
+/// The report contains the panic message and backtrace, the schedule and system that were
+/// running, and the sections added with [`App::add_crash_report_section`], like the recent log
+/// lines, the current states, the system information and the frame count when their plugins
+/// are added.
 /// ```no_run
 /// # use bevy_app::{App, NoopPluginGroup as MinimalPlugins, PluginGroup, PanicHandlerPlugin};
 /// fn main() {
@@ -41,6 +71,9 @@ impl Plugin for PanicHandlerPlugin {
     fn build(&self, _app: &mut App) {
         #[cfg(feature = "std")]
         {
+            _app.init_resource::<CrashReportSettings>()
+                .init_resource::<CrashReportSections>();
+
             static SET_HOOK: std::sync::Once = std::sync::Once::new();
             SET_HOOK.call_once(|| {
                 cfg_if::cfg_if! {
@@ -55,7 +88,294 @@ impl Plugin for PanicHandlerPlugin {
                     }
                     // Otherwise use the default target panic hook - Do nothing.
                 }
+
+                // Remember the message and backtrace of the panic for the crash report. The
+                // backtrace is only captured if crash reports are enabled, and only resolved if a
+                // report is written.
+                let current_hook = std::panic::take_hook();
+                std::panic::set_hook(alloc::boxed::Box::new(move |info| {
+                    let record = PanicRecord {
+                        message: String::from(info.payload_as_str().unwrap_or("Box<dyn Any>")),
+                        location: info.location().map(|location| format!("{location}")),
+                        thread: std::thread::current().name().map(String::from),
+                        backtrace: CAPTURE_BACKTRACE
+                            .load(Ordering::Relaxed)
+                            .then(Backtrace::force_capture),
+                    };
+                    *LAST_PANIC.lock().unwrap_or_else(std::sync::PoisonError::into_inner) =
+                        Some(record);
+                    current_hook(info);
+                }));
             });
         }
     }
 }
+
+/// The settings of the crash reports written by the [`PanicHandlerPlugin`].
+#[cfg(feature = "std")]
+#[derive(Resource, Clone, Debug)]
+pub struct CrashReportSettings {
+    /// The directory the crash reports are written to, created if needed.
+    ///
+    /// No crash report is written if this is `None`, the default. No report is written either
+    /// when panics abort the process, with `panic = "abort"`.
+    pub directory: Option<PathBuf>,
+    /// The number of most recent log lines included in the crash reports, if they are captured.
+    ///
+    /// Defaults to 100.
+    pub log_lines: usize,
+}
+
+#[cfg(feature = "std")]
+impl Default for CrashReportSettings {
+    fn default() -> Self {
+        Self {
+            directory: None,
+            log_lines: 100,
+        }
+    }
+}
+
+/// Writes a section of a crash report from the [`World`] of the crashed app, or returns `None`
+/// to leave it out.
+#[cfg(feature = "std")]
+pub type CrashReportSection = fn(world: &World) -> Option<String>;
+
+/// The sections of the crash reports, added with [`App::add_crash_report_section`].
+#[cfg(feature = "std")]
+#[derive(Resource, Default)]
+pub struct CrashReportSections {
+    sections: Vec<(String, CrashReportSection)>,
+}
+
+#[cfg(feature = "std")]
+impl CrashReportSections {
+    /// Adds a section titled `title`, replacing any section with the same title.
+    pub fn add(&mut self, title: impl Into<String>, section: CrashReportSection) {
+        let title = title.into();
+        self.sections.retain(|(existing, _)| *existing != title);
+        self.sections.push((title, section));
+    }
+
+    /// Iterates over the titles and writers of the sections, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, CrashReportSection)> {
+        self.sections
+            .iter()
+            .map(|(title, section)| (title.as_str(), *section))
+    }
+}
+
+#[cfg(feature = "std")]
+impl App {
+    /// Adds a section to the crash reports written by the [`PanicHandlerPlugin`].
+    ///
+    /// The `section` reads the [`World`] of the crashed app, which may have been left in the
+    /// middle of a frame.
+    pub fn add_crash_report_section(
+        &mut self,
+        title: impl Into<String>,
+        section: CrashReportSection,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<CrashReportSections>()
+            .add(title, section);
+        self
+    }
+}
+
+/// The last panic seen by the panic hook of the [`PanicHandlerPlugin`].
+#[cfg(feature = "std")]
+struct PanicRecord {
+    message: String,
+    location: Option<String>,
+    thread: Option<String>,
+    /// The unresolved backtrace, only formatted when writing a crash report.
+    backtrace: Option<Backtrace>,
+}
+
+#[cfg(feature = "std")]
+static LAST_PANIC: Mutex<Option<PanicRecord>> = Mutex::new(None);
+
+/// Whether the panic hook captures a backtrace, which is slow, updated by [`App::update`] when
+/// the app has [`CrashReportSettings`].
+#[cfg(feature = "std")]
+static CAPTURE_BACKTRACE: AtomicBool = AtomicBool::new(false);
+
+/// Makes the panic hook capture backtraces only if the [`CrashReportSettings`] of the `world`
+/// have a directory, before [`App::update`] runs the schedules.
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub(crate) fn prepare_crash_report(world: &World) {
+    if let Some(settings) = world.get_resource::<CrashReportSettings>() {
+        CAPTURE_BACKTRACE.store(settings.directory.is_some(), Ordering::Relaxed);
+    }
+}
+
+/// Writes a crash report for the panic that unwound out of [`App::update`] with `payload`, if
+/// the [`CrashReportSettings`] of the `world` have a directory.
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+#[expect(clippy::print_stderr, reason = "Allowed while crashing.")]
+pub(crate) fn write_crash_report(world: &World, payload: &(dyn Any + Send)) {
+    let context = PanicContext::take();
+    let record = LAST_PANIC
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    let Some(directory) = world
+        .get_resource::<CrashReportSettings>()
+        .and_then(|settings| settings.directory.clone())
+    else {
+        return;
+    };
+
+    let report = crash_report(world, payload, &context, record.as_ref());
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    let path = directory.join(format!("crash_{timestamp}_{}.txt", std::process::id()));
+    match std::fs::create_dir_all(&directory).and_then(|()| std::fs::write(&path, report)) {
+        Ok(()) => eprintln!("Wrote a crash report to {}", path.display()),
+        Err(error) => eprintln!(
+            "Failed to write a crash report to {}: {error}",
+            path.display()
+        ),
+    }
+}
+
+#[cfg(feature = "std")]
+fn crash_report(
+    world: &World,
+    payload: &(dyn Any + Send),
+    context: &PanicContext,
+    record: Option<&PanicRecord>,
+) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|message| String::from(*message))
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("Box<dyn Any>"));
+    // The hook may have seen a panic of another thread since.
+    let record = record.filter(|record| record.message == message);
+    let unknown = || String::from("unknown");
+
+    let mut report = String::from("Crash report\n\n");
+    // Writing to a `String` can't fail.
+    _ = writeln!(report, "Panic: {message}");
+    _ = writeln!(
+        report,
+        "Location: {}",
+        record
+            .and_then(|record| record.location.clone())
+            .unwrap_or_else(unknown)
+    );
+    _ = writeln!(
+        report,
+        "Thread: {}",
+        record
+            .and_then(|record| record.thread.clone())
+            .unwrap_or_else(unknown)
+    );
+    _ = writeln!(
+        report,
+        "Schedule: {}",
+        context.schedule.clone().unwrap_or_else(unknown)
+    );
+    _ = writeln!(
+        report,
+        "System: {}",
+        context.system.clone().unwrap_or_else(unknown)
+    );
+
+    let backtrace = record
+        .and_then(|record| record.backtrace.as_ref())
+        .map_or_else(
+            || String::from("unavailable"),
+            |backtrace| format!("{backtrace}"),
+        );
+    _ = write!(report, "\n## Backtrace\n\n{}\n", backtrace.trim_end());
+
+    if let Some(sections) = world.get_resource::<CrashReportSections>() {
+        for (title, section) in sections.iter() {
+            // A section may panic if the world is in an unexpected state.
+            let content =
+                std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| section(world)));
+            match content {
+                Ok(Some(content)) => {
+                    _ = write!(report, "\n## {title}\n\n{}\n", content.trim_end());
+                }
+                Ok(None) => {}
+                Err(_) => _ = write!(report, "\n## {title}\n\nunavailable\n"),
+            }
+        }
+    }
+    report
+}
+
+#[cfg(all(test, feature = "std", not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::{Last, Update};
+    use bevy_ecs::{prelude::*, system::IntoSystem};
+
+    #[derive(Resource)]
+    struct Level(u32);
+
+    fn crashing_system(level: Res<Level>) {
+        if level.0 == 3 {
+            panic!("level {} is broken", level.0);
+        }
+    }
+
+    #[test]
+    fn crash_report() {
+        let directory =
+            std::env::temp_dir().join(format!("bevy_crash_report_test_{}", std::process::id()));
+        let mut app = App::new();
+        app.add_plugins(PanicHandlerPlugin)
+            .insert_resource(CrashReportSettings {
+                directory: Some(directory.clone()),
+                ..Default::default()
+            })
+            .insert_resource(Level(3))
+            .add_crash_report_section("Level", |world| {
+                world
+                    .get_resource::<Level>()
+                    .map(|level| format!("current level: {}", level.0))
+            })
+            .add_crash_report_section("Missing", |_| None)
+            .add_systems(Update, crashing_system);
+
+        // A panic caught outside of `App::update` isn't reported for the next one.
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            let mut schedule = Schedule::new(Last);
+            schedule.add_systems(|| panic!("caught"));
+            schedule.run(app.world_mut());
+        }));
+        assert!(result.is_err());
+
+        // The panic propagates out of `App::update` unchanged.
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| app.update()));
+        assert_eq!(
+            result.unwrap_err().downcast_ref::<String>().unwrap(),
+            "level 3 is broken"
+        );
+
+        let reports: Vec<_> = std::fs::read_dir(&directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        assert_eq!(reports.len(), 1);
+        let report = std::fs::read_to_string(&reports[0]).unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        let system_name = IntoSystem::into_system(crashing_system).name();
+        assert!(report.contains("Panic: level 3 is broken\n"));
+        assert!(report.contains("Location: crates/bevy_app/src/panic_handler.rs:"));
+        assert!(report.contains("Schedule: Update\n"));
+        assert!(report.contains(&format!("System: {system_name}\n")));
+        assert!(report.contains("## Backtrace\n"));
+        assert!(!report.contains("## Backtrace\n\nunavailable"));
+        assert!(report.contains("## Level\n\ncurrent level: 3\n"));
+        assert!(!report.contains("## Missing"));
+    }
+}
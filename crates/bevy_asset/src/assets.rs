@@ -507,6 +507,17 @@ impl<A: Asset> Assets<A> {
         self.dense_storage.len() + self.hash_map.len()
     }
 
+    /// Returns an estimate of the number of bytes allocated for the assets of the collection.
+    ///
+    /// This doesn't include the memory owned by the assets themselves, such as the contents of
+    /// their `Vec`s.
+    pub fn allocated_bytes(&self) -> usize {
+        self.dense_storage.storage.capacity() * size_of::<Entry<A>>()
+            + self.hash_map.capacity() * size_of::<(Uuid, A)>()
+            + self.queued_events.capacity() * size_of::<AssetEvent<A>>()
+            + self.duplicate_handles.capacity() * size_of::<(AssetId<A>, u16)>()
+    }
+
     /// Returns an iterator over the [`AssetId`] of every [`Asset`] stored in this collection.
     pub fn ids(&self) -> impl Iterator<Item = AssetId<A>> + '_ {
         self.dense_storage
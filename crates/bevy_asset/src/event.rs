@@ -1,4 +1,4 @@
-use crate::{Asset, AssetId, AssetLoadError, AssetPath, UntypedAssetId};
+use crate::{Asset, AssetId, AssetLoadError, AssetPath, AssetSaveError, UntypedAssetId};
 use bevy_ecs::event::{BufferedEvent, Event};
 use bevy_reflect::Reflect;
 use core::fmt::Debug;
@@ -44,6 +44,26 @@ impl<A: Asset> From<&AssetLoadFailedEvent<A>> for UntypedAssetLoadFailedEvent {
     }
 }
 
+/// A [`BufferedEvent`] emitted when an asset was written to its path by [`AssetServer::save`](crate::AssetServer::save).
+#[derive(Event, BufferedEvent, Clone, Debug)]
+pub struct AssetSavedEvent {
+    /// The stable identifier of the asset that was saved.
+    pub id: UntypedAssetId,
+    /// The asset path the asset was written to.
+    pub path: AssetPath<'static>,
+}
+
+/// A [`BufferedEvent`] emitted when [`AssetServer::save`](crate::AssetServer::save) fails to save an asset.
+#[derive(Event, BufferedEvent, Clone, Debug)]
+pub struct AssetSaveFailedEvent {
+    /// The stable identifier of the asset that failed to save.
+    pub id: UntypedAssetId,
+    /// The asset path that was attempted.
+    pub path: AssetPath<'static>,
+    /// Why the asset failed to save.
+    pub error: AssetSaveError,
+}
+
 /// [`BufferedEvent`]s that occur for a specific loaded [`Asset`], such as "value changed" events and "dependency" events.
 #[expect(missing_docs, reason = "Documenting the id fields is unhelpful.")]
 #[derive(Event, BufferedEvent, Reflect)]
@@ -1,11 +1,13 @@
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
+use std::path::{Path, PathBuf};
 
-use crate::{Asset, UntypedHandle};
+use crate::{io::AssetSourceId, Asset, AssetPath, UntypedHandle};
 use bevy_reflect::TypePath;
 
 /// A "loaded folder" containing handles for all assets stored in a given [`AssetPath`].
 ///
-/// This is produced by [`AssetServer::load_folder`](crate::prelude::AssetServer::load_folder).
+/// This is produced by [`AssetServer::load_folder`](crate::prelude::AssetServer::load_folder) and
+/// [`AssetServer::load_glob`](crate::prelude::AssetServer::load_glob).
 ///
 /// [`AssetPath`]: crate::AssetPath
 #[derive(Asset, TypePath)]
@@ -14,3 +16,175 @@ pub struct LoadedFolder {
     #[dependency]
     pub handles: Vec<UntypedHandle>,
 }
+
+/// A glob pattern that selects assets to load with [`AssetServer::load_glob`](crate::AssetServer::load_glob).
+///
+/// The pattern is matched against the path of each asset within its asset source, using `/` as the
+/// separator. The following syntax is supported:
+/// - `?` matches any single character other than `/`.
+/// - `*` matches any sequence of characters other than `/`.
+/// - `**` as a whole path component matches any number of directories, including none.
+///
+/// Like an [`AssetPath`], the pattern may start with an asset source name, such as `remote://**/*.png`.
+///
+/// A glob can additionally require assets to declare a set of tags in their `.meta` file
+/// (see [`AssetMeta::tags`](crate::meta::AssetMeta::tags)). Assets without a `.meta` file have no tags.
+///
+/// ```
+/// # use bevy_asset::AssetGlob;
+/// let glob = AssetGlob::new("textures/**/*.ktx2").with_tag("hd");
+/// assert!(glob.matches("textures/terrain/grass.ktx2"));
+/// assert!(!glob.matches("textures/terrain/grass.png"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AssetGlob {
+    source: AssetSourceId<'static>,
+    pattern: String,
+    tags: Vec<String>,
+}
+
+impl AssetGlob {
+    /// Creates a new [`AssetGlob`] from the given pattern.
+    pub fn new<'a>(pattern: impl Into<AssetPath<'a>>) -> Self {
+        let pattern = pattern.into();
+        Self {
+            source: pattern.source().clone_owned(),
+            pattern: pattern.path().to_string_lossy().replace('\\', "/"),
+            tags: Vec::new(),
+        }
+    }
+
+    /// Requires matching assets to declare `tag` in their `.meta` file, returning the glob.
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        let tag = tag.into();
+        if !self.tags.contains(&tag) {
+            self.tags.push(tag);
+            self.tags.sort();
+        }
+        self
+    }
+
+    /// Returns the asset source this glob selects assets from.
+    pub fn source(&self) -> &AssetSourceId<'static> {
+        &self.source
+    }
+
+    /// Returns the pattern of this glob, without the asset source.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the tags matching assets are required to declare.
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Returns `true` if `path` matches the pattern of this glob. This does not check tags.
+    pub fn matches(&self, path: impl AsRef<Path>) -> bool {
+        let path = path.as_ref().to_string_lossy().replace('\\', "/");
+        let path: Vec<&str> = path.split('/').filter(|c| !c.is_empty()).collect();
+        let pattern: Vec<&str> = self.pattern.split('/').filter(|c| !c.is_empty()).collect();
+        match_components(&pattern, &path)
+    }
+
+    /// Returns `true` if `tags` contains every tag required by this glob.
+    pub fn matches_tags(&self, tags: &[String]) -> bool {
+        self.tags.iter().all(|tag| tags.contains(tag))
+    }
+
+    /// Returns the deepest folder that contains every path matching this glob.
+    pub(crate) fn base_path(&self) -> PathBuf {
+        let mut components: Vec<&str> = self.pattern.split('/').filter(|c| !c.is_empty()).collect();
+        // The last component always names files, even if it has no wildcards.
+        components.pop();
+        components
+            .into_iter()
+            .take_while(|component| !component.contains(['*', '?']))
+            .collect()
+    }
+}
+
+impl<'a> From<&'a str> for AssetGlob {
+    fn from(pattern: &'a str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+impl From<String> for AssetGlob {
+    fn from(pattern: String) -> Self {
+        Self::new(pattern)
+    }
+}
+
+impl core::fmt::Display for AssetGlob {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let AssetSourceId::Name(name) = &self.source {
+            write!(f, "{name}://")?;
+        }
+        write!(f, "{}", self.pattern)?;
+        if !self.tags.is_empty() {
+            write!(f, " [{}]", self.tags.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+fn match_components(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|i| match_components(rest, &path[i..])),
+        Some((component, rest)) => path.split_first().is_some_and(|(first, path_rest)| {
+            let pattern: Vec<char> = component.chars().collect();
+            let text: Vec<char> = first.chars().collect();
+            match_component(&pattern, &text) && match_components(rest, path_rest)
+        }),
+    }
+}
+
+fn match_component(pattern: &[char], text: &[char]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => (0..=text.len()).any(|i| match_component(rest, &text[i..])),
+        Some(('?', rest)) => !text.is_empty() && match_component(rest, &text[1..]),
+        Some((c, rest)) => text.first() == Some(c) && match_component(rest, &text[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AssetGlob;
+    use alloc::string::ToString;
+    use std::path::PathBuf;
+
+    #[test]
+    fn glob_matching() {
+        let glob = AssetGlob::new("textures/**/*.ktx2");
+        assert!(glob.matches("textures/grass.ktx2"));
+        assert!(glob.matches("textures/terrain/rock/granite.ktx2"));
+        assert!(!glob.matches("textures/grass.png"));
+        assert!(!glob.matches("models/grass.ktx2"));
+        assert_eq!(glob.base_path(), PathBuf::from("textures"));
+
+        let glob = AssetGlob::new("levels/level_??.*");
+        assert!(glob.matches("levels/level_01.scn.ron"));
+        assert!(!glob.matches("levels/level_1.scn.ron"));
+        assert!(!glob.matches("levels/sub/level_01.scn.ron"));
+        assert_eq!(glob.base_path(), PathBuf::from("levels"));
+
+        let glob = AssetGlob::new("**");
+        assert!(glob.matches("a/b/c.png"));
+        assert_eq!(glob.base_path(), PathBuf::new());
+    }
+
+    #[test]
+    fn glob_source_and_tags() {
+        let glob = AssetGlob::new("remote://*.png")
+            .with_tag("ui")
+            .with_tag("hd");
+        assert_eq!(glob.source().as_str(), Some("remote"));
+        assert_eq!(glob.pattern(), "*.png");
+        assert!(glob.matches_tags(&["hd".into(), "ui".into(), "extra".into()]));
+        assert!(!glob.matches_tags(&["hd".into()]));
+        assert_eq!(glob.to_string(), "remote://*.png [hd, ui]");
+    }
+}
@@ -0,0 +1,107 @@
+use alloc::vec::Vec;
+
+use crate::{
+    AssetPath, DependencyLoadState, LoadState, RecursiveDependencyLoadState, UntypedAssetId,
+};
+use bevy_platform::collections::{HashMap, HashSet};
+
+/// A read-only snapshot of the assets tracked by the [`AssetServer`](crate::AssetServer) and the
+/// dependencies between them.
+///
+/// This is produced by [`AssetServer::dependency_graph`](crate::AssetServer::dependency_graph) and is
+/// intended for debugging and tooling, such as visualizing why an asset is still loading.
+/// Assets that are not tracked by the server (such as those added directly to [`Assets`](crate::Assets))
+/// do not have nodes, but may still show up as the dependencies of tracked assets.
+#[derive(Clone, Debug, Default)]
+pub struct AssetDependencyGraph {
+    nodes: HashMap<UntypedAssetId, AssetGraphNode>,
+}
+
+impl AssetDependencyGraph {
+    pub(crate) fn new(nodes: HashMap<UntypedAssetId, AssetGraphNode>) -> Self {
+        Self { nodes }
+    }
+
+    /// Returns the node for the asset with the given `id`, if it is tracked by the server.
+    pub fn get(&self, id: impl Into<UntypedAssetId>) -> Option<&AssetGraphNode> {
+        self.nodes.get(&id.into())
+    }
+
+    /// Returns the node for the asset with the given `path`, if it is tracked by the server.
+    ///
+    /// If multiple assets of different types share this path, an arbitrary one is returned.
+    pub fn get_by_path<'a>(&self, path: impl Into<AssetPath<'a>>) -> Option<&AssetGraphNode> {
+        let path = path.into();
+        self.nodes
+            .values()
+            .find(|node| node.path.as_ref() == Some(&path))
+    }
+
+    /// Iterates over all nodes in the graph, in arbitrary order.
+    pub fn nodes(&self) -> impl Iterator<Item = &AssetGraphNode> {
+        self.nodes.values()
+    }
+
+    /// Returns the number of nodes in the graph.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the subgraph made up of the given `roots` and all of their recursive dependencies.
+    pub fn subgraph(&self, roots: impl IntoIterator<Item = impl Into<UntypedAssetId>>) -> Self {
+        let mut nodes = <HashMap<_, _>>::default();
+        let mut stack: Vec<UntypedAssetId> = roots.into_iter().map(Into::into).collect();
+        while let Some(id) = stack.pop() {
+            if nodes.contains_key(&id) {
+                continue;
+            }
+            let Some(node) = self.nodes.get(&id) else {
+                continue;
+            };
+            stack.extend(node.dependencies.iter().copied());
+            nodes.insert(id, node.clone());
+        }
+        Self { nodes }
+    }
+
+    /// Returns the ids of the assets that failed to load and prevent `id` from finishing its
+    /// recursive dependency load. This includes `id` itself if it failed to load.
+    pub fn failed_dependencies(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        self.subgraph([id])
+            .nodes()
+            .filter(|node| node.load_state.is_failed())
+            .map(|node| node.id)
+            .collect()
+    }
+}
+
+/// A single asset in an [`AssetDependencyGraph`].
+#[derive(Clone, Debug)]
+pub struct AssetGraphNode {
+    /// The id of this asset.
+    pub id: UntypedAssetId,
+    /// The path of this asset, if it has one.
+    pub path: Option<AssetPath<'static>>,
+    /// The load state of this asset. If it failed to load, this contains the load error.
+    pub load_state: LoadState,
+    /// The combined load state of this asset's direct dependencies.
+    pub dependency_load_state: DependencyLoadState,
+    /// The combined load state of this asset's recursive dependencies.
+    pub recursive_dependency_load_state: RecursiveDependencyLoadState,
+    /// The type name of the [`AssetLoader`](crate::AssetLoader) used to load this asset, or the asset
+    /// it is labeled within. This is `None` if the asset has not started loading from a path yet.
+    pub loader: Option<&'static str>,
+    /// The assets this asset depends on, as reported by its most recent load.
+    pub dependencies: HashSet<UntypedAssetId>,
+    /// The tracked assets that depend on this asset.
+    pub dependants: HashSet<UntypedAssetId>,
+    /// The paths this asset's loader read while loading it ("loader dependencies").
+    ///
+    /// This is only populated when the server is watching for changes.
+    pub loader_dependencies: Vec<AssetPath<'static>>,
+}
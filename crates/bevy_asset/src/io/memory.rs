@@ -1,8 +1,10 @@
-use crate::io::{AssetReader, AssetReaderError, PathStream, Reader};
+use crate::io::{
+    AssetReader, AssetReaderError, AssetWriter, AssetWriterError, PathStream, Reader, Writer,
+};
 use alloc::{borrow::ToOwned, boxed::Box, sync::Arc, vec::Vec};
 use bevy_platform::collections::HashMap;
 use core::{pin::Pin, task::Poll};
-use futures_io::AsyncRead;
+use futures_io::{AsyncRead, AsyncWrite};
 use futures_lite::{ready, Stream};
 use parking_lot::RwLock;
 use std::path::{Path, PathBuf};
@@ -63,6 +65,40 @@ impl Dir {
         dir.0.write().assets.remove(&key)
     }
 
+    /// Removes the stored meta at `path` and returns the `Data` stored if found and otherwise `None`.
+    pub fn remove_meta(&self, path: &Path) -> Option<Data> {
+        let mut dir = self.clone();
+        if let Some(parent) = path.parent() {
+            dir = self.get_or_insert_dir(parent);
+        }
+        let key: Box<str> = path.file_name().unwrap().to_string_lossy().into();
+        dir.0.write().metadata.remove(&key)
+    }
+
+    /// Removes the directory at `path` (including its contents) and returns it if found and otherwise `None`.
+    pub fn remove_dir(&self, path: &Path) -> Option<Dir> {
+        let mut dir = self.clone();
+        if let Some(parent) = path.parent() {
+            dir = self.get_dir(parent)?;
+        }
+        let key: Box<str> = path.file_name()?.to_string_lossy().into();
+        dir.0.write().dirs.remove(&key)
+    }
+
+    /// Returns `true` if this directory contains no assets, meta files or directories.
+    pub fn is_empty(&self) -> bool {
+        let dir = self.0.read();
+        dir.assets.is_empty() && dir.metadata.is_empty() && dir.dirs.is_empty()
+    }
+
+    /// Removes all assets, meta files and directories in this directory.
+    pub fn clear(&self) {
+        let mut dir = self.0.write();
+        dir.assets.clear();
+        dir.metadata.clear();
+        dir.dirs.clear();
+    }
+
     pub fn insert_meta(&self, path: &Path, value: impl Into<Value>) {
         let mut dir = self.clone();
         if let Some(parent) = path.parent() {
@@ -194,10 +230,13 @@ pub enum Value {
 }
 
 impl Data {
-    fn path(&self) -> &Path {
+    /// The path of this data, relative to the root [`Dir`].
+    pub fn path(&self) -> &Path {
         &self.path
     }
-    fn value(&self) -> &[u8] {
+
+    /// The stored bytes.
+    pub fn value(&self) -> &[u8] {
         match &self.value {
             Value::Vec(vec) => vec,
             Value::Static(value) => value,
@@ -325,6 +364,164 @@ impl AssetReader for MemoryAssetReader {
     }
 }
 
+/// In-memory [`AssetWriter`] implementation that writes to a [`Dir`].
+/// This is primarily intended for unit tests.
+///
+/// Written bytes are only stored in the [`Dir`] once the [`Writer`] is flushed or closed.
+#[derive(Default, Clone)]
+pub struct MemoryAssetWriter {
+    pub root: Dir,
+}
+
+struct DataWriter {
+    root: Dir,
+    path: PathBuf,
+    is_meta: bool,
+    bytes: Vec<u8>,
+}
+
+impl DataWriter {
+    fn store(&self) {
+        if self.is_meta {
+            self.root.insert_meta(&self.path, self.bytes.clone());
+        } else {
+            self.root.insert_asset(&self.path, self.bytes.clone());
+        }
+    }
+}
+
+impl AsyncWrite for DataWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _cx: &mut core::task::Context<'_>,
+        buf: &[u8],
+    ) -> Poll<futures_io::Result<usize>> {
+        self.bytes.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        _cx: &mut core::task::Context<'_>,
+    ) -> Poll<futures_io::Result<()>> {
+        self.store();
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(
+        self: Pin<&mut Self>,
+        _cx: &mut core::task::Context<'_>,
+    ) -> Poll<futures_io::Result<()>> {
+        self.store();
+        Poll::Ready(Ok(()))
+    }
+}
+
+fn not_found(path: &Path) -> AssetWriterError {
+    std::io::Error::new(
+        std::io::ErrorKind::NotFound,
+        alloc::format!("{} does not exist", path.display()),
+    )
+    .into()
+}
+
+impl MemoryAssetWriter {
+    fn writer(&self, path: &Path, is_meta: bool) -> Box<Writer> {
+        Box::new(DataWriter {
+            root: self.root.clone(),
+            path: path.to_owned(),
+            is_meta,
+            bytes: Vec::new(),
+        })
+    }
+}
+
+impl AssetWriter for MemoryAssetWriter {
+    async fn write<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        Ok(self.writer(path, false))
+    }
+
+    async fn write_meta<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        Ok(self.writer(path, true))
+    }
+
+    async fn remove<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.root
+            .remove_asset(path)
+            .map(|_| ())
+            .ok_or_else(|| not_found(path))
+    }
+
+    async fn remove_meta<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.root
+            .remove_meta(path)
+            .map(|_| ())
+            .ok_or_else(|| not_found(path))
+    }
+
+    async fn rename<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        let data = self
+            .root
+            .remove_asset(old_path)
+            .ok_or_else(|| not_found(old_path))?;
+        self.root.insert_asset(new_path, data.value);
+        Ok(())
+    }
+
+    async fn rename_meta<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        let data = self
+            .root
+            .remove_meta(old_path)
+            .ok_or_else(|| not_found(old_path))?;
+        self.root.insert_meta(new_path, data.value);
+        Ok(())
+    }
+
+    async fn create_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.root.get_or_insert_dir(path);
+        Ok(())
+    }
+
+    async fn remove_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.root
+            .remove_dir(path)
+            .map(|_| ())
+            .ok_or_else(|| not_found(path))
+    }
+
+    async fn remove_empty_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        let dir = self.root.get_dir(path).ok_or_else(|| not_found(path))?;
+        if !dir.is_empty() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::DirectoryNotEmpty,
+                alloc::format!("{} is not empty", path.display()),
+            )
+            .into());
+        }
+        self.root.remove_dir(path);
+        Ok(())
+    }
+
+    async fn remove_assets_in_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        self.root
+            .get_dir(path)
+            .ok_or_else(|| not_found(path))?
+            .clear();
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 pub mod test {
     use super::Dir;
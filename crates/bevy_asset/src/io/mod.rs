@@ -22,6 +22,7 @@ pub use futures_lite::AsyncWriteExt;
 pub use source::*;
 
 use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use bevy_platform::sync::atomic::{AtomicU64, Ordering};
 use bevy_tasks::{BoxedFuture, ConditionalSendFuture};
 use core::future::Future;
 use core::{
@@ -762,6 +763,68 @@ impl Reader for SliceReader<'_> {
     }
 }
 
+/// A [`Reader`] wrapper that counts the bytes read from the inner reader into a shared counter.
+///
+/// This is used by the [`AssetServer`](crate::AssetServer) to report byte-level progress for
+/// in-flight loads. See [`AssetLoadProgress`](crate::AssetLoadProgress).
+pub struct ProgressReader<R> {
+    reader: R,
+    bytes_read: Arc<AtomicU64>,
+}
+
+impl<R: Reader> ProgressReader<R> {
+    /// Creates a new [`ProgressReader`] that adds the number of bytes read from `reader` to `bytes_read`.
+    pub fn new(reader: R, bytes_read: Arc<AtomicU64>) -> Self {
+        Self { reader, bytes_read }
+    }
+
+    /// Returns the total number of bytes recorded in the shared counter.
+    pub fn bytes_read(&self) -> u64 {
+        self.bytes_read.load(Ordering::Relaxed)
+    }
+
+    /// Consumes this wrapper, returning the inner reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Reader> AsyncRead for ProgressReader<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<futures_io::Result<usize>> {
+        let n = ready!(Pin::new(&mut self.reader).poll_read(cx, buf))?;
+        self.bytes_read.fetch_add(n as u64, Ordering::Relaxed);
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl<R: Reader> AsyncSeekForward for ProgressReader<R> {
+    fn poll_seek_forward(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        offset: u64,
+    ) -> Poll<std::io::Result<u64>> {
+        Pin::new(&mut self.reader).poll_seek_forward(cx, offset)
+    }
+}
+
+impl<R: Reader> Reader for ProgressReader<R> {
+    fn read_to_end<'a>(
+        &'a mut self,
+        buf: &'a mut Vec<u8>,
+    ) -> StackFuture<'a, std::io::Result<usize>, STACK_FUTURE_SIZE> {
+        // The inner future already uses the whole stack budget, so this one has to be boxed.
+        StackFuture::from(Box::pin(async {
+            let n = self.reader.read_to_end(buf).await?;
+            self.bytes_read.fetch_add(n as u64, Ordering::Relaxed);
+            Ok(n)
+        }))
+    }
+}
+
 /// Appends `.meta` to the given path.
 pub(crate) fn get_meta_path(path: &Path) -> PathBuf {
     let mut meta_path = path.to_path_buf();
@@ -177,11 +177,13 @@ mod assets;
 mod direct_access_ext;
 mod event;
 mod folder;
+mod graph;
 mod handle;
 mod id;
 mod loader;
 mod loader_builders;
 mod path;
+mod progress;
 mod reflect;
 mod render_asset;
 mod server;
@@ -192,6 +194,7 @@ pub use direct_access_ext::DirectAssetAccessExt;
 pub use event::*;
 pub use folder::*;
 pub use futures_lite::{AsyncReadExt, AsyncWriteExt};
+pub use graph::*;
 pub use handle::*;
 pub use id::*;
 pub use loader::*;
@@ -199,6 +202,7 @@ pub use loader_builders::{
     Deferred, DynamicTyped, Immediate, NestedLoader, StaticTyped, UnknownTyped,
 };
 pub use path::*;
+pub use progress::*;
 pub use reflect::*;
 pub use render_asset::*;
 pub use server::*;
@@ -217,6 +221,8 @@ use alloc::{
     vec::Vec,
 };
 use bevy_app::{App, Plugin, PostUpdate, PreUpdate};
+#[cfg(feature = "memory_usage")]
+use bevy_diagnostic::RegisterMemoryUsage;
 use bevy_ecs::prelude::Component;
 use bevy_ecs::{
     reflect::AppTypeRegistry,
@@ -414,6 +420,9 @@ impl Plugin for AssetPlugin {
             .init_asset::<LoadedUntypedAsset>()
             .init_asset::<()>()
             .add_event::<UntypedAssetLoadFailedEvent>()
+            .add_event::<AssetSavedEvent>()
+            .add_event::<AssetSaveFailedEvent>()
+            .add_event::<LoadingGroupCompletedEvent>()
             .configure_sets(
                 PreUpdate,
                 AssetTrackingSystems.after(handle_internal_asset_events),
@@ -423,6 +432,10 @@ impl Plugin for AssetPlugin {
             // This is virtually never a real problem: asset loading is async and so anything that interacts directly with it
             // needs to be robust to stochastic delays anyways.
             .add_systems(PreUpdate, handle_internal_asset_events.ambiguous_with_all())
+            .add_systems(
+                PreUpdate,
+                track_loading_groups.after(handle_internal_asset_events),
+            )
             .register_type::<AssetPath>();
     }
 }
@@ -619,7 +632,10 @@ impl AssetApp for App {
             .add_systems(
                 PreUpdate,
                 Assets::<A>::track_assets.in_set(AssetTrackingSystems),
-            )
+            );
+        #[cfg(feature = "memory_usage")]
+        self.register_memory_usage(Assets::<A>::allocated_bytes);
+        self
     }
 
     fn register_asset_reflect<A>(&mut self) -> &mut Self
@@ -668,16 +684,18 @@ pub type AssetEvents = AssetEventSystems;
 #[cfg(test)]
 mod tests {
     use crate::{
-        folder::LoadedFolder,
+        folder::{AssetGlob, LoadedFolder},
         handle::Handle,
         io::{
             gated::{GateOpener, GatedReader},
-            memory::{Dir, MemoryAssetReader},
-            AssetReader, AssetReaderError, AssetSource, AssetSourceId, Reader,
+            memory::{Dir, MemoryAssetReader, MemoryAssetWriter},
+            AssetReader, AssetReaderError, AssetSource, AssetSourceId, Reader, Writer,
         },
         loader::{AssetLoader, LoadContext},
+        saver::{AssetSaver, SavedAsset},
         Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets, LoadState, UnapprovedPathMode,
+        AssetPlugin, AssetSaveError, AssetSaveFailedEvent, AssetSavedEvent, AssetServer, Assets,
+        AsyncWriteExt, LoadState, LoadingGroup, LoadingGroupCompletedEvent, UnapprovedPathMode,
     };
     use alloc::{
         boxed::Box,
@@ -700,7 +718,7 @@ mod tests {
     use std::path::Path;
     use thiserror::Error;
 
-    #[derive(Asset, TypePath, Debug, Default)]
+    #[derive(Asset, TypePath, Debug, Default, Clone)]
     pub struct CoolText {
         pub text: String,
         pub embedded: String,
@@ -1667,6 +1685,279 @@ mod tests {
         });
     }
 
+    #[test]
+    fn loading_group_progress() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+
+        let a_path = "a.cool.ron";
+        let a_ron = r#"
+(
+    text: "a",
+    dependencies: [
+        "b.cool.ron",
+        "missing.cool.ron",
+    ],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        let b_path = "b.cool.ron";
+        let b_ron = r#"
+(
+    text: "b",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        dir.insert_asset_text(Path::new(a_path), a_ron);
+        dir.insert_asset_text(Path::new(b_path), b_ron);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let handle: Handle<CoolText> = asset_server.load(a_path);
+        let group = app
+            .world_mut()
+            .spawn(LoadingGroup::default().with(handle))
+            .id();
+
+        app.update();
+        let progress = app.world().get::<LoadingGroup>(group).unwrap().progress();
+        assert_eq!(progress.pending, 1);
+        assert_eq!(progress.total(), 1);
+        assert!(!progress.is_finished());
+
+        gate_opener.open(a_path);
+        gate_opener.open(b_path);
+        gate_opener.open("missing.cool.ron");
+
+        let mut reader = EventCursor::default();
+        run_app_until(&mut app, |world| {
+            let events = world.resource::<Events<LoadingGroupCompletedEvent>>();
+            let event = reader.read(events).next()?;
+            assert_eq!(event.entity, group);
+            Some(())
+        });
+
+        let group = app.world().get::<LoadingGroup>(group).unwrap();
+        let progress = group.progress();
+        assert!(group.is_completed());
+        assert_eq!(progress.loaded, 2);
+        assert_eq!(progress.failed, 1);
+        assert_eq!(progress.pending, 0);
+        assert_eq!(progress.bytes_read, (a_ron.len() + b_ron.len()) as u64);
+        assert_eq!(progress.fraction(), 1.0);
+    }
+
+    #[test]
+    fn dependency_graph() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+
+        let a_path = "a.cool.ron";
+        let a_ron = r#"
+(
+    text: "a",
+    dependencies: [
+        "b.cool.ron",
+        "missing.cool.ron",
+    ],
+    embedded_dependencies: [],
+    sub_texts: ["sub"],
+)"#;
+        let b_path = "b.cool.ron";
+        let b_ron = r#"
+(
+    text: "b",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        dir.insert_asset_text(Path::new(a_path), a_ron);
+        dir.insert_asset_text(Path::new(b_path), b_ron);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load(a_path);
+
+        app.update();
+        let graph = asset_server.dependency_graph();
+        let node = graph.get(&a).unwrap();
+        assert!(node.load_state.is_loading());
+        assert!(node.dependencies.is_empty());
+
+        gate_opener.open(a_path);
+        gate_opener.open(b_path);
+        gate_opener.open("missing.cool.ron");
+        run_app_until(&mut app, |world| {
+            let server = world.resource::<AssetServer>();
+            server
+                .recursive_dependency_load_state(&a)
+                .is_failed()
+                .then_some(())
+        });
+
+        let graph = asset_server.dependency_graph();
+        let a_node = graph.get(&a).unwrap();
+        let b_node = graph.get_by_path(b_path).unwrap();
+        let missing_node = graph.get_by_path("missing.cool.ron").unwrap();
+        let sub_node = graph.get_by_path("a.cool.ron#sub").unwrap();
+
+        assert!(a_node.load_state.is_loaded());
+        assert!(a_node.recursive_dependency_load_state.is_failed());
+        assert_eq!(
+            a_node.loader,
+            Some(core::any::type_name::<CoolTextLoader>())
+        );
+        assert_eq!(
+            a_node.dependencies,
+            [b_node.id, missing_node.id].into_iter().collect()
+        );
+        assert!(a_node.dependants.is_empty());
+
+        assert!(b_node.load_state.is_loaded());
+        assert_eq!(b_node.dependants, [a_node.id].into_iter().collect());
+
+        assert!(matches!(
+            &missing_node.load_state,
+            LoadState::Failed(error) if matches!(**error, AssetLoadError::AssetReaderError(_))
+        ));
+        assert_eq!(missing_node.dependants, [a_node.id].into_iter().collect());
+
+        // Labeled assets report the loader of the asset they are labeled within.
+        assert_eq!(sub_node.loader, a_node.loader);
+
+        assert_eq!(graph.failed_dependencies(&a), vec![missing_node.id]);
+        let subgraph = graph.subgraph([b_node.id]);
+        assert_eq!(subgraph.len(), 1);
+        assert!(subgraph.get(b_node.id).is_some());
+    }
+
+    #[test]
+    fn load_glob() {
+        let dir = Dir::default();
+
+        let text_ron = |text: &str| {
+            format!(
+                r#"(
+    text: "{text}",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#
+            )
+        };
+        let tagged_meta = format!(
+            r#"(
+    meta_format_version: "1.0",
+    tags: ["hd"],
+    asset: Load(
+        loader: "{}",
+        settings: (),
+    ),
+)"#,
+            core::any::type_name::<CoolTextLoader>()
+        );
+        dir.insert_asset_text(Path::new("text/a.cool.ron"), &text_ron("a"));
+        dir.insert_meta_text(Path::new("text/a.cool.ron"), &tagged_meta);
+        dir.insert_asset_text(Path::new("text/nested/b.cool.ron"), &text_ron("b"));
+        dir.insert_asset_text(Path::new("text/c.other.ron"), &text_ron("c"));
+        dir.insert_asset_text(Path::new("d.cool.ron"), &text_ron("d"));
+
+        let mut app = ungated_test_app(dir);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let all = asset_server.load_glob("text/**/*.cool.ron");
+        let tagged = asset_server.load_glob(AssetGlob::new("text/**/*.cool.ron").with_tag("hd"));
+        let missing = asset_server.load_glob("missing/*.cool.ron");
+        assert_eq!(all, asset_server.load_glob("text/**/*.cool.ron"));
+        assert_ne!(all, tagged);
+
+        let texts = |world: &World, handle: &Handle<LoadedFolder>| {
+            let folder = world.resource::<Assets<LoadedFolder>>().get(handle)?;
+            let mut texts = Vec::new();
+            for handle in &folder.handles {
+                texts.push(get::<CoolText>(world, handle.id().typed())?.text.clone());
+            }
+            texts.sort();
+            Some(texts)
+        };
+        run_app_until(&mut app, |world| {
+            assert_eq!(texts(world, &all)?, ["a", "b"]);
+            assert_eq!(texts(world, &tagged)?, ["a"]);
+            assert!(texts(world, &missing)?.is_empty());
+            Some(())
+        });
+    }
+
+    #[test]
+    fn reject_incompatible_loader_version() {
+        let dir = Dir::default();
+        let meta = |version: u32| {
+            format!(
+                r#"(
+    meta_format_version: "1.0",
+    loader_version: Some({version}),
+    asset: Load(
+        loader: "{}",
+        settings: (),
+    ),
+)"#,
+                core::any::type_name::<CoolTextLoader>()
+            )
+        };
+        let ron = r#"(
+    text: "a",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        dir.insert_asset_text(Path::new("current.cool.ron"), ron);
+        dir.insert_meta_text(
+            Path::new("current.cool.ron"),
+            &meta(<CoolTextLoader as AssetLoader>::VERSION),
+        );
+        dir.insert_asset_text(Path::new("outdated.cool.ron"), ron);
+        dir.insert_meta_text(
+            Path::new("outdated.cool.ron"),
+            &meta(<CoolTextLoader as AssetLoader>::VERSION + 1),
+        );
+
+        let mut app = ungated_test_app(dir);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let current: Handle<CoolText> = asset_server.load("current.cool.ron");
+        let outdated: Handle<CoolText> = asset_server.load("outdated.cool.ron");
+
+        run_app_until(&mut app, |world| {
+            let server = world.resource::<AssetServer>();
+            if !server.is_loaded(&current) {
+                return None;
+            }
+            let LoadState::Failed(error) = server.load_state(&outdated) else {
+                return None;
+            };
+            assert!(matches!(
+                *error,
+                AssetLoadError::IncompatibleLoaderVersion {
+                    expected: 0,
+                    found: 1,
+                    ..
+                }
+            ));
+            Some(())
+        });
+    }
+
     /// Tests that `AssetLoadFailedEvent<A>` events are emitted and can be used to retry failed assets.
     #[test]
     fn load_error_events() {
@@ -1788,6 +2079,122 @@ mod tests {
         });
     }
 
+    #[derive(Default)]
+    struct CoolTextSaver;
+
+    impl AssetSaver for CoolTextSaver {
+        type Asset = CoolText;
+        type Settings = ();
+        type OutputLoader = CoolTextLoader;
+        type Error = std::io::Error;
+
+        async fn save(
+            &self,
+            writer: &mut Writer,
+            asset: SavedAsset<'_, Self::Asset>,
+            _settings: &Self::Settings,
+        ) -> Result<(), Self::Error> {
+            let ron = CoolTextRon {
+                text: asset.text.clone(),
+                dependencies: vec![],
+                embedded_dependencies: vec![],
+                sub_texts: vec![],
+            };
+            let bytes = ron::ser::to_string(&ron)
+                .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+            writer.write_all(bytes.as_bytes()).await
+        }
+    }
+
+    #[test]
+    fn unversioned_process_keeps_asset_hash() {
+        use crate::{
+            meta::{get_asset_hash, get_versioned_asset_hash},
+            processor::{LoadTransformAndSave, Process},
+            transformer::IdentityAssetTransformer,
+        };
+
+        type CoolTextProcess =
+            LoadTransformAndSave<CoolTextLoader, IdentityAssetTransformer<CoolText>, CoolTextSaver>;
+
+        // Processes made of unversioned stages must keep the hashes they had before versions were
+        // hashed, or all of their processed output would be invalidated.
+        let hash = get_asset_hash(b"meta", b"asset");
+        let versions = [CoolTextProcess::VERSION, CoolTextLoader::VERSION];
+        assert_eq!(get_versioned_asset_hash(hash, &versions), hash);
+    }
+
+    /// Creates an app whose default asset source reads from and writes to `dir` without any gating.
+    fn ungated_test_app(dir: Dir) -> App {
+        let mut app = App::new();
+        let reader = MemoryAssetReader { root: dir.clone() };
+        let writer = MemoryAssetWriter { root: dir };
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(reader.clone()))
+                .with_writer(move |_| Some(Box::new(writer.clone()))),
+        )
+        .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader);
+        app
+    }
+
+    #[test]
+    fn save_asset() {
+        let dir = Dir::default();
+        let mut app = ungated_test_app(dir.clone());
+
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .add(CoolText {
+                text: "saved".to_string(),
+                ..Default::default()
+            });
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        asset_server.save::<_, CoolTextSaver>(&handle, "saved.cool.ron", ());
+
+        let mut reader = EventCursor::default();
+        run_app_until(&mut app, |world| {
+            let events = world.resource::<Events<AssetSavedEvent>>();
+            let event = reader.read(events).next()?;
+            assert_eq!(event.id, handle.id().untyped());
+            assert_eq!(event.path, AssetPath::from("saved.cool.ron"));
+            Some(())
+        });
+        assert!(dir.get_asset(Path::new("saved.cool.ron")).is_some());
+        assert!(dir.get_metadata(Path::new("saved.cool.ron")).is_some());
+
+        // The saved asset can be loaded back with the saver's output loader.
+        let loaded: Handle<CoolText> = asset_server.load("saved.cool.ron");
+        run_app_until(&mut app, |world| {
+            let text = get::<CoolText>(world, loaded.id())?;
+            assert_eq!(text.text, "saved");
+            Some(())
+        });
+    }
+
+    #[test]
+    fn save_missing_asset_fails() {
+        let mut app = ungated_test_app(Dir::default());
+
+        let handle = app.world().resource::<Assets<CoolText>>().reserve_handle();
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        asset_server.save::<_, CoolTextSaver>(&handle, "missing.cool.ron", ());
+
+        let mut reader = EventCursor::default();
+        run_app_until(&mut app, |world| {
+            let events = world.resource::<Events<AssetSaveFailedEvent>>();
+            let event = reader.read(events).next()?;
+            assert_eq!(event.id, handle.id().untyped());
+            assert!(matches!(event.error, AssetSaveError::MissingAsset(_)));
+            Some(())
+        });
+    }
+
     #[test]
     fn ignore_system_ambiguities_on_assets() {
         let mut app = App::new();
@@ -35,6 +35,16 @@ pub trait AssetLoader: Send + Sync + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The type of [error](`std::error::Error`) which could be encountered by this loader.
     type Error: Into<BevyError>;
+    /// The version of this loader. This should be incremented whenever a change to the loader changes how it
+    /// interprets its input, such that previously processed assets are no longer compatible with it.
+    ///
+    /// The version of an [`AssetLoader`] is part of the hash the [`AssetProcessor`] uses to decide whether an asset
+    /// needs to be reprocessed, and is recorded in the meta of processed assets. Loading a processed asset
+    /// recorded with a different version fails with [`AssetLoadError::IncompatibleLoaderVersion`].
+    ///
+    /// [`AssetProcessor`]: crate::processor::AssetProcessor
+    /// [`AssetLoadError::IncompatibleLoaderVersion`]: crate::AssetLoadError::IncompatibleLoaderVersion
+    const VERSION: u32 = 0;
     /// Asynchronously loads [`AssetLoader::Asset`] (and any other labeled assets) from the bytes provided by [`Reader`].
     fn load(
         &self,
@@ -74,6 +84,8 @@ pub trait ErasedAssetLoader: Send + Sync + 'static {
     fn asset_type_name(&self) -> &'static str;
     /// Returns the [`TypeId`] of the top-level [`Asset`] loaded by the [`AssetLoader`].
     fn asset_type_id(&self) -> TypeId;
+    /// Returns the [`AssetLoader::VERSION`] of the underlying [`AssetLoader`].
+    fn version(&self) -> u32;
 }
 
 impl<L> ErasedAssetLoader for L
@@ -131,6 +143,10 @@ where
     fn asset_type_id(&self) -> TypeId {
         TypeId::of::<L::Asset>()
     }
+
+    fn version(&self) -> u32 {
+        L::VERSION
+    }
 }
 
 pub(crate) struct LabeledAsset {
@@ -31,6 +31,16 @@ pub struct AssetMeta<L: AssetLoader, P: Process> {
     /// [`AssetProcessor`]: crate::processor::AssetProcessor
     #[serde(skip_serializing_if = "Option::is_none")]
     pub processed_info: Option<ProcessedInfo>,
+    /// Arbitrary user-defined tags for this asset. These can be used to select assets with
+    /// [`AssetServer::load_glob`](crate::AssetServer::load_glob), and are carried over to the
+    /// processed meta by the [`AssetProcessor`](crate::processor::AssetProcessor).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// The [`AssetLoader::VERSION`] of the loader this asset was produced for. This is set by the
+    /// [`AssetProcessor`](crate::processor::AssetProcessor) on processed assets, which then fail to load
+    /// if the loader's version has changed since.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loader_version: Option<u32>,
     /// How to handle this asset in the asset system. See [`AssetAction`].
     pub asset: AssetAction<L::Settings, P::Settings>,
 }
@@ -40,6 +50,8 @@ impl<L: AssetLoader, P: Process> AssetMeta<L, P> {
         Self {
             meta_format_version: META_FORMAT_VERSION.to_string(),
             processed_info: None,
+            tags: Vec::new(),
+            loader_version: None,
             asset,
         }
     }
@@ -120,6 +132,14 @@ pub struct ProcessedInfoMinimal {
     pub processed_info: Option<ProcessedInfo>,
 }
 
+/// This is a minimal counterpart to [`AssetMeta`] that exists to read the tags of an asset without knowing its
+/// loader or processor.
+#[derive(Serialize, Deserialize)]
+pub struct AssetTagsMinimal {
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
 /// A dynamic type-erased counterpart to [`AssetMeta`] that enables passing around and interacting with [`AssetMeta`] without knowing
 /// its type.
 pub trait AssetMetaDyn: Downcast + Send + Sync {
@@ -133,6 +153,14 @@ pub trait AssetMetaDyn: Downcast + Send + Sync {
     fn processed_info(&self) -> &Option<ProcessedInfo>;
     /// Returns a mutable reference to the [`ProcessedInfo`] if it exists.
     fn processed_info_mut(&mut self) -> &mut Option<ProcessedInfo>;
+    /// Returns the tags of this asset.
+    fn tags(&self) -> &[String];
+    /// Returns a mutable reference to the tags of this asset.
+    fn tags_mut(&mut self) -> &mut Vec<String>;
+    /// Returns the [`AssetLoader::VERSION`] this asset was produced for, if it was recorded.
+    fn loader_version(&self) -> Option<u32>;
+    /// Returns a mutable reference to the recorded [`AssetLoader::VERSION`].
+    fn loader_version_mut(&mut self) -> &mut Option<u32>;
 }
 
 impl<L: AssetLoader, P: Process> AssetMetaDyn for AssetMeta<L, P> {
@@ -161,6 +189,18 @@ impl<L: AssetLoader, P: Process> AssetMetaDyn for AssetMeta<L, P> {
     fn processed_info_mut(&mut self) -> &mut Option<ProcessedInfo> {
         &mut self.processed_info
     }
+    fn tags(&self) -> &[String] {
+        &self.tags
+    }
+    fn tags_mut(&mut self) -> &mut Vec<String> {
+        &mut self.tags
+    }
+    fn loader_version(&self) -> Option<u32> {
+        self.loader_version
+    }
+    fn loader_version_mut(&mut self) -> &mut Option<u32> {
+        &mut self.loader_version
+    }
 }
 
 impl_downcast!(AssetMetaDyn);
@@ -248,6 +288,22 @@ pub(crate) fn get_asset_hash(meta_bytes: &[u8], asset_bytes: &[u8]) -> AssetHash
     *hasher.finalize().as_bytes()
 }
 
+/// Combines `asset_hash` with the versions of the loaders and processors used to process the asset.
+///
+/// Versions of `0` (the default) are not hashed, so that the hashes of assets that don't use versioning stay the same.
+/// NOTE: changing the hashing logic here is a _breaking change_ that requires a [`META_FORMAT_VERSION`] bump.
+pub(crate) fn get_versioned_asset_hash(asset_hash: AssetHash, versions: &[u32]) -> AssetHash {
+    if versions.iter().all(|version| *version == 0) {
+        return asset_hash;
+    }
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&asset_hash);
+    for version in versions {
+        hasher.update(&version.to_le_bytes());
+    }
+    *hasher.finalize().as_bytes()
+}
+
 /// NOTE: changing the hashing logic here is a _breaking change_ that requires a [`META_FORMAT_VERSION`] bump.
 pub(crate) fn get_full_asset_hash(
     asset_hash: AssetHash,
@@ -50,8 +50,9 @@ use crate::{
         MissingAssetSourceError,
     },
     meta::{
-        get_asset_hash, get_full_asset_hash, AssetAction, AssetActionMinimal, AssetHash, AssetMeta,
-        AssetMetaDyn, AssetMetaMinimal, ProcessedInfo, ProcessedInfoMinimal,
+        get_asset_hash, get_full_asset_hash, get_versioned_asset_hash, AssetAction,
+        AssetActionMinimal, AssetHash, AssetMeta, AssetMetaDyn, AssetMetaMinimal, ProcessedInfo,
+        ProcessedInfoMinimal,
     },
     AssetLoadError, AssetMetaCheck, AssetPath, AssetServer, AssetServerMode, DeserializeMetaError,
     MissingAssetLoaderForExtensionError, UnapprovedPathMode, WriteDefaultMetaError,
@@ -814,49 +815,55 @@ impl AssetProcessor {
         // Note: we get the asset source reader first because we don't want to create meta files for assets that don't have source files
         let mut byte_reader = reader.read(path).await.map_err(reader_err)?;
 
-        let (mut source_meta, meta_bytes, processor) = match reader.read_meta_bytes(path).await {
+        // The versions of the loader (or processor and output loader) are hashed, so that bumping them reprocesses the asset.
+        let (mut source_meta, meta_bytes, processor, versions) = match reader
+            .read_meta_bytes(path)
+            .await
+        {
             Ok(meta_bytes) => {
                 let minimal: AssetMetaMinimal = ron::de::from_bytes(&meta_bytes).map_err(|e| {
                     ProcessError::DeserializeMetaError(DeserializeMetaError::DeserializeMinimal(e))
                 })?;
-                let (meta, processor) = match minimal.asset {
+                let (meta, processor, versions) = match minimal.asset {
                     AssetActionMinimal::Load { loader } => {
                         let loader = server.get_asset_loader_with_type_name(&loader).await?;
                         let meta = loader.deserialize_meta(&meta_bytes)?;
-                        (meta, None)
+                        (meta, None, vec![loader.version()])
                     }
                     AssetActionMinimal::Process { processor } => {
                         let processor = self
                             .get_processor(&processor)
                             .ok_or_else(|| ProcessError::MissingProcessor(processor))?;
                         let meta = processor.deserialize_meta(&meta_bytes)?;
-                        (meta, Some(processor))
+                        let versions = vec![processor.version(), processor.output_loader_version()];
+                        (meta, Some(processor), versions)
                     }
                     AssetActionMinimal::Ignore => {
                         return Ok(ProcessResult::Ignored);
                     }
                 };
-                (meta, meta_bytes, processor)
+                (meta, meta_bytes, processor, versions)
             }
             Err(AssetReaderError::NotFound(_path)) => {
-                let (meta, processor) = if let Some(processor) = asset_path
+                let (meta, processor, versions) = if let Some(processor) = asset_path
                     .get_full_extension()
                     .and_then(|ext| self.get_default_processor(&ext))
                 {
                     let meta = processor.default_meta();
-                    (meta, Some(processor))
+                    let versions = vec![processor.version(), processor.output_loader_version()];
+                    (meta, Some(processor), versions)
                 } else {
                     match server.get_path_asset_loader(asset_path.clone()).await {
-                        Ok(loader) => (loader.default_meta(), None),
+                        Ok(loader) => (loader.default_meta(), None, vec![loader.version()]),
                         Err(MissingAssetLoaderForExtensionError { .. }) => {
                             let meta: Box<dyn AssetMetaDyn> =
                                 Box::new(AssetMeta::<(), ()>::new(AssetAction::Ignore));
-                            (meta, None)
+                            (meta, None, Vec::new())
                         }
                     }
                 };
                 let meta_bytes = meta.serialize();
-                (meta, meta_bytes, processor)
+                (meta, meta_bytes, processor, versions)
             }
             Err(err) => {
                 return Err(ProcessError::ReadAssetMetaError {
@@ -880,7 +887,8 @@ impl AssetProcessor {
         // PERF: in theory these hashes could be streamed if we want to avoid allocating the whole asset.
         // The downside is that reading assets would need to happen twice (once for the hash and once for the asset loader)
         // Hard to say which is worse
-        let new_hash = get_asset_hash(&meta_bytes, &asset_bytes);
+        let new_hash =
+            get_versioned_asset_hash(get_asset_hash(&meta_bytes, &asset_bytes), &versions);
         let mut new_processed_info = ProcessedInfo {
             hash: new_hash,
             full_hash: new_hash,
@@ -925,6 +933,7 @@ impl AssetProcessor {
         self.log_begin_processing(asset_path).await;
         if let Some(processor) = processor {
             let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
+            let tags = source_meta.tags().to_vec();
             let mut processed_meta = {
                 let mut context =
                     ProcessContext::new(self, asset_path, &asset_bytes, &mut new_processed_info);
@@ -950,6 +959,7 @@ impl AssetProcessor {
             );
             new_processed_info.full_hash = full_hash;
             *processed_meta.processed_info_mut() = Some(new_processed_info.clone());
+            *processed_meta.tags_mut() = tags;
             let meta_bytes = processed_meta.serialize();
             processed_writer
                 .write_meta_bytes(path, &meta_bytes)
@@ -961,6 +971,8 @@ impl AssetProcessor {
                 .await
                 .map_err(writer_err)?;
             *source_meta.processed_info_mut() = Some(new_processed_info.clone());
+            // Without a processor, `versions` only contains the version of the loader (if there is one).
+            *source_meta.loader_version_mut() = versions.first().copied();
             let meta_bytes = source_meta.serialize();
             processed_writer
                 .write_meta_bytes(path, &meta_bytes)
@@ -1144,6 +1156,7 @@ struct InstrumentedAssetProcessor<T>(T);
 impl<T: Process> Process for InstrumentedAssetProcessor<T> {
     type Settings = T::Settings;
     type OutputLoader = T::OutputLoader;
+    const VERSION: u32 = T::VERSION;
 
     fn process(
         &self,
@@ -1157,6 +1170,8 @@ impl<T: Process> Process for InstrumentedAssetProcessor<T> {
         let meta = AssetMeta {
             meta_format_version: meta.meta_format_version,
             processed_info: meta.processed_info,
+            tags: meta.tags,
+            loader_version: meta.loader_version,
             asset: meta.asset,
         };
         let span = info_span!(
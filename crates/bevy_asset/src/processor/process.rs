@@ -30,6 +30,14 @@ pub trait Process: Send + Sync + Sized + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The [`AssetLoader`] that will be used to load the final processed asset.
     type OutputLoader: AssetLoader;
+    /// The version of this processor. This should be incremented whenever a change to the processor changes its output.
+    ///
+    /// The version is part of the hash the [`AssetProcessor`] uses to decide whether an asset needs to be reprocessed,
+    /// so bumping it causes affected assets to be reprocessed automatically. Versions should only ever increase.
+    ///
+    /// For [`LoadTransformAndSave`], this is a hash of [`AssetLoader::VERSION`], [`AssetTransformer::VERSION`] and
+    /// [`AssetSaver::VERSION`], so that changing any of them changes it.
+    const VERSION: u32 = 0;
     /// Processes the asset stored on `context` in some way using the settings stored on `meta`. The results are written to `writer`. The
     /// final written processed asset is loadable using [`Process::OutputLoader`]. This load will use the returned [`AssetLoader::Settings`].
     fn process(
@@ -160,6 +168,43 @@ pub enum ProcessError {
     ExtensionRequired,
 }
 
+/// Hashes the versions of the stages of a processor with FNV-1a, so that changing the version of any stage, or
+/// swapping the versions of two stages, changes the result.
+///
+/// Returns 0 if every version is 0, so that processors whose stages were never versioned keep the processed
+/// hashes they had before versions were hashed.
+const fn hash_versions(versions: &[u32]) -> u32 {
+    let mut all_zero = true;
+    let mut i = 0;
+    while i < versions.len() {
+        if versions[i] != 0 {
+            all_zero = false;
+        }
+        i += 1;
+    }
+    if all_zero {
+        return 0;
+    }
+    let mut hash: u32 = 0x811c_9dc5;
+    let mut i = 0;
+    while i < versions.len() {
+        let bytes = versions[i].to_le_bytes();
+        let mut j = 0;
+        while j < bytes.len() {
+            hash ^= bytes[j] as u32;
+            hash = hash.wrapping_mul(0x0100_0193);
+            j += 1;
+        }
+        i += 1;
+    }
+    // A versioned processor must never be mistaken for an unversioned one.
+    if hash == 0 {
+        1
+    } else {
+        hash
+    }
+}
+
 impl<Loader, Transformer, Saver> Process for LoadTransformAndSave<Loader, Transformer, Saver>
 where
     Loader: AssetLoader,
@@ -169,6 +214,7 @@ where
     type Settings =
         LoadTransformAndSaveSettings<Loader::Settings, Transformer::Settings, Saver::Settings>;
     type OutputLoader = Saver::OutputLoader;
+    const VERSION: u32 = hash_versions(&[Loader::VERSION, Transformer::VERSION, Saver::VERSION]);
 
     async fn process(
         &self,
@@ -221,6 +267,10 @@ pub trait ErasedProcessor: Send + Sync {
     fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError>;
     /// Returns the default type-erased [`AssetMeta`] for the underlying [`Process`] impl.
     fn default_meta(&self) -> Box<dyn AssetMetaDyn>;
+    /// Returns the [`Process::VERSION`] of the underlying [`Process`] impl.
+    fn version(&self) -> u32;
+    /// Returns the [`AssetLoader::VERSION`] of the underlying [`Process::OutputLoader`].
+    fn output_loader_version(&self) -> u32;
 }
 
 impl<P: Process> ErasedProcessor for P {
@@ -235,12 +285,12 @@ impl<P: Process> ErasedProcessor for P {
                 .downcast::<AssetMeta<(), P>>()
                 .map_err(|_e| ProcessError::WrongMetaType)?;
             let loader_settings = <P as Process>::process(self, context, *meta, writer).await?;
-            let output_meta: Box<dyn AssetMetaDyn> =
-                Box::new(AssetMeta::<P::OutputLoader, ()>::new(AssetAction::Load {
-                    loader: core::any::type_name::<P::OutputLoader>().to_string(),
-                    settings: loader_settings,
-                }));
-            Ok(output_meta)
+            let mut output_meta = AssetMeta::<P::OutputLoader, ()>::new(AssetAction::Load {
+                loader: core::any::type_name::<P::OutputLoader>().to_string(),
+                settings: loader_settings,
+            });
+            output_meta.loader_version = Some(P::OutputLoader::VERSION);
+            Ok(Box::new(output_meta) as Box<dyn AssetMetaDyn>)
         })
     }
 
@@ -255,6 +305,14 @@ impl<P: Process> ErasedProcessor for P {
             settings: P::Settings::default(),
         }))
     }
+
+    fn version(&self) -> u32 {
+        P::VERSION
+    }
+
+    fn output_loader_version(&self) -> u32 {
+        P::OutputLoader::VERSION
+    }
 }
 
 /// Provides scoped data access to the [`AssetProcessor`].
@@ -336,3 +394,24 @@ impl<'a> ProcessContext<'a> {
         self.asset_bytes
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::hash_versions;
+
+    #[test]
+    fn versions_change_hash() {
+        let base = hash_versions(&[1, 0, 2]);
+        // Moving a bump from one stage to another changes the hash.
+        assert_ne!(base, hash_versions(&[2, 0, 1]));
+        // So does bumping the transformer.
+        assert_ne!(base, hash_versions(&[1, 1, 2]));
+        assert_eq!(base, hash_versions(&[1, 0, 2]));
+    }
+
+    #[test]
+    fn unversioned_stages_hash_to_zero() {
+        assert_eq!(hash_versions(&[0, 0, 0]), 0);
+        assert_ne!(hash_versions(&[0, 1, 0]), 0);
+    }
+}
@@ -0,0 +1,166 @@
+use alloc::vec::Vec;
+
+use crate::{AssetServer, UntypedAssetId, UntypedHandle};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::{BufferedEvent, Event, EventWriter},
+    system::{Query, Res},
+};
+
+/// A snapshot of the loading progress of a set of assets, including their recursive dependencies.
+///
+/// This is produced by [`AssetServer::load_progress`] and tracked over time by [`LoadingGroup`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AssetLoadProgress {
+    /// The number of assets that have not finished loading yet.
+    pub pending: usize,
+    /// The number of assets that have finished loading.
+    pub loaded: usize,
+    /// The number of assets that failed to load.
+    pub failed: usize,
+    /// The number of bytes read from the asset sources so far.
+    ///
+    /// Only bytes read through the [`Reader`](crate::io::Reader) passed to an [`AssetLoader`](crate::AssetLoader)
+    /// are counted. Meta files and assets loaded "immediately" by other loaders are not included.
+    pub bytes_read: u64,
+}
+
+impl AssetLoadProgress {
+    /// The total number of assets known so far.
+    pub fn total(&self) -> usize {
+        self.pending + self.loaded + self.failed
+    }
+
+    /// The number of assets that are no longer loading, successfully or not.
+    pub fn finished(&self) -> usize {
+        self.loaded + self.failed
+    }
+
+    /// Returns `true` if no assets are pending.
+    pub fn is_finished(&self) -> bool {
+        self.pending == 0
+    }
+
+    /// Returns `true` if any asset failed to load.
+    pub fn is_failed(&self) -> bool {
+        self.failed > 0
+    }
+
+    /// Returns the fraction of assets that are no longer loading, in the range `0.0..=1.0`.
+    ///
+    /// An empty set of assets is considered fully loaded.
+    pub fn fraction(&self) -> f32 {
+        match self.total() {
+            0 => 1.0,
+            total => self.finished() as f32 / total as f32,
+        }
+    }
+}
+
+/// A [`Component`] that tracks the combined [`AssetLoadProgress`] of a group of assets.
+///
+/// The group holds strong handles, so its assets are kept alive for as long as the group exists.
+/// Its progress is refreshed every frame until every asset in the group (and all of their recursive
+/// dependencies) has either loaded or failed, at which point a [`LoadingGroupCompletedEvent`] is sent.
+/// Adding more handles to a completed group restarts tracking.
+///
+/// ```
+/// # use bevy_asset::{prelude::*, LoadingGroup};
+/// # use bevy_ecs::prelude::*;
+/// fn start_loading(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn(
+///         LoadingGroup::default()
+///             .with(asset_server.load_folder("textures"))
+///             .with(asset_server.load_untyped("levels/forest.scn.ron")),
+///     );
+/// }
+///
+/// fn update_progress_bar(groups: Query<&LoadingGroup>) {
+///     for group in &groups {
+///         let progress = group.progress();
+///         let _percent = progress.fraction() * 100.0;
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Default)]
+pub struct LoadingGroup {
+    handles: Vec<UntypedHandle>,
+    progress: AssetLoadProgress,
+    completed: bool,
+}
+
+impl LoadingGroup {
+    /// Creates a new [`LoadingGroup`] from the given handles.
+    pub fn new(handles: impl IntoIterator<Item = impl Into<UntypedHandle>>) -> Self {
+        Self {
+            handles: handles.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds `handle` to this group, returning the group.
+    pub fn with(mut self, handle: impl Into<UntypedHandle>) -> Self {
+        self.add(handle);
+        self
+    }
+
+    /// Adds `handle` to this group.
+    pub fn add(&mut self, handle: impl Into<UntypedHandle>) {
+        self.handles.push(handle.into());
+        self.completed = false;
+    }
+
+    /// Returns the handles in this group.
+    pub fn handles(&self) -> &[UntypedHandle] {
+        &self.handles
+    }
+
+    /// Returns the ids of the assets in this group.
+    pub fn ids(&self) -> impl Iterator<Item = UntypedAssetId> + '_ {
+        self.handles.iter().map(UntypedHandle::id)
+    }
+
+    /// Returns the progress of this group as of the last time it was updated.
+    pub fn progress(&self) -> AssetLoadProgress {
+        self.progress
+    }
+
+    /// Returns `true` if every asset in this group has either loaded or failed.
+    pub fn is_completed(&self) -> bool {
+        self.completed
+    }
+}
+
+/// A [`BufferedEvent`] sent when every asset in a [`LoadingGroup`] (and all of their recursive
+/// dependencies) has either loaded or failed.
+#[derive(Event, BufferedEvent, Clone, Copy, Debug)]
+pub struct LoadingGroupCompletedEvent {
+    /// The entity holding the [`LoadingGroup`].
+    pub entity: Entity,
+    /// The final progress of the group.
+    pub progress: AssetLoadProgress,
+}
+
+/// A system that refreshes the progress of all incomplete [`LoadingGroup`]s and sends a
+/// [`LoadingGroupCompletedEvent`] for every group that finished loading.
+pub fn track_loading_groups(
+    asset_server: Res<AssetServer>,
+    mut groups: Query<(Entity, &mut LoadingGroup)>,
+    mut completed_events: EventWriter<LoadingGroupCompletedEvent>,
+) {
+    for (entity, mut group) in &mut groups {
+        if group.completed {
+            continue;
+        }
+        let progress = asset_server.load_progress(group.ids());
+        let completed = progress.is_finished();
+        if group.progress != progress || completed {
+            group.progress = progress;
+            group.completed = completed;
+        }
+        if completed {
+            completed_events.write(LoadingGroupCompletedEvent { entity, progress });
+        }
+    }
+}
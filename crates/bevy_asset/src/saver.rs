@@ -24,6 +24,15 @@ pub trait AssetSaver: Send + Sync + 'static {
     type OutputLoader: AssetLoader;
     /// The type of [error](`std::error::Error`) which could be encountered by this saver.
     type Error: Into<Box<dyn core::error::Error + Send + Sync + 'static>>;
+    /// The version of this saver. This should be incremented whenever a change to the saver changes its output.
+    ///
+    /// When this saver is used by [`LoadTransformAndSave`], its version is part of the processor's
+    /// [`Process::VERSION`], so bumping it causes the [`AssetProcessor`] to reprocess affected assets.
+    ///
+    /// [`LoadTransformAndSave`]: crate::processor::LoadTransformAndSave
+    /// [`Process::VERSION`]: crate::processor::Process::VERSION
+    /// [`AssetProcessor`]: crate::processor::AssetProcessor
+    const VERSION: u32 = 0;
 
     /// Saves the given runtime [`Asset`] by writing it to a byte format using `writer`. The passed in `settings` can influence how the
     /// `asset` is saved.
@@ -99,6 +108,16 @@ impl<'a, A: Asset> SavedAsset<'a, A> {
         })
     }
 
+    /// Creates a new [`SavedAsset`] from a runtime `asset` value, such as one stored in [`Assets`](crate::Assets).
+    /// The resulting [`SavedAsset`] has no labeled assets.
+    pub fn from_asset(value: &'a A) -> Self {
+        static EMPTY_LABELED_ASSETS: HashMap<CowArc<'static, str>, LabeledAsset> = HashMap::new();
+        Self {
+            value,
+            labeled_assets: &EMPTY_LABELED_ASSETS,
+        }
+    }
+
     /// Creates a new [`SavedAsset`] from the a [`TransformedAsset`]
     pub fn from_transformed(asset: &'a TransformedAsset<A>) -> Self {
         Self {
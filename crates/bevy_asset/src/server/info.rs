@@ -1,8 +1,9 @@
 use crate::{
     meta::{AssetHash, MetaTransform},
-    Asset, AssetHandleProvider, AssetLoadError, AssetPath, DependencyLoadState, ErasedLoadedAsset,
-    Handle, InternalAssetEvent, LoadState, RecursiveDependencyLoadState, StrongHandle,
-    UntypedAssetId, UntypedHandle,
+    Asset, AssetDependencyGraph, AssetGlob, AssetGraphNode, AssetHandleProvider, AssetLoadError,
+    AssetLoadProgress, AssetPath, DependencyLoadState, ErasedLoadedAsset, Handle,
+    InternalAssetEvent, LoadState, RecursiveDependencyLoadState, StrongHandle, UntypedAssetId,
+    UntypedHandle,
 };
 use alloc::{
     borrow::ToOwned,
@@ -11,7 +12,10 @@ use alloc::{
     vec::Vec,
 };
 use bevy_ecs::world::World;
-use bevy_platform::collections::{hash_map::Entry, HashMap, HashSet};
+use bevy_platform::{
+    collections::{hash_map::Entry, HashMap, HashSet},
+    sync::atomic::{AtomicU64, Ordering},
+};
 use bevy_tasks::Task;
 use bevy_utils::TypeIdMap;
 use core::{any::TypeId, task::Waker};
@@ -33,6 +37,12 @@ pub(crate) struct AssetInfo {
     failed_rec_dependencies: HashSet<UntypedAssetId>,
     dependents_waiting_on_load: HashSet<UntypedAssetId>,
     dependents_waiting_on_recursive_dep_load: HashSet<UntypedAssetId>,
+    /// The direct dependencies of this asset, as reported by the most recent load.
+    pub(crate) dependencies: HashSet<UntypedAssetId>,
+    /// The number of bytes read from this asset's [`Reader`](crate::io::Reader) during its most recent load.
+    pub(crate) bytes_read: Arc<AtomicU64>,
+    /// The type name of the loader used for the most recent load of this asset.
+    pub(crate) loader: Option<&'static str>,
     /// The asset paths required to load this asset. Hashes will only be set for processed assets.
     /// This is set using the value from [`LoadedAsset`].
     /// This will only be populated if [`AssetInfos::watching_for_changes`] is set to `true` to
@@ -62,6 +72,9 @@ impl AssetInfo {
             loader_dependencies: HashMap::default(),
             dependents_waiting_on_load: HashSet::default(),
             dependents_waiting_on_recursive_dep_load: HashSet::default(),
+            dependencies: HashSet::default(),
+            bytes_read: Arc::new(AtomicU64::new(0)),
+            loader: None,
             handle_drops_to_skip: 0,
             waiting_tasks: Vec::new(),
         }
@@ -81,6 +94,16 @@ pub(crate) struct AssetInfos {
     /// Tracks living labeled assets for a given source asset.
     /// This should only be set when watching for changes to avoid unnecessary work.
     pub(crate) living_labeled_assets: HashMap<AssetPath<'static>, HashSet<Box<str>>>,
+    /// Tracks the hashes of assets written by [`AssetServer::save`](crate::AssetServer::save), so that the
+    /// resulting change events do not trigger a reload.
+    /// This should only be set when watching for changes to avoid unnecessary work.
+    pub(crate) saved_asset_hashes: HashMap<AssetPath<'static>, AssetHash>,
+    /// Tracks the paths being written by [`AssetServer::save`](crate::AssetServer::save), whose change events
+    /// are ignored until the write completes.
+    pub(crate) pending_saves: HashSet<AssetPath<'static>>,
+    /// Tracks the [`LoadedFolder`](crate::LoadedFolder) assets loaded by [`AssetServer::load_glob`](crate::AssetServer::load_glob).
+    /// Entries whose handles have been dropped are removed lazily by [`AssetInfos::prune_globs`].
+    pub(crate) globs: HashMap<AssetGlob, UntypedAssetId>,
     pub(crate) handle_providers: TypeIdMap<AssetHandleProvider>,
     pub(crate) dependency_loaded_event_sender: TypeIdMap<fn(&mut World, UntypedAssetId)>,
     pub(crate) dependency_failed_event_sender:
@@ -345,6 +368,16 @@ impl AssetInfos {
         Some(UntypedHandle::Strong(strong_handle))
     }
 
+    /// Removes the [`AssetGlob`]s whose [`LoadedFolder`](crate::LoadedFolder) handles have all been dropped.
+    pub(crate) fn prune_globs(&mut self) {
+        let infos = &self.infos;
+        self.globs.retain(|_, id| {
+            infos
+                .get(id)
+                .is_some_and(|info| info.weak_handle.strong_count() > 0)
+        });
+    }
+
     /// Returns `true` if the asset this path points to is still alive
     pub(crate) fn is_path_alive<'a>(&self, path: impl Into<AssetPath<'a>>) -> bool {
         self.get_path_ids(&path.into())
@@ -378,6 +411,72 @@ impl AssetInfos {
         )
     }
 
+    /// Walks the given assets and their recursive dependencies, tallying their load states.
+    /// Assets that are not tracked by the server (such as those added directly to [`Assets`](crate::Assets))
+    /// are considered loaded.
+    pub(crate) fn load_progress(
+        &self,
+        ids: impl IntoIterator<Item = UntypedAssetId>,
+    ) -> AssetLoadProgress {
+        let mut progress = AssetLoadProgress::default();
+        let mut visited = <HashSet<UntypedAssetId>>::default();
+        let mut stack: Vec<UntypedAssetId> = ids.into_iter().collect();
+        while let Some(id) = stack.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+            let Some(info) = self.infos.get(&id) else {
+                progress.loaded += 1;
+                continue;
+            };
+            progress.bytes_read += info.bytes_read.load(Ordering::Relaxed);
+            match info.load_state {
+                LoadState::NotLoaded | LoadState::Loading => progress.pending += 1,
+                LoadState::Loaded => progress.loaded += 1,
+                LoadState::Failed(_) => progress.failed += 1,
+            }
+            stack.extend(info.dependencies.iter().copied());
+        }
+        progress
+    }
+
+    /// Builds a snapshot of all tracked assets and the dependencies between them.
+    pub(crate) fn dependency_graph(&self) -> AssetDependencyGraph {
+        let mut nodes: HashMap<UntypedAssetId, AssetGraphNode> = self
+            .infos
+            .iter()
+            .map(|(&id, info)| {
+                // Labeled assets are loaded by the loader of the asset they are labeled within.
+                let loader = info.loader.or_else(|| {
+                    let path = info.path.as_ref()?;
+                    path.label()?;
+                    self.get_path_ids(&path.without_label())
+                        .find_map(|id| self.infos.get(&id)?.loader)
+                });
+                let node = AssetGraphNode {
+                    id,
+                    path: info.path.clone(),
+                    load_state: info.load_state.clone(),
+                    dependency_load_state: info.dep_load_state.clone(),
+                    recursive_dependency_load_state: info.rec_dep_load_state.clone(),
+                    loader,
+                    dependencies: info.dependencies.clone(),
+                    dependants: HashSet::default(),
+                    loader_dependencies: info.loader_dependencies.keys().cloned().collect(),
+                };
+                (id, node)
+            })
+            .collect();
+        for (&id, info) in &self.infos {
+            for dependency in &info.dependencies {
+                if let Some(node) = nodes.get_mut(dependency) {
+                    node.dependants.insert(id);
+                }
+            }
+        }
+        AssetDependencyGraph::new(nodes)
+    }
+
     /// Updates [`AssetInfo`] / load state for an asset that has finished loading (and relevant dependencies / dependents).
     pub(crate) fn process_asset_load(
         &mut self,
@@ -392,6 +491,7 @@ impl AssetInfos {
         }
 
         loaded_asset.value.insert(loaded_asset_id, world);
+        let dependencies = loaded_asset.dependencies.clone();
         let mut loading_deps = loaded_asset.dependencies;
         let mut failed_deps = <HashSet<_>>::default();
         let mut dep_error = None;
@@ -488,6 +588,7 @@ impl AssetInfos {
             let info = self
                 .get_mut(loaded_asset_id)
                 .expect("Asset info should always exist at this point");
+            info.dependencies = dependencies;
             info.loading_dependencies = loading_deps;
             info.failed_dependencies = failed_deps;
             info.loading_rec_dependencies = loading_rec_deps;
@@ -320,6 +320,7 @@ impl<T: AssetLoader> AssetLoader for InstrumentedAssetLoader<T> {
     type Asset = T::Asset;
     type Settings = T::Settings;
     type Error = T::Error;
+    const VERSION: u32 = T::VERSION;
 
     fn load(
         &self,
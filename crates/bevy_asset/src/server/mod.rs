@@ -2,19 +2,21 @@ mod info;
 mod loaders;
 
 use crate::{
-    folder::LoadedFolder,
+    folder::{AssetGlob, LoadedFolder},
     io::{
         AssetReaderError, AssetSource, AssetSourceEvent, AssetSourceId, AssetSources,
         AssetWriterError, ErasedAssetReader, MissingAssetSourceError, MissingAssetWriterError,
-        MissingProcessedAssetReaderError, Reader,
+        MissingProcessedAssetReaderError, ProgressReader, Reader,
     },
     loader::{AssetLoader, ErasedAssetLoader, LoadContext, LoadedAsset},
     meta::{
-        loader_settings_meta_transform, AssetActionMinimal, AssetMetaDyn, AssetMetaMinimal,
-        MetaTransform, Settings,
+        get_asset_hash, loader_settings_meta_transform, AssetAction, AssetActionMinimal, AssetHash,
+        AssetMeta, AssetMetaDyn, AssetMetaMinimal, AssetTagsMinimal, MetaTransform, Settings,
     },
     path::AssetPath,
-    Asset, AssetEvent, AssetHandleProvider, AssetId, AssetLoadFailedEvent, AssetMetaCheck, Assets,
+    saver::{AssetSaver, SavedAsset},
+    Asset, AssetDependencyGraph, AssetEvent, AssetHandleProvider, AssetId, AssetLoadFailedEvent,
+    AssetLoadProgress, AssetMetaCheck, AssetSaveFailedEvent, AssetSavedEvent, Assets,
     DeserializeMetaError, ErasedLoadedAsset, Handle, LoadedUntypedAsset, UnapprovedPathMode,
     UntypedAssetId, UntypedAssetLoadFailedEvent, UntypedHandle,
 };
@@ -26,8 +28,11 @@ use alloc::{
 };
 use atomicow::CowArc;
 use bevy_ecs::prelude::*;
-use bevy_platform::collections::HashSet;
-use bevy_tasks::IoTaskPool;
+use bevy_platform::{
+    collections::{HashMap, HashSet},
+    sync::atomic::Ordering,
+};
+use bevy_tasks::{BoxedFuture, IoTaskPool};
 use core::{any::TypeId, future::Future, panic::AssertUnwindSafe, task::Poll};
 use crossbeam_channel::{Receiver, Sender};
 use either::Either;
@@ -37,7 +42,7 @@ use loaders::*;
 use parking_lot::{RwLock, RwLockWriteGuard};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
-use tracing::{error, info};
+use tracing::{debug, error, info};
 
 /// Loads and tracks the state of [`Asset`] values from a configured [`AssetReader`](crate::io::AssetReader).
 /// This can be used to kick off new asset loads and retrieve their current load states.
@@ -662,7 +667,7 @@ impl AssetServer {
 
         let path = path.into_owned();
         let path_clone = path.clone();
-        let (mut meta, loader, mut reader) = self
+        let (mut meta, loader, reader) = self
             .get_meta_loader_and_reader(&path_clone, input_handle_type_id)
             .await
             .inspect_err(|e| {
@@ -766,12 +771,26 @@ impl AssetServer {
             (asset_id.unwrap(), None, path.clone())
         };
 
+        // Count the bytes read for this load so that it can be reported by `AssetServer::load_progress`.
+        let bytes_read = self
+            .data
+            .infos
+            .write()
+            .get_mut(base_asset_id)
+            .map(|info| {
+                info.loader = Some(loader.type_name());
+                info.bytes_read.store(0, Ordering::Relaxed);
+                info.bytes_read.clone()
+            })
+            .unwrap_or_default();
+        let mut reader = ProgressReader::new(reader, bytes_read);
+
         match self
             .load_with_meta_loader_and_reader(
                 &base_path,
                 meta.as_ref(),
                 &*loader,
-                &mut *reader,
+                &mut reader,
                 true,
                 false,
             )
@@ -983,15 +1002,103 @@ impl AssetServer {
         handle
     }
 
+    /// Loads all assets matching the given [`AssetGlob`]. The [`LoadedFolder`] asset (when it loads) will
+    /// contain handles to all matching assets. You can wait for all assets to load by checking the
+    /// [`LoadedFolder`]'s [`RecursiveDependencyLoadState`].
+    ///
+    /// ```
+    /// # use bevy_asset::{prelude::*, AssetGlob};
+    /// # use bevy_ecs::prelude::*;
+    /// fn load_textures(asset_server: Res<AssetServer>) {
+    ///     // Every KTX2 texture under `textures`, including subfolders.
+    ///     let _all = asset_server.load_glob("textures/**/*.ktx2");
+    ///     // Only the textures whose `.meta` files declare the `hd` tag.
+    ///     let _hd = asset_server.load_glob(AssetGlob::new("textures/**/*.ktx2").with_tag("hd"));
+    /// }
+    /// ```
+    ///
+    /// Loading the same glob multiple times will return the same handle. If the `file_watcher`
+    /// feature is enabled, [`LoadedFolder`] handles will reload when a matching file is added or
+    /// removed, or when the `.meta` file of a matching file changes.
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
+    pub fn load_glob(&self, glob: impl Into<AssetGlob>) -> Handle<LoadedFolder> {
+        let glob = glob.into();
+        let mut infos = self.data.infos.write();
+        infos.prune_globs();
+        if let Some(handle) = infos
+            .globs
+            .get(&glob)
+            .and_then(|id| infos.get_id_handle(*id))
+        {
+            return handle.typed_debug_checked();
+        }
+        let handle = infos.create_loading_handle_untyped(
+            TypeId::of::<LoadedFolder>(),
+            core::any::type_name::<LoadedFolder>(),
+        );
+        infos.globs.insert(glob.clone(), handle.id());
+        drop(infos);
+
+        self.load_glob_internal(handle.id(), glob);
+        handle.typed_debug_checked()
+    }
+
     pub(crate) fn load_folder_internal(&self, id: UntypedAssetId, path: AssetPath) {
+        self.load_folder_filtered(id, path.into_owned(), None);
+    }
+
+    pub(crate) fn load_glob_internal(&self, id: UntypedAssetId, glob: AssetGlob) {
+        let path = AssetPath::from(glob.base_path()).with_source(glob.source().clone());
+        self.load_folder_filtered(id, path, Some(glob));
+    }
+
+    /// Loads the assets in the folder at `path` recursively, skipping those that don't match `glob` (if set).
+    fn load_folder_filtered(
+        &self,
+        id: UntypedAssetId,
+        path: AssetPath<'static>,
+        glob: Option<AssetGlob>,
+    ) {
+        async fn has_tags(
+            source: &AssetSourceId<'static>,
+            path: &Path,
+            reader: &dyn ErasedAssetReader,
+            glob: &AssetGlob,
+        ) -> Result<bool, AssetLoadError> {
+            // Without a tag filter, every asset matches and its meta does not need to be read.
+            if glob.tags().is_empty() {
+                return Ok(true);
+            }
+            let tags = match reader.read_meta_bytes(path).await {
+                Ok(meta_bytes) => {
+                    ron::de::from_bytes::<AssetTagsMinimal>(&meta_bytes)
+                        .map_err(|e| AssetLoadError::DeserializeMeta {
+                            path: AssetPath::from_path(path)
+                                .clone_owned()
+                                .with_source(source.clone()),
+                            error: DeserializeMetaError::DeserializeMinimal(e).into(),
+                        })?
+                        .tags
+                }
+                Err(AssetReaderError::NotFound(_)) => Vec::new(),
+                Err(err) => return Err(err.into()),
+            };
+            Ok(glob.matches_tags(&tags))
+        }
+
         async fn load_folder<'a>(
             source: AssetSourceId<'static>,
             path: &'a Path,
             reader: &'a dyn ErasedAssetReader,
             server: &'a AssetServer,
+            glob: Option<&'a AssetGlob>,
             handles: &'a mut Vec<UntypedHandle>,
         ) -> Result<(), AssetLoadError> {
-            let is_dir = reader.is_directory(path).await?;
+            let is_dir = match reader.is_directory(path).await {
+                // A glob matches nothing if its base folder doesn't exist.
+                Err(AssetReaderError::NotFound(_)) if glob.is_some() => return Ok(()),
+                result => result?,
+            };
             if is_dir {
                 let mut path_stream = reader.read_directory(path.as_ref()).await?;
                 while let Some(child_path) = path_stream.next().await {
@@ -1001,10 +1108,21 @@ impl AssetServer {
                             &child_path,
                             reader,
                             server,
+                            glob,
                             handles,
                         ))
                         .await?;
                     } else {
+                        let skip = match glob {
+                            Some(glob) => {
+                                !glob.matches(&child_path)
+                                    || !has_tags(&source, &child_path, reader, glob).await?
+                            }
+                            None => false,
+                        };
+                        if skip {
+                            continue;
+                        }
                         let path = child_path.to_str().expect("Path should be a valid string.");
                         let asset_path = AssetPath::parse(path).with_source(source.clone());
                         match server.load_untyped_async(asset_path).await {
@@ -1022,7 +1140,6 @@ impl AssetServer {
             Ok(())
         }
 
-        let path = path.into_owned();
         let server = self.clone();
         IoTaskPool::get()
             .spawn(async move {
@@ -1049,7 +1166,16 @@ impl AssetServer {
                 };
 
                 let mut handles = Vec::new();
-                match load_folder(source.id(), path.path(), asset_reader, &server, &mut handles).await {
+                match load_folder(
+                    source.id(),
+                    path.path(),
+                    asset_reader,
+                    &server,
+                    glob.as_ref(),
+                    &mut handles,
+                )
+                .await
+                {
                     Ok(_) => server.send_asset_event(InternalAssetEvent::Loaded {
                         id,
                         loaded_asset: LoadedAsset::new_with_dependencies(
@@ -1184,6 +1310,33 @@ impl AssetServer {
         )
     }
 
+    /// Returns the combined [`AssetLoadProgress`] of the given assets and all of their recursive dependencies.
+    ///
+    /// Each asset is counted once, even if it is reachable from multiple of the given `ids`. Dependencies
+    /// only become known once the asset that depends on them has loaded, so the total may grow while
+    /// loading is in progress.
+    ///
+    /// To track a set of handles over multiple frames, see [`LoadingGroup`](crate::LoadingGroup).
+    pub fn load_progress(
+        &self,
+        ids: impl IntoIterator<Item = impl Into<UntypedAssetId>>,
+    ) -> AssetLoadProgress {
+        self.data
+            .infos
+            .read()
+            .load_progress(ids.into_iter().map(Into::into))
+    }
+
+    /// Returns a snapshot of every asset tracked by this server, including its load state, load error,
+    /// the loader used to load it, and its dependencies and dependants.
+    ///
+    /// This is intended for debugging and tooling. Use [`AssetDependencyGraph::subgraph`] to narrow it
+    /// down to the dependency tree of specific assets, for example to find which dependency is
+    /// keeping a scene from finishing loading.
+    pub fn dependency_graph(&self) -> AssetDependencyGraph {
+        self.data.infos.read().dependency_graph()
+    }
+
     /// Returns an active handle for the given path, if the asset at the given path has already started loading,
     /// or is still "alive".
     pub fn get_handle<'a, A: Asset>(&self, path: impl Into<AssetPath<'a>>) -> Option<Handle<A>> {
@@ -1388,6 +1541,17 @@ impl AssetServer {
                             error: e.into(),
                         }
                     })?;
+                    match meta.loader_version() {
+                        Some(version) if version != loader.version() => {
+                            return Err(AssetLoadError::IncompatibleLoaderVersion {
+                                path: asset_path.clone_owned(),
+                                loader_name: loader.type_name(),
+                                expected: loader.version(),
+                                found: version,
+                            });
+                        }
+                        _ => {}
+                    }
 
                     Ok((meta, loader, reader))
                 }
@@ -1634,6 +1798,147 @@ impl AssetServer {
 
         Ok(())
     }
+
+    /// Saves the asset referenced by `handle` to `path` using the [`AssetSaver`] `S`, writing both the asset
+    /// and a `.meta` file that loads it with [`AssetSaver::OutputLoader`]. The asset is written through the
+    /// [`AssetWriter`](crate::io::AssetWriter) of the path's [`AssetSource`].
+    ///
+    /// The save is queued and the asset is cloned the next time [`handle_internal_asset_events`] runs, so the
+    /// saved data reflects the asset as it is at that point. The clone is then serialized and written on the
+    /// [`IoTaskPool`], the `.meta` file first.
+    /// An [`AssetSavedEvent`](crate::AssetSavedEvent) is sent once the files are written, or an
+    /// [`AssetSaveFailedEvent`](crate::AssetSaveFailedEvent) if saving fails. The asset must already be
+    /// present in its [`Assets`] collection.
+    ///
+    /// When watching for changes, the change events caused by this write will not trigger a reload of `path`,
+    /// as long as the file contents still match what was saved. In [`AssetServerMode::Processed`] the
+    /// written file is reprocessed, and the processed result is reloaded as usual.
+    pub fn save<'a, A: Asset + Clone, S: AssetSaver<Asset = A> + FromWorld>(
+        &self,
+        handle: &Handle<A>,
+        path: impl Into<AssetPath<'a>>,
+        settings: S::Settings,
+    ) {
+        let id = handle.id().untyped();
+        // Keep the asset alive until it has been serialized.
+        let handle = handle.clone();
+        self.send_asset_event(InternalAssetEvent::Save {
+            id,
+            path: path.into().into_owned(),
+            serialize: Box::new(move |world| {
+                let saver = S::from_world(world);
+                let asset = world
+                    .get_resource::<Assets<A>>()
+                    .and_then(|assets| assets.get(&handle))
+                    .ok_or(AssetSaveError::MissingAsset(id))?
+                    .clone();
+                Ok(Box::pin(async move {
+                    serialize_asset(&saver, &asset, &settings).await
+                }))
+            }),
+        });
+    }
+
+    /// Serializes the asset for [`AssetServer::save`] and writes the resulting asset and meta bytes to `path`.
+    fn write_saved_asset(
+        &self,
+        id: UntypedAssetId,
+        path: AssetPath<'static>,
+        serialize: SerializeAssetFuture,
+    ) {
+        let server = self.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let result = async {
+                    let (asset_bytes, meta_bytes) = serialize.await?;
+                    let writer = server.get_source(path.source())?.writer()?;
+                    {
+                        let mut infos = server.data.infos.write();
+                        if infos.watching_for_changes {
+                            infos
+                                .saved_asset_hashes
+                                .insert(path.clone(), get_asset_hash(&meta_bytes, &asset_bytes));
+                        }
+                    }
+                    // The meta is written first, so that the asset is never loaded with a stale meta.
+                    writer.write_meta_bytes(path.path(), &meta_bytes).await?;
+                    writer.write_bytes(path.path(), &asset_bytes).await?;
+                    Ok::<_, AssetSaveError>(())
+                }
+                .await;
+                match result {
+                    Ok(()) => server.send_asset_event(InternalAssetEvent::Saved { id, path }),
+                    Err(error) => {
+                        error!("Failed to save asset {path}: {error}");
+                        server.send_asset_event(InternalAssetEvent::SaveFailed { id, path, error });
+                    }
+                }
+            })
+            .detach();
+    }
+
+    /// Kicks off a reload of `path` (and the `dependents` that loaded it as a loader dependency), unless the
+    /// current contents of `path` match the `hash` of the asset that was last saved there by [`AssetServer::save`].
+    fn reload_if_changed_since_save(
+        &self,
+        path: AssetPath<'static>,
+        hash: AssetHash,
+        dependents: HashSet<AssetPath<'static>>,
+    ) {
+        let server = self.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let current_hash = async {
+                    let source = server.get_source(path.source()).ok()?;
+                    let reader = match server.data.mode {
+                        AssetServerMode::Unprocessed => source.reader(),
+                        AssetServerMode::Processed => source.processed_reader().ok()?,
+                    };
+                    let mut asset_bytes = Vec::new();
+                    let mut asset_reader = reader.read(path.path()).await.ok()?;
+                    asset_reader.read_to_end(&mut asset_bytes).await.ok()?;
+                    let meta_bytes = reader.read_meta_bytes(path.path()).await.ok()?;
+                    Some(get_asset_hash(&meta_bytes, &asset_bytes))
+                }
+                .await;
+
+                if current_hash == Some(hash) {
+                    debug!("Not reloading {path} because it is unchanged since it was saved");
+                    return;
+                }
+
+                server.data.infos.write().saved_asset_hashes.remove(&path);
+                info!("Reloading {path} because it has changed");
+                server.reload(path);
+                for dependent in dependents {
+                    info!("Reloading {dependent} because it has changed");
+                    server.reload(dependent);
+                }
+            })
+            .detach();
+    }
+}
+
+/// Serializes `asset` into asset bytes and meta bytes using the [`AssetSaver`] `S`.
+async fn serialize_asset<S: AssetSaver>(
+    saver: &S,
+    asset: &S::Asset,
+    settings: &S::Settings,
+) -> Result<(Vec<u8>, Vec<u8>), AssetSaveError> {
+    let mut asset_bytes = Vec::new();
+    let loader_settings = saver
+        .save(&mut asset_bytes, SavedAsset::from_asset(asset), settings)
+        .await
+        .map_err(|error| AssetSaveError::AssetSaverError {
+            saver_name: core::any::type_name::<S>(),
+            error: error.into().into(),
+        })?;
+
+    let meta = AssetMeta::<S::OutputLoader, ()>::new(AssetAction::Load {
+        loader: core::any::type_name::<S::OutputLoader>().to_string(),
+        settings: loader_settings,
+    });
+    Ok((asset_bytes, meta.serialize()))
 }
 
 /// A system that manages internal [`AssetServer`] events, such as finalizing asset loads.
@@ -1642,6 +1947,9 @@ pub fn handle_internal_asset_events(world: &mut World) {
         let mut infos = server.data.infos.write();
         let var_name = vec![];
         let mut untyped_failures = var_name;
+        let mut saves = vec![];
+        let mut save_failures = vec![];
+        let mut pending_writes = vec![];
         for event in server.data.asset_event_receiver.try_iter() {
             match event {
                 InternalAssetEvent::Loaded { id, loaded_asset } => {
@@ -1681,12 +1989,41 @@ pub fn handle_internal_asset_events(world: &mut World) {
                         .expect("Asset failed event sender should exist");
                     sender(world, id, path, error);
                 }
+                InternalAssetEvent::Save {
+                    id,
+                    path,
+                    serialize,
+                } => match serialize(world) {
+                    Ok(serialize) => {
+                        infos.pending_saves.insert(path.clone());
+                        pending_writes.push((id, path, serialize));
+                    }
+                    Err(error) => {
+                        error!("Failed to save asset {path}: {error}");
+                        save_failures.push(AssetSaveFailedEvent { id, path, error });
+                    }
+                },
+                InternalAssetEvent::Saved { id, path } => {
+                    infos.pending_saves.remove(&path);
+                    saves.push(AssetSavedEvent { id, path });
+                }
+                InternalAssetEvent::SaveFailed { id, path, error } => {
+                    infos.pending_saves.remove(&path);
+                    infos.saved_asset_hashes.remove(&path);
+                    save_failures.push(AssetSaveFailedEvent { id, path, error });
+                }
             }
         }
 
         if !untyped_failures.is_empty() {
             world.send_event_batch(untyped_failures);
         }
+        if !saves.is_empty() {
+            world.send_event_batch(saves);
+        }
+        if !save_failures.is_empty() {
+            world.send_event_batch(save_failures);
+        }
 
         fn queue_ancestors(
             asset_path: &AssetPath,
@@ -1714,15 +2051,67 @@ pub fn handle_internal_asset_events(world: &mut World) {
             }
         };
 
+        let reload_matching_globs =
+            |path: &Path, is_folder: bool, source: &AssetSourceId<'static>| {
+                for (glob, id) in &infos.globs {
+                    let matches = if is_folder {
+                        let base_path = glob.base_path();
+                        path.starts_with(&base_path) || base_path.starts_with(path)
+                    } else {
+                        glob.matches(path)
+                    };
+                    if glob.source() != source || !matches || infos.get_id_handle(*id).is_none() {
+                        continue;
+                    }
+                    info!("Reloading glob {glob} because the content has changed");
+                    server.load_glob_internal(*id, glob.clone());
+                }
+            };
+
         let mut paths_to_reload = <HashSet<_>>::default();
+        let mut saved_paths_to_check = <HashMap<_, _>>::default();
         let mut handle_event = |source: AssetSourceId<'static>, event: AssetSourceEvent| {
+            // Globs may select assets by the tags in their meta files, so they also need to reload when those change.
+            match &event {
+                AssetSourceEvent::AddedAsset(path)
+                | AssetSourceEvent::RemovedAsset(path)
+                | AssetSourceEvent::AddedMeta(path)
+                | AssetSourceEvent::ModifiedMeta(path)
+                | AssetSourceEvent::RemovedMeta(path) => {
+                    reload_matching_globs(path, false, &source);
+                }
+                AssetSourceEvent::RenamedAsset { old, new } => {
+                    reload_matching_globs(old, false, &source);
+                    reload_matching_globs(new, false, &source);
+                }
+                AssetSourceEvent::AddedFolder(path) | AssetSourceEvent::RemovedFolder(path) => {
+                    reload_matching_globs(path, true, &source);
+                }
+                AssetSourceEvent::RenamedFolder { old, new } => {
+                    reload_matching_globs(old, true, &source);
+                    reload_matching_globs(new, true, &source);
+                }
+                _ => {}
+            }
             match event {
                 // TODO: if the asset was processed and the processed file was changed, the first modified event
                 // should be skipped?
                 AssetSourceEvent::ModifiedAsset(path) | AssetSourceEvent::ModifiedMeta(path) => {
                     let path = AssetPath::from(path).with_source(source);
-                    queue_ancestors(&path, &infos, &mut paths_to_reload);
-                    paths_to_reload.insert(path);
+                    if infos.pending_saves.contains(&path) {
+                        // The files are still being written by `AssetServer::save`, and are only complete once
+                        // it is done.
+                        debug!("Not reloading {path} because it is being saved");
+                    } else if let Some(hash) = infos.saved_asset_hashes.get(&path) {
+                        // This may be the change caused by `AssetServer::save`, which should not cause a reload.
+                        let (_, dependents) = saved_paths_to_check
+                            .entry(path.clone())
+                            .or_insert_with(|| (*hash, HashSet::default()));
+                        queue_ancestors(&path, &infos, dependents);
+                    } else {
+                        queue_ancestors(&path, &infos, &mut paths_to_reload);
+                        paths_to_reload.insert(path);
+                    }
                 }
                 AssetSourceEvent::RenamedFolder { old, new } => {
                     reload_parent_folders(old, &source);
@@ -1762,10 +2151,20 @@ pub fn handle_internal_asset_events(world: &mut World) {
             server.reload(path);
         }
 
+        for (path, (hash, dependents)) in saved_paths_to_check {
+            server.reload_if_changed_since_save(path, hash, dependents);
+        }
+
         #[cfg(not(any(target_arch = "wasm32", not(feature = "multi_threaded"))))]
         infos
             .pending_tasks
             .retain(|_, load_task| !load_task.is_finished());
+
+        // The writes lock the asset infos, and run immediately without the `multi_threaded` feature.
+        drop(infos);
+        for (id, path, serialize) in pending_writes {
+            server.write_saved_asset(id, path, serialize);
+        }
     });
 }
 
@@ -1783,8 +2182,31 @@ pub(crate) enum InternalAssetEvent {
         path: AssetPath<'static>,
         error: AssetLoadError,
     },
+    Save {
+        id: UntypedAssetId,
+        path: AssetPath<'static>,
+        serialize: SerializeAssetFn,
+    },
+    Saved {
+        id: UntypedAssetId,
+        path: AssetPath<'static>,
+    },
+    SaveFailed {
+        id: UntypedAssetId,
+        path: AssetPath<'static>,
+        error: AssetSaveError,
+    },
 }
 
+/// Copies an asset stored in the [`World`], returning the future serializing it into its asset bytes and meta
+/// bytes.
+pub(crate) type SerializeAssetFn =
+    Box<dyn FnOnce(&mut World) -> Result<SerializeAssetFuture, AssetSaveError> + Send + Sync>;
+
+/// Serializes an asset into its asset bytes and meta bytes.
+pub(crate) type SerializeAssetFuture =
+    BoxedFuture<'static, Result<(Vec<u8>, Vec<u8>), AssetSaveError>>;
+
 /// The load state of an asset.
 #[derive(Component, Clone, Debug)]
 pub enum LoadState {
@@ -1931,6 +2353,16 @@ pub enum AssetLoadError {
         path: AssetPath<'static>,
         error: Box<DeserializeMetaError>,
     },
+    #[error(
+        "Asset '{path}' was produced for version {found} of loader '{loader_name}', but the loader is at version {expected}. Reprocess the asset to load it."
+    )]
+    #[from(ignore)]
+    IncompatibleLoaderVersion {
+        path: AssetPath<'static>,
+        loader_name: &'static str,
+        expected: u32,
+        found: u32,
+    },
     #[error("Asset '{path}' is configured to be processed. It cannot be loaded directly.")]
     #[from(ignore)]
     CannotLoadProcessedAsset { path: AssetPath<'static> },
@@ -2050,6 +2482,35 @@ pub enum WaitForAssetError {
     DependencyFailed(Arc<AssetLoadError>),
 }
 
+/// An error that occurs while saving an asset with [`AssetServer::save`].
+#[derive(Error, Debug, Clone)]
+pub enum AssetSaveError {
+    /// The asset is not stored in its [`Assets`] collection, for example because it has not finished loading.
+    #[error("asset {0} does not exist in its `Assets` collection")]
+    MissingAsset(UntypedAssetId),
+    /// The [`AssetSource`] of the path doesn't exist.
+    #[error(transparent)]
+    MissingAssetSource(#[from] MissingAssetSourceError),
+    /// The [`AssetSource`] of the path has no [`AssetWriter`](crate::io::AssetWriter).
+    #[error(transparent)]
+    MissingAssetWriter(#[from] MissingAssetWriterError),
+    /// The [`AssetSaver`] returned an error.
+    #[error("asset saver '{saver_name}' failed to save the asset: {error}")]
+    AssetSaverError {
+        saver_name: &'static str,
+        error: Arc<dyn core::error::Error + Send + Sync + 'static>,
+    },
+    /// Writing the saved bytes to the [`AssetWriter`](crate::io::AssetWriter) failed.
+    #[error("failed to write the saved asset: {0}")]
+    AssetWriterError(Arc<AssetWriterError>),
+}
+
+impl From<AssetWriterError> for AssetSaveError {
+    fn from(error: AssetWriterError) -> Self {
+        Self::AssetWriterError(Arc::new(error))
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum WriteDefaultMetaError {
     #[error(transparent)]
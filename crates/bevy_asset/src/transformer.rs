@@ -24,6 +24,16 @@ pub trait AssetTransformer: Send + Sync + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The type of [error](`std::error::Error`) which could be encountered by this transformer.
     type Error: Into<Box<dyn core::error::Error + Send + Sync + 'static>>;
+    /// The version of this transformer. This should be incremented whenever a change to the transformer changes its
+    /// output.
+    ///
+    /// When this transformer is used by [`LoadTransformAndSave`], its version is part of the processor's
+    /// [`Process::VERSION`], so bumping it causes the [`AssetProcessor`] to reprocess affected assets.
+    ///
+    /// [`LoadTransformAndSave`]: crate::processor::LoadTransformAndSave
+    /// [`Process::VERSION`]: crate::processor::Process::VERSION
+    /// [`AssetProcessor`]: crate::processor::AssetProcessor
+    const VERSION: u32 = 0;
 
     /// Transforms the given [`TransformedAsset`] to [`AssetTransformer::AssetOutput`].
     /// The [`TransformedAsset`]'s `labeled_assets` can be altered to add new Labeled Sub-Assets
@@ -0,0 +1,216 @@
+//! Checks of the state of the world, failing the test when they don't hold.
+
+use bevy_ecs::{prelude::*, reflect::AppTypeRegistry};
+use bevy_reflect::{
+    serde::TypedReflectDeserializer, GetPath, PartialReflect, Reflect, TypeRegistry,
+};
+use serde::de::DeserializeSeed;
+
+/// Checks that an entity has a [`Name`] equal to `name`.
+pub(crate) fn entity_exists(world: &mut World, name: &str) -> Result<(), String> {
+    match named_entity(world, name) {
+        Some(_) => Ok(()),
+        None => Err(format!("no entity is named `{name}`")),
+    }
+}
+
+/// Checks that `count` entities have the component with the type path `component`.
+///
+/// Like other queries, this skips the entities excluded by the [`DefaultQueryFilters`], like the
+/// disabled ones.
+///
+/// [`DefaultQueryFilters`]: bevy_ecs::entity_disabling::DefaultQueryFilters
+pub(crate) fn query_count(world: &mut World, component: &str, count: usize) -> Result<(), String> {
+    let type_id = world
+        .resource::<AppTypeRegistry>()
+        .read()
+        .get_with_type_path(component)
+        .ok_or_else(|| format!("`{component}` is not registered"))?
+        .type_id();
+    let actual = match world.components().get_id(type_id) {
+        Some(id) => QueryBuilder::<Entity>::new(world)
+            .with_id(id)
+            .build()
+            .iter(world)
+            .count(),
+        None => 0,
+    };
+    if actual == count {
+        Ok(())
+    } else {
+        Err(format!(
+            "{actual} entities have `{component}`, expected {count}"
+        ))
+    }
+}
+
+/// Checks that the field at `path` of the resource with the type path `resource` equals
+/// `value`, given in RON.
+pub(crate) fn resource_equals(
+    world: &World,
+    resource: &str,
+    path: &str,
+    value: &str,
+) -> Result<(), String> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let reflect_resource = registry
+        .get_with_type_path(resource)
+        .and_then(|registration| registration.data::<ReflectResource>())
+        .ok_or_else(|| format!("`{resource}` is not a reflected resource"))?;
+    let reflected = reflect_resource
+        .reflect(world)
+        .map_err(|_| format!("resource `{resource}` does not exist"))?;
+    field_equals(&registry, reflected, path, value)
+        .map_err(|error| format!("resource `{resource}`: {error}"))
+}
+
+/// Checks that the field at `path` of the component with the type path `component`, on the
+/// entity named `entity`, equals `value`, given in RON.
+pub(crate) fn component_equals(
+    world: &mut World,
+    entity: &str,
+    component: &str,
+    path: &str,
+    value: &str,
+) -> Result<(), String> {
+    let id = named_entity(world, entity).ok_or_else(|| format!("no entity is named `{entity}`"))?;
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let reflect_component = registry
+        .get_with_type_path(component)
+        .and_then(|registration| registration.data::<ReflectComponent>())
+        .ok_or_else(|| format!("`{component}` is not a reflected component"))?;
+    let reflected = reflect_component
+        .reflect(world.entity(id))
+        .ok_or_else(|| format!("entity `{entity}` has no `{component}`"))?;
+    field_equals(&registry, reflected, path, value)
+        .map_err(|error| format!("`{component}` of entity `{entity}`: {error}"))
+}
+
+fn named_entity(world: &mut World, name: &str) -> Option<Entity> {
+    world
+        .query::<(Entity, &Name)>()
+        .iter(world)
+        .find(|(_, entity_name)| entity_name.as_str() == name)
+        .map(|(entity, _)| entity)
+}
+
+/// Checks that the field at `path` of `reflected`, or `reflected` itself if `path` is empty,
+/// equals `value`, given in RON.
+fn field_equals(
+    registry: &TypeRegistry,
+    reflected: &dyn Reflect,
+    path: &str,
+    value: &str,
+) -> Result<(), String> {
+    let field: &dyn PartialReflect = if path.is_empty() {
+        reflected.as_partial_reflect()
+    } else {
+        reflected
+            .reflect_path(path)
+            .map_err(|error| format!("invalid path `{path}`: {error}"))?
+    };
+    let registration = field
+        .get_represented_type_info()
+        .and_then(|info| registry.get(info.type_id()))
+        .ok_or_else(|| format!("the type of `{path}` is not registered"))?;
+    let mut deserializer = ron::Deserializer::from_str(value)
+        .map_err(|error| format!("invalid value `{value}`: {error}"))?;
+    let expected = TypedReflectDeserializer::new(registration, registry)
+        .deserialize(&mut deserializer)
+        .map_err(|error| format!("invalid value `{value}`: {error}"))?;
+    if field.reflect_partial_eq(&*expected) == Some(true) {
+        Ok(())
+    } else {
+        Err(format!("`{path}` is {field:?}, expected {value}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ci_testing::{systems::send_events, *};
+    use bevy_app::prelude::*;
+    use bevy_ecs::{entity_disabling::Disabled, prelude::*};
+    use bevy_reflect::Reflect;
+
+    #[derive(Resource, Reflect)]
+    #[reflect(Resource)]
+    struct Score {
+        value: u32,
+    }
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Health(f32);
+
+    fn run(events: Vec<CiTestingEvent>) -> Option<AppExit> {
+        let mut app = App::new();
+        app.register_type::<Score>()
+            .register_type::<Health>()
+            .insert_resource(Score { value: 10 })
+            .insert_resource(CiTestingConfig {
+                events: events
+                    .into_iter()
+                    .map(|event| CiTestingEventOnFrame(0, event))
+                    .collect(),
+                ..Default::default()
+            })
+            .add_event::<AppExit>()
+            .add_systems(Update, send_events);
+        app.world_mut().spawn((Name::new("Player"), Health(100.0)));
+        app.world_mut().spawn(Health(50.0));
+        // Disabled entities aren't counted.
+        app.world_mut().spawn((Health(0.0), Disabled));
+
+        app.update();
+        app.should_exit()
+    }
+
+    fn resource(path: &str, value: &str) -> CiTestingEvent {
+        CiTestingEvent::AssertResource {
+            resource: "bevy_dev_tools::ci_testing::assertions::tests::Score".into(),
+            path: path.into(),
+            value: value.into(),
+        }
+    }
+
+    fn health(value: &str) -> CiTestingEvent {
+        CiTestingEvent::AssertComponent {
+            entity: "Player".into(),
+            component: "bevy_dev_tools::ci_testing::assertions::tests::Health".into(),
+            path: ".0".into(),
+            value: value.into(),
+        }
+    }
+
+    fn count(count: usize) -> CiTestingEvent {
+        CiTestingEvent::AssertQueryCount {
+            component: "bevy_dev_tools::ci_testing::assertions::tests::Health".into(),
+            count,
+        }
+    }
+
+    #[test]
+    fn assertions() {
+        assert_eq!(
+            run(vec![
+                CiTestingEvent::AssertEntityExists("Player".into()),
+                resource("value", "10"),
+                resource("", "(value: 10)"),
+                health("100.0"),
+                count(2),
+            ]),
+            None
+        );
+
+        for failing in [
+            CiTestingEvent::AssertEntityExists("Enemy".into()),
+            resource("value", "11"),
+            resource("missing", "10"),
+            resource("value", "invalid"),
+            health("50.0"),
+            count(1),
+        ] {
+            assert_eq!(run(vec![failing]), Some(AppExit::error()));
+        }
+    }
+}
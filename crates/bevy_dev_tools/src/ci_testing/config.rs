@@ -1,4 +1,10 @@
 use bevy_ecs::prelude::*;
+use bevy_input::{
+    gamepad::{GamepadAxis, GamepadButton},
+    keyboard::KeyCode,
+    mouse::MouseButton,
+};
+use bevy_math::Vec2;
 use serde::Deserialize;
 
 /// A configuration struct for automated CI testing.
@@ -49,6 +55,99 @@ pub enum CiTestingEvent {
     AppExit,
     /// Sends a [`CiTestingCustomEvent`] using the given [`String`].
     Custom(String),
+    /// Presses a key of the keyboard, in the primary window.
+    KeyPress(KeyCode),
+    /// Releases a key of the keyboard, in the primary window.
+    KeyRelease(KeyCode),
+    /// Types the given text in the primary window, pressing and releasing a key for each
+    /// character.
+    TextInput(String),
+    /// Moves the cursor to the given position of the primary window, in logical pixels.
+    CursorMove(Vec2),
+    /// Presses a mouse button, in the primary window.
+    MouseButtonPress(MouseButton),
+    /// Releases a mouse button, in the primary window.
+    MouseButtonRelease(MouseButton),
+    /// Changes the value of a button of a virtual gamepad, between `0.0` (released) and `1.0`
+    /// (fully pressed).
+    ///
+    /// Virtual gamepads are numbered from `0`, and are connected the first time they are used.
+    GamepadButton {
+        /// The number of the virtual gamepad.
+        #[serde(default)]
+        gamepad: usize,
+        /// The button to change.
+        button: GamepadButton,
+        /// The new value of the button.
+        value: f32,
+    },
+    /// Changes the value of an axis of a virtual gamepad, between `-1.0` and `1.0`.
+    ///
+    /// Virtual gamepads are numbered from `0`, and are connected the first time they are used.
+    GamepadAxis {
+        /// The number of the virtual gamepad.
+        #[serde(default)]
+        gamepad: usize,
+        /// The axis to change.
+        axis: GamepadAxis,
+        /// The new value of the axis.
+        value: f32,
+    },
+    /// Resizes the primary window to the given size, in logical pixels.
+    WindowResize(Vec2),
+    /// Checks that an entity has a [`Name`] equal to the given string.
+    ///
+    /// Failed assertions log an error and stop the program by sending [`AppExit::Error`].
+    ///
+    /// [`AppExit::Error`]: bevy_app::AppExit::Error
+    AssertEntityExists(String),
+    /// Checks the number of entities with a component.
+    ///
+    /// Failed assertions log an error and stop the program by sending [`AppExit::Error`].
+    ///
+    /// [`AppExit::Error`]: bevy_app::AppExit::Error
+    AssertQueryCount {
+        /// The full type path of the component, which must be registered.
+        component: String,
+        /// The expected number of entities.
+        count: usize,
+    },
+    /// Checks the value of a reflected resource, or of one of its fields.
+    ///
+    /// Failed assertions log an error and stop the program by sending [`AppExit::Error`].
+    ///
+    /// [`AppExit::Error`]: bevy_app::AppExit::Error
+    AssertResource {
+        /// The full type path of the resource.
+        resource: String,
+        /// The [reflection path] of the field to check, or an empty string for the whole
+        /// resource.
+        ///
+        /// [reflection path]: bevy_reflect::GetPath
+        #[serde(default)]
+        path: String,
+        /// The expected value, in RON.
+        value: String,
+    },
+    /// Checks the value of a reflected component of a named entity, or of one of its fields.
+    ///
+    /// Failed assertions log an error and stop the program by sending [`AppExit::Error`].
+    ///
+    /// [`AppExit::Error`]: bevy_app::AppExit::Error
+    AssertComponent {
+        /// The [`Name`] of the entity.
+        entity: String,
+        /// The full type path of the component.
+        component: String,
+        /// The [reflection path] of the field to check, or an empty string for the whole
+        /// component.
+        ///
+        /// [reflection path]: bevy_reflect::GetPath
+        #[serde(default)]
+        path: String,
+        /// The expected value, in RON.
+        value: String,
+    },
 }
 
 /// A custom event that can be configured from a configuration file for CI testing.
@@ -69,6 +168,18 @@ mod tests {
     events: [
         (100, Custom("Hello, world!")),
         (200, Screenshot),
+        (210, KeyPress(Space)),
+        (211, KeyRelease(Space)),
+        (220, TextInput("hi")),
+        (230, CursorMove((10.0, 20.0))),
+        (231, MouseButtonPress(Left)),
+        (240, GamepadButton(button: South, value: 1.0)),
+        (241, GamepadAxis(gamepad: 1, axis: LeftStickX, value: -0.5)),
+        (250, WindowResize((640.0, 480.0))),
+        (260, AssertEntityExists("Player")),
+        (261, AssertQueryCount(component: "game::Enemy", count: 3)),
+        (262, AssertResource(resource: "game::Score", path: "value", value: "10")),
+        (263, AssertComponent(entity: "Player", component: "game::Health", value: "(100)")),
         (300, AppExit),
     ],
 )"#;
@@ -80,6 +191,53 @@ mod tests {
             events: vec![
                 CiTestingEventOnFrame(100, CiTestingEvent::Custom("Hello, world!".into())),
                 CiTestingEventOnFrame(200, CiTestingEvent::Screenshot),
+                CiTestingEventOnFrame(210, CiTestingEvent::KeyPress(KeyCode::Space)),
+                CiTestingEventOnFrame(211, CiTestingEvent::KeyRelease(KeyCode::Space)),
+                CiTestingEventOnFrame(220, CiTestingEvent::TextInput("hi".into())),
+                CiTestingEventOnFrame(230, CiTestingEvent::CursorMove(Vec2::new(10.0, 20.0))),
+                CiTestingEventOnFrame(231, CiTestingEvent::MouseButtonPress(MouseButton::Left)),
+                CiTestingEventOnFrame(
+                    240,
+                    CiTestingEvent::GamepadButton {
+                        gamepad: 0,
+                        button: GamepadButton::South,
+                        value: 1.0,
+                    },
+                ),
+                CiTestingEventOnFrame(
+                    241,
+                    CiTestingEvent::GamepadAxis {
+                        gamepad: 1,
+                        axis: GamepadAxis::LeftStickX,
+                        value: -0.5,
+                    },
+                ),
+                CiTestingEventOnFrame(250, CiTestingEvent::WindowResize(Vec2::new(640.0, 480.0))),
+                CiTestingEventOnFrame(260, CiTestingEvent::AssertEntityExists("Player".into())),
+                CiTestingEventOnFrame(
+                    261,
+                    CiTestingEvent::AssertQueryCount {
+                        component: "game::Enemy".into(),
+                        count: 3,
+                    },
+                ),
+                CiTestingEventOnFrame(
+                    262,
+                    CiTestingEvent::AssertResource {
+                        resource: "game::Score".into(),
+                        path: "value".into(),
+                        value: "10".into(),
+                    },
+                ),
+                CiTestingEventOnFrame(
+                    263,
+                    CiTestingEvent::AssertComponent {
+                        entity: "Player".into(),
+                        component: "game::Health".into(),
+                        path: String::new(),
+                        value: "(100)".into(),
+                    },
+                ),
                 CiTestingEventOnFrame(300, CiTestingEvent::AppExit),
             ],
         };
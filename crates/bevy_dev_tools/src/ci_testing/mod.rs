@@ -1,5 +1,6 @@
 //! Utilities for testing in CI environments.
 
+mod assertions;
 mod config;
 mod systems;
 
@@ -1,8 +1,10 @@
-use super::config::*;
+use super::{assertions, config::*};
+use crate::simulated_input as input;
 use bevy_app::AppExit;
 use bevy_ecs::prelude::*;
+use bevy_input::ButtonState;
 use bevy_render::view::screenshot::{save_to_disk, Screenshot};
-use tracing::{debug, info};
+use tracing::{debug, error, info};
 
 pub(crate) fn send_events(world: &mut World, mut current_frame: Local<u32>) {
     let mut config = world.resource_mut::<CiTestingConfig>();
@@ -55,8 +57,135 @@ pub(crate) fn send_events(world: &mut World, mut current_frame: Local<u32>) {
             CiTestingEvent::Custom(event_string) => {
                 world.send_event(CiTestingCustomEvent(event_string));
             }
+            // Input is sent as the events of real devices.
+            CiTestingEvent::KeyPress(key_code) => {
+                input::key(world, key_code, ButtonState::Pressed);
+            }
+            CiTestingEvent::KeyRelease(key_code) => {
+                input::key(world, key_code, ButtonState::Released);
+            }
+            CiTestingEvent::TextInput(text) => input::text(world, &text),
+            CiTestingEvent::CursorMove(position) => input::cursor_move(world, position),
+            CiTestingEvent::MouseButtonPress(button) => {
+                input::mouse_button(world, button, ButtonState::Pressed);
+            }
+            CiTestingEvent::MouseButtonRelease(button) => {
+                input::mouse_button(world, button, ButtonState::Released);
+            }
+            CiTestingEvent::GamepadButton {
+                gamepad,
+                button,
+                value,
+            } => input::gamepad_button(world, gamepad, button, value),
+            CiTestingEvent::GamepadAxis {
+                gamepad,
+                axis,
+                value,
+            } => input::gamepad_axis(world, gamepad, axis, value),
+            CiTestingEvent::WindowResize(size) => input::window_resize(world, size),
+            // Assertions stop the program with an error when they fail.
+            CiTestingEvent::AssertEntityExists(name) => {
+                let result = assertions::entity_exists(world, &name);
+                report_assertion(world, *current_frame, result);
+            }
+            CiTestingEvent::AssertQueryCount { component, count } => {
+                let result = assertions::query_count(world, &component, count);
+                report_assertion(world, *current_frame, result);
+            }
+            CiTestingEvent::AssertResource {
+                resource,
+                path,
+                value,
+            } => {
+                let result = assertions::resource_equals(world, &resource, &path, &value);
+                report_assertion(world, *current_frame, result);
+            }
+            CiTestingEvent::AssertComponent {
+                entity,
+                component,
+                path,
+                value,
+            } => {
+                let result =
+                    assertions::component_equals(world, &entity, &component, &path, &value);
+                report_assertion(world, *current_frame, result);
+            }
         }
     }
 
     *current_frame += 1;
 }
+
+fn report_assertion(world: &mut World, frame: u32, result: Result<(), String>) {
+    match result {
+        Ok(()) => debug!("Assertion passed at frame {}.", frame),
+        Err(message) => {
+            error!("Assertion failed at frame {}: {}", frame, message);
+            world.send_event(AppExit::error());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ci_testing::{systems::send_events, *};
+    use bevy_app::prelude::*;
+    use bevy_ecs::prelude::*;
+    use bevy_input::{gamepad::Gamepad, prelude::*, InputPlugin};
+    use bevy_math::Vec2;
+    use bevy_window::{PrimaryWindow, Window, WindowPlugin};
+
+    #[test]
+    fn inject_input() {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, WindowPlugin::default()))
+            .insert_resource(CiTestingConfig {
+                events: vec![
+                    CiTestingEventOnFrame(0, CiTestingEvent::KeyPress(KeyCode::Space)),
+                    CiTestingEventOnFrame(0, CiTestingEvent::MouseButtonPress(MouseButton::Left)),
+                    CiTestingEventOnFrame(0, CiTestingEvent::CursorMove(Vec2::new(10.0, 20.0))),
+                    CiTestingEventOnFrame(
+                        0,
+                        CiTestingEvent::GamepadButton {
+                            gamepad: 0,
+                            button: GamepadButton::South,
+                            value: 1.0,
+                        },
+                    ),
+                    CiTestingEventOnFrame(1, CiTestingEvent::KeyRelease(KeyCode::Space)),
+                ],
+                ..Default::default()
+            })
+            .add_systems(Update, send_events);
+
+        // The input is processed on the frame after it is sent.
+        app.update();
+        app.update();
+        assert!(app
+            .world()
+            .resource::<ButtonInput<KeyCode>>()
+            .pressed(KeyCode::Space));
+        assert!(app
+            .world()
+            .resource::<ButtonInput<MouseButton>>()
+            .pressed(MouseButton::Left));
+        let window = app
+            .world_mut()
+            .query_filtered::<&Window, With<PrimaryWindow>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(window.cursor_position(), Some(Vec2::new(10.0, 20.0)));
+        let gamepad = app
+            .world_mut()
+            .query::<&Gamepad>()
+            .single(app.world())
+            .unwrap();
+        assert!(gamepad.pressed(GamepadButton::South));
+
+        app.update();
+        assert!(!app
+            .world()
+            .resource::<ButtonInput<KeyCode>>()
+            .pressed(KeyCode::Space));
+    }
+}
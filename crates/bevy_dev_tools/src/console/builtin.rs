@@ -0,0 +1,210 @@
+//! The commands available in every developer console.
+
+use bevy_app::App;
+use bevy_ecs::{
+    error::Result,
+    prelude::*,
+    reflect::{AppTypeRegistry, ReflectResource},
+};
+use bevy_reflect::{
+    DynamicEnum, DynamicVariant, GetPath, ReflectFromReflect, TypeData, TypeInfo, TypeRegistration,
+    TypeRegistry, VariantInfo,
+};
+use bevy_state::reflect::{ReflectFreelyMutableState, ReflectState};
+
+use super::{ConsoleArgs, ConsoleCommands, DevConsole, RegisterConsoleCommand};
+use crate::reflect_text::set_text;
+
+pub(super) fn register(app: &mut App) {
+    app.register_console_command("help", "help [command]: lists the commands", help)
+        .register_console_command("clear", "clear: clears the console", clear)
+        .register_console_command(
+            "get",
+            "get <Resource> [path]: prints a reflected resource or one of its fields",
+            get,
+        )
+        .register_console_command(
+            "set",
+            "set <Resource> <path> <value>: sets a field of a reflected resource",
+            set,
+        )
+        .register_console_command(
+            "state",
+            "state <State> [Variant]: prints a state, or switches it to a field-less variant",
+            state,
+        );
+    let mut commands = app.world_mut().resource_mut::<ConsoleCommands>();
+    commands.set_completions("help", complete_help);
+    commands.set_completions("get", complete_resource);
+    commands.set_completions("set", complete_resource);
+    commands.set_completions("state", complete_state);
+}
+
+fn help(In(args): In<ConsoleArgs>, commands: Res<ConsoleCommands>) -> Result<String> {
+    if let Some(name) = args.get(0) {
+        let command = commands
+            .get(name)
+            .ok_or_else(|| format!("unknown command `{name}`"))?;
+        return Ok(command.help.clone());
+    }
+    Ok(commands
+        .iter()
+        .map(|(_, command)| command.help.as_str())
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+fn clear(In(_): In<ConsoleArgs>, mut console: ResMut<DevConsole>) -> Result<String> {
+    console.clear();
+    Ok(String::new())
+}
+
+fn get(In(args): In<ConsoleArgs>, world: &World) -> Result<String> {
+    let name = args.required(0, "resource")?;
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let resource = reflect_resource(&registry, name)?
+        .reflect(world)
+        .map_err(|_| format!("resource `{name}` doesn't exist"))?;
+    match args.get(1) {
+        Some(path) => {
+            let field = resource
+                .reflect_path(path)
+                .map_err(|error| format!("invalid path `{path}`: {error}"))?;
+            Ok(format!("{field:#?}"))
+        }
+        None => Ok(format!("{resource:#?}")),
+    }
+}
+
+fn set(In(args): In<ConsoleArgs>, world: &mut World) -> Result<String> {
+    let name = args.required(0, "resource")?;
+    let path = args.required(1, "path")?;
+    let value = args.rest(2);
+    if value.is_empty() {
+        return Err("missing argument `value`".into());
+    }
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let mut resource = reflect_resource(&registry, name)?
+        .reflect_mut(world)
+        .map_err(|_| format!("resource `{name}` doesn't exist"))?;
+    let field = resource
+        .reflect_path_mut(path)
+        .map_err(|error| format!("invalid path `{path}`: {error}"))?;
+    if !set_text(field, &value) {
+        return Err(format!("can't set `{path}` to `{value}`").into());
+    }
+    Ok(format!("{field:?}"))
+}
+
+fn state(In(args): In<ConsoleArgs>, world: &mut World) -> Result<String> {
+    let name = args.required(0, "state")?;
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let registration =
+        registration(&registry, name).ok_or_else(|| format!("unknown type `{name}`"))?;
+
+    let Some(variant) = args.get(1) else {
+        let current = registration
+            .data::<ReflectState>()
+            .ok_or_else(|| format!("`{name}` isn't a registered state"))?
+            .reflect(world)
+            .ok_or_else(|| format!("state `{name}` doesn't exist"))?;
+        return Ok(format!("{current:?}"));
+    };
+
+    let reflect_state = registration
+        .data::<ReflectFreelyMutableState>()
+        .ok_or_else(|| {
+            format!("`{name}` isn't a state registered with `register_type_mutable_state`")
+        })?;
+    let is_unit_variant = match registration.type_info() {
+        TypeInfo::Enum(info) => matches!(info.variant(variant), Some(VariantInfo::Unit(_))),
+        _ => false,
+    };
+    let new_state = registration
+        .data::<ReflectFromReflect>()
+        .filter(|_| is_unit_variant)
+        .and_then(|from_reflect| {
+            from_reflect.from_reflect(&DynamicEnum::new(variant, DynamicVariant::Unit))
+        })
+        .ok_or_else(|| format!("`{variant}` isn't a field-less variant of `{name}`"))?;
+    reflect_state.set_next_state(world, new_state.as_ref(), &registry);
+    Ok(format!("{name} will switch to {variant}"))
+}
+
+/// Finds a type by its short or full type path.
+fn registration<'a>(registry: &'a TypeRegistry, name: &str) -> Option<&'a TypeRegistration> {
+    registry
+        .get_with_short_type_path(name)
+        .or_else(|| registry.get_with_type_path(name))
+}
+
+fn reflect_resource<'a>(registry: &'a TypeRegistry, name: &str) -> Result<&'a ReflectResource> {
+    registration(registry, name)
+        .ok_or_else(|| format!("unknown type `{name}`"))?
+        .data::<ReflectResource>()
+        .ok_or_else(|| format!("`{name}` isn't a reflected resource").into())
+}
+
+/// The short type paths of the types registered with the type data `T`.
+fn type_names<T: TypeData>(world: &World) -> Vec<String> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    registry
+        .iter_with_data::<T>()
+        .map(|(registration, _)| {
+            registration
+                .type_info()
+                .type_path_table()
+                .short_path()
+                .to_owned()
+        })
+        .collect()
+}
+
+fn complete_help(world: &World, previous: &[&str]) -> Vec<String> {
+    match previous {
+        [] => world
+            .resource::<ConsoleCommands>()
+            .iter()
+            .map(|(name, _)| name.to_owned())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn complete_resource(world: &World, previous: &[&str]) -> Vec<String> {
+    match previous {
+        [] => type_names::<ReflectResource>(world),
+        [name] => {
+            let registry = world.resource::<AppTypeRegistry>().read();
+            match registration(&registry, name).map(TypeRegistration::type_info) {
+                Some(TypeInfo::Struct(info)) => info
+                    .field_names()
+                    .iter()
+                    .map(|&field| field.to_owned())
+                    .collect(),
+                _ => Vec::new(),
+            }
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn complete_state(world: &World, previous: &[&str]) -> Vec<String> {
+    match previous {
+        [] => type_names::<ReflectFreelyMutableState>(world),
+        [name] => {
+            let registry = world.resource::<AppTypeRegistry>().read();
+            match registration(&registry, name).map(TypeRegistration::type_info) {
+                Some(TypeInfo::Enum(info)) => info
+                    .iter()
+                    .filter(|variant| matches!(variant, VariantInfo::Unit(_)))
+                    .map(|variant| variant.name().to_owned())
+                    .collect(),
+                _ => Vec::new(),
+            }
+        }
+        _ => Vec::new(),
+    }
+}
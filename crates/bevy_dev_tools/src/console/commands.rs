@@ -0,0 +1,218 @@
+//! The registry of the console commands and their arguments.
+
+use alloc::collections::BTreeMap;
+use bevy_app::{App, SubApp};
+use bevy_ecs::{
+    error::Result,
+    prelude::*,
+    system::{IntoSystem, SystemId},
+};
+use core::{fmt::Display, str::FromStr};
+
+/// The arguments of a console command, following its name.
+///
+/// Arguments are separated by whitespace, unless they are quoted with `"`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConsoleArgs(pub Vec<String>);
+
+impl ConsoleArgs {
+    /// Returns the number of arguments.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no arguments.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the argument at `index`, if any.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.0.get(index).map(String::as_str)
+    }
+
+    /// Returns the argument at `index`, or an error naming the missing argument.
+    pub fn required(&self, index: usize, name: &str) -> Result<&str> {
+        self.get(index)
+            .ok_or_else(|| format!("missing argument `{name}`").into())
+    }
+
+    /// Parses the argument at `index`, or returns an error naming the missing or invalid argument.
+    pub fn parse<T: FromStr>(&self, index: usize, name: &str) -> Result<T>
+    where
+        T::Err: Display,
+    {
+        let argument = self.required(index, name)?;
+        argument
+            .parse()
+            .map_err(|error| format!("invalid `{name}` `{argument}`: {error}").into())
+    }
+
+    /// Parses the argument at `index` if there is one, or returns an error if it is invalid.
+    pub fn parse_optional<T: FromStr>(&self, index: usize, name: &str) -> Result<Option<T>>
+    where
+        T::Err: Display,
+    {
+        match self.get(index) {
+            Some(_) => self.parse(index, name).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the arguments from `index` on, joined with spaces.
+    pub fn rest(&self, index: usize) -> String {
+        self.0.get(index..).unwrap_or_default().join(" ")
+    }
+}
+
+/// Splits a command line into its words, which are separated by whitespace unless they are
+/// quoted with `"`.
+pub(crate) fn split_words(line: &str) -> Result<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut quoted = false;
+    for character in line.chars() {
+        match character {
+            '"' => {
+                quoted = !quoted;
+                word.get_or_insert_default();
+            }
+            character if character.is_whitespace() && !quoted => {
+                words.extend(word.take());
+            }
+            character => word.get_or_insert_default().push(character),
+        }
+    }
+    if quoted {
+        return Err("unclosed quote".into());
+    }
+    words.extend(word);
+    Ok(words)
+}
+
+/// Returns the candidates to complete the argument following `previous`, the previous
+/// arguments of the command.
+pub type ConsoleCompletions = fn(world: &World, previous: &[&str]) -> Vec<String>;
+
+/// A command of the developer console.
+#[derive(Clone)]
+pub struct ConsoleCommand {
+    /// The one-shot system running the command, returning the text to print.
+    pub system: SystemId<In<ConsoleArgs>, Result<String>>,
+    /// The usage and description of the command, printed by `help`.
+    pub help: String,
+    /// The completions of the arguments of the command, if any.
+    pub completions: Option<ConsoleCompletions>,
+}
+
+/// The commands of the developer console, by name.
+///
+/// Use [`RegisterConsoleCommand`] to add a command.
+#[derive(Resource, Default, Clone)]
+pub struct ConsoleCommands {
+    commands: BTreeMap<String, ConsoleCommand>,
+}
+
+impl ConsoleCommands {
+    /// Adds a command, replacing any command with the same name.
+    pub fn insert(&mut self, name: impl Into<String>, command: ConsoleCommand) {
+        self.commands.insert(name.into(), command);
+    }
+
+    /// Returns the command named `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&ConsoleCommand> {
+        self.commands.get(name)
+    }
+
+    /// Sets the completions of the arguments of the command named `name`, if it exists.
+    pub fn set_completions(&mut self, name: &str, completions: ConsoleCompletions) {
+        if let Some(command) = self.commands.get_mut(name) {
+            command.completions = Some(completions);
+        }
+    }
+
+    /// Iterates over the commands and their names, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ConsoleCommand)> {
+        self.commands
+            .iter()
+            .map(|(name, command)| (name.as_str(), command))
+    }
+}
+
+/// Extend [`App`] with a `register_console_command` function.
+pub trait RegisterConsoleCommand {
+    /// Registers a command of the developer console, run by a one-shot system.
+    ///
+    /// The system takes the arguments following the name of the command, and returns the text
+    /// to print in the console, or an error.
+    ///
+    /// Will initialize the [`ConsoleCommands`] if they don't exist.
+    fn register_console_command<M>(
+        &mut self,
+        name: impl Into<String>,
+        help: impl Into<String>,
+        system: impl IntoSystem<In<ConsoleArgs>, Result<String>, M> + 'static,
+    ) -> &mut Self;
+}
+
+impl RegisterConsoleCommand for SubApp {
+    fn register_console_command<M>(
+        &mut self,
+        name: impl Into<String>,
+        help: impl Into<String>,
+        system: impl IntoSystem<In<ConsoleArgs>, Result<String>, M> + 'static,
+    ) -> &mut Self {
+        let system = self.world_mut().register_system(system);
+        self.init_resource::<ConsoleCommands>();
+        self.world_mut().resource_mut::<ConsoleCommands>().insert(
+            name,
+            ConsoleCommand {
+                system,
+                help: help.into(),
+                completions: None,
+            },
+        );
+        self
+    }
+}
+
+impl RegisterConsoleCommand for App {
+    fn register_console_command<M>(
+        &mut self,
+        name: impl Into<String>,
+        help: impl Into<String>,
+        system: impl IntoSystem<In<ConsoleArgs>, Result<String>, M> + 'static,
+    ) -> &mut Self {
+        SubApp::register_console_command(self.main_mut(), name, help, system);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_and_parse() {
+        assert_eq!(
+            split_words(r#"  say "hello world"  3 "" "#).unwrap(),
+            ["say", "hello world", "3", ""]
+        );
+        assert!(split_words(r#"say "hello"#).is_err());
+
+        let args = ConsoleArgs(vec!["enemy".into(), "3".into(), "x".into()]);
+        assert_eq!(args.parse::<u32>(1, "count").unwrap(), 3);
+        assert!(args
+            .parse::<u32>(2, "count")
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid `count` `x`: invalid digit found in string"));
+        assert!(args
+            .required(3, "speed")
+            .unwrap_err()
+            .to_string()
+            .starts_with("missing argument `speed`"));
+        assert_eq!(args.parse_optional::<u32>(3, "speed").unwrap(), None);
+        assert_eq!(args.rest(1), "3 x");
+    }
+}
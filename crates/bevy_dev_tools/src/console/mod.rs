@@ -0,0 +1,554 @@
+//! Module containing logic for the in-game developer console.
+//!
+//! The console drops down from the top of the window when its toggle key is pressed, and runs
+//! the commands typed in it, like `get Time<Virtual>`, `set Time<Virtual> context.relative_speed
+//! 0.5` or `state GameState Paused`. The previous commands are recalled with the up and down
+//! arrows, and Tab completes the name of the command and its arguments.
+//!
+//! Commands are one-shot systems taking the [`ConsoleArgs`] following the name of the command,
+//! and returning the text to print:
+//!
+//! ```no_run
+//! # use bevy_app::prelude::*;
+//! # use bevy_ecs::{error::Result, prelude::*, system::RegisteredSystemError};
+//! # use bevy_dev_tools::console::{ConsoleArgs, DevConsolePlugin, RegisterConsoleCommand};
+//! #[derive(Component)]
+//! struct Enemy;
+//!
+//! fn spawn(In(args): In<ConsoleArgs>, mut commands: Commands) -> Result<String> {
+//!     let kind = args.required(0, "kind")?;
+//!     let count = args.parse_optional::<u32>(1, "count")?.unwrap_or(1);
+//!     if kind != "enemy" {
+//!         return Err(format!("can't spawn `{kind}`").into());
+//!     }
+//!     commands.spawn_batch((0..count).map(|_| Enemy));
+//!     Ok(format!("spawned {count} {kind}"))
+//! }
+//!
+//! App::new()
+//!     .add_plugins(DevConsolePlugin::default())
+//!     .register_console_command("spawn", "spawn <kind> [count]: spawns entities", spawn)
+//!     .run();
+//! ```
+
+mod builtin;
+mod commands;
+
+pub use commands::{
+    ConsoleArgs, ConsoleCommand, ConsoleCommands, ConsoleCompletions, RegisterConsoleCommand,
+};
+
+use alloc::collections::VecDeque;
+use bevy_app::prelude::*;
+use bevy_color::Color;
+use bevy_ecs::{error::Result, prelude::*, system::RegisteredSystemError};
+use bevy_input::{
+    keyboard::{Key, KeyCode, KeyboardInput},
+    ButtonState,
+};
+use bevy_text::{TextColor, TextFont};
+use bevy_ui::{
+    widget::Text, BackgroundColor, Display, FlexDirection, GlobalZIndex, Node, PositionType,
+    UiRect, Val,
+};
+
+use commands::split_words;
+
+/// [`GlobalZIndex`] used to render the developer console.
+///
+/// It is above the [`INSPECTOR_ZINDEX`](crate::inspector::INSPECTOR_ZINDEX), and under the
+/// [`FPS_OVERLAY_ZINDEX`](crate::fps_overlay::FPS_OVERLAY_ZINDEX).
+pub const CONSOLE_ZINDEX: i32 = i32::MAX - 48;
+
+const PANEL_COLOR: Color = Color::srgba(0.05, 0.05, 0.07, 0.92);
+const SCROLLBACK_COLOR: Color = Color::srgb(0.8, 0.8, 0.85);
+const INPUT_COLOR: Color = Color::WHITE;
+
+/// A plugin that adds an in-game developer console to the Bevy application, with the `help`,
+/// `clear`, `get`, `set` and `state` commands.
+///
+/// Add more commands with [`RegisterConsoleCommand::register_console_command`].
+///
+/// The keyboard input typed in the console is also received by the other systems of the
+/// application.
+#[derive(Default)]
+pub struct DevConsolePlugin {
+    /// Starting configuration of the console, this can be later be changed through the
+    /// [`DevConsoleConfig`] resource.
+    pub config: DevConsoleConfig,
+}
+
+impl Plugin for DevConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone())
+            .init_resource::<DevConsole>()
+            .init_resource::<ConsoleCommands>()
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (
+                    console_input,
+                    show_console.run_if(resource_changed::<DevConsoleConfig>),
+                    update_console.run_if(
+                        resource_changed::<DevConsole>.or(resource_changed::<DevConsoleConfig>),
+                    ),
+                )
+                    .chain(),
+            );
+        builtin::register(app);
+    }
+}
+
+/// Configuration options for the developer console.
+#[derive(Resource, Clone)]
+pub struct DevConsoleConfig {
+    /// Displays the console if true.
+    pub open: bool,
+    /// The key toggling the console, if any.
+    ///
+    /// Defaults to the backquote key, under Escape on a US keyboard.
+    pub toggle_key: Option<KeyCode>,
+    /// Configuration of the text of the console.
+    pub text_font: TextFont,
+    /// The number of lines of the scrollback shown.
+    pub visible_lines: usize,
+}
+
+impl Default for DevConsoleConfig {
+    fn default() -> Self {
+        Self {
+            open: false,
+            toggle_key: Some(KeyCode::Backquote),
+            text_font: TextFont {
+                font_size: 14.0,
+                ..Default::default()
+            },
+            visible_lines: 16,
+        }
+    }
+}
+
+/// The scrollback, history and input of the developer console.
+#[derive(Resource)]
+pub struct DevConsole {
+    /// The maximum number of lines kept in the scrollback.
+    pub max_scrollback: usize,
+    /// The maximum number of commands kept in the history.
+    pub max_history: usize,
+    scrollback: VecDeque<String>,
+    history: VecDeque<String>,
+    input: String,
+    /// The command of the history shown in the input, if any.
+    history_index: Option<usize>,
+    /// The number of lines the scrollback is scrolled up by.
+    scroll: usize,
+}
+
+impl Default for DevConsole {
+    fn default() -> Self {
+        Self {
+            max_scrollback: 1000,
+            max_history: 100,
+            scrollback: VecDeque::new(),
+            history: VecDeque::new(),
+            input: String::new(),
+            history_index: None,
+            scroll: 0,
+        }
+    }
+}
+
+impl DevConsole {
+    /// Prints the `text` in the scrollback, one line per line of the text.
+    pub fn print(&mut self, text: &str) {
+        self.scrollback.extend(text.lines().map(str::to_owned));
+        let excess = self.scrollback.len().saturating_sub(self.max_scrollback);
+        self.scrollback.drain(..excess);
+        self.scroll = 0;
+    }
+
+    /// Clears the scrollback.
+    pub fn clear(&mut self) {
+        self.scrollback.clear();
+        self.scroll = 0;
+    }
+
+    /// Iterates over the lines of the scrollback, from the oldest.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.scrollback.iter().map(String::as_str)
+    }
+
+    /// Iterates over the commands of the history, from the oldest.
+    pub fn history(&self) -> impl Iterator<Item = &str> {
+        self.history.iter().map(String::as_str)
+    }
+
+    /// Returns the text being typed.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Runs the command `line` as if it was typed in the console, printing it and its output,
+    /// and adding it to the history.
+    pub fn submit(world: &mut World, line: &str) {
+        {
+            let mut console = world.resource_mut::<DevConsole>();
+            console.print(&format!("> {line}"));
+            if !line.trim().is_empty() && console.history.back().is_none_or(|last| last != line) {
+                console.history.push_back(line.to_owned());
+                let excess = console.history.len().saturating_sub(console.max_history);
+                console.history.drain(..excess);
+            }
+        }
+        let output = match run_console_command(world, line) {
+            Ok(output) => output,
+            // Only print the message, not the backtrace captured with the error.
+            Err(error) => format!(
+                "error: {}",
+                error.to_string().lines().next().unwrap_or_default()
+            ),
+        };
+        if !output.is_empty() {
+            world.resource_mut::<DevConsole>().print(&output);
+        }
+    }
+
+    fn recall_history(&mut self, older: bool) {
+        let index = match (self.history_index, older) {
+            (None, true) => self.history.len().checked_sub(1),
+            (None, false) => None,
+            (Some(index), true) => Some(index.saturating_sub(1)),
+            (Some(index), false) => Some(index + 1).filter(|&index| index < self.history.len()),
+        };
+        self.history_index = index;
+        self.input = index
+            .and_then(|index| self.history.get(index).cloned())
+            .unwrap_or_default();
+    }
+}
+
+/// Runs the console command `line`, returning its output.
+pub fn run_console_command(world: &mut World, line: &str) -> Result<String> {
+    let mut words = split_words(line)?;
+    if words.is_empty() {
+        return Ok(String::new());
+    }
+    let name = words.remove(0);
+    let system = world
+        .get_resource::<ConsoleCommands>()
+        .and_then(|commands| commands.get(&name))
+        .map(|command| command.system)
+        .ok_or_else(|| format!("unknown command `{name}`, `help` lists the commands"))?;
+    match world.run_system_with(system, ConsoleArgs(words)) {
+        Ok(output) => output,
+        Err(RegisteredSystemError::Failed(error)) => Err(error),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Returns the word being completed at the end of the `input`, and its candidates.
+fn completions(world: &World, input: &str) -> (String, Vec<String>) {
+    let Ok(mut words) = split_words(input) else {
+        return (String::new(), Vec::new());
+    };
+    let partial = match input.ends_with(char::is_whitespace) {
+        true => String::new(),
+        false => words.pop().unwrap_or_default(),
+    };
+    let mut candidates = match words.split_first() {
+        None => world
+            .get_resource::<ConsoleCommands>()
+            .map(|commands| commands.iter().map(|(name, _)| name.to_owned()).collect())
+            .unwrap_or_default(),
+        Some((name, previous)) => world
+            .get_resource::<ConsoleCommands>()
+            .and_then(|commands| commands.get(name))
+            .and_then(|command| command.completions)
+            .map(|completions| {
+                let previous: Vec<_> = previous.iter().map(String::as_str).collect();
+                completions(world, &previous)
+            })
+            .unwrap_or_default(),
+    };
+    candidates.retain(|candidate| candidate.starts_with(&partial));
+    candidates.sort();
+    candidates.dedup();
+    (partial, candidates)
+}
+
+/// Completes the word at the end of the input, or prints the candidates if there are several.
+fn complete_input(world: &mut World) {
+    let input = world.resource::<DevConsole>().input.clone();
+    let (partial, candidates) = completions(world, &input);
+    let mut console = world.resource_mut::<DevConsole>();
+    let Some(first) = candidates.first() else {
+        return;
+    };
+    let completion = match candidates.len() {
+        1 => format!("{first} "),
+        _ => {
+            let common = candidates.iter().fold(first.as_str(), |common, candidate| {
+                let length = common
+                    .char_indices()
+                    .zip(candidate.chars())
+                    .find(|((_, a), b)| a != b)
+                    .map_or(common.len().min(candidate.len()), |((index, _), _)| index);
+                &common[..length]
+            });
+            console.print(&candidates.join("  "));
+            common.to_owned()
+        }
+    };
+    if completion.len() > partial.len() {
+        let start = input.len() - partial.len();
+        console.input.truncate(start);
+        console.input.push_str(&completion);
+    }
+}
+
+/// The root node of the console.
+#[derive(Component)]
+struct ConsoleRoot;
+
+/// The text showing the scrollback.
+#[derive(Component)]
+struct ConsoleScrollback;
+
+/// The text showing the input.
+#[derive(Component)]
+struct ConsoleInput;
+
+fn setup(mut commands: Commands, config: Res<DevConsoleConfig>) {
+    let text = |color| (Text::default(), config.text_font.clone(), TextColor(color));
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            top: Val::Px(0.0),
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(6.0)),
+            row_gap: Val::Px(4.0),
+            display: display(config.open),
+            ..Default::default()
+        },
+        BackgroundColor(PANEL_COLOR),
+        GlobalZIndex(CONSOLE_ZINDEX),
+        ConsoleRoot,
+        children![
+            (text(SCROLLBACK_COLOR), ConsoleScrollback),
+            (text(INPUT_COLOR), ConsoleInput)
+        ],
+    ));
+}
+
+fn display(open: bool) -> Display {
+    match open {
+        true => Display::Flex,
+        false => Display::None,
+    }
+}
+
+/// Types into the console, and runs the command on Enter.
+fn console_input(
+    mut events: EventReader<KeyboardInput>,
+    mut config: ResMut<DevConsoleConfig>,
+    mut console: ResMut<DevConsole>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        if config.toggle_key == Some(event.key_code) {
+            config.open = !config.open;
+            continue;
+        }
+        if !config.open {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter => {
+                let line = core::mem::take(&mut console.input);
+                console.history_index = None;
+                commands.queue(move |world: &mut World| DevConsole::submit(world, &line));
+            }
+            Key::Escape => config.open = false,
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::ArrowUp => console.recall_history(true),
+            Key::ArrowDown => console.recall_history(false),
+            Key::Tab => commands.queue(complete_input),
+            Key::PageUp => {
+                let page = config.visible_lines.max(1);
+                let max_scroll = console.scrollback.len().saturating_sub(page);
+                console.scroll = (console.scroll + page).min(max_scroll);
+            }
+            Key::PageDown => {
+                console.scroll = console.scroll.saturating_sub(config.visible_lines.max(1));
+            }
+            _ => {
+                let Some(text) = &event.text else {
+                    continue;
+                };
+                console
+                    .input
+                    .extend(text.chars().filter(|character| !character.is_control()));
+            }
+        }
+    }
+}
+
+fn show_console(config: Res<DevConsoleConfig>, mut roots: Query<&mut Node, With<ConsoleRoot>>) {
+    for mut node in &mut roots {
+        node.display = display(config.open);
+    }
+}
+
+fn update_console(
+    config: Res<DevConsoleConfig>,
+    console: Res<DevConsole>,
+    mut scrollbacks: Query<&mut Text, (With<ConsoleScrollback>, Without<ConsoleInput>)>,
+    mut inputs: Query<&mut Text, With<ConsoleInput>>,
+) {
+    let end = console.scrollback.len() - console.scroll.min(console.scrollback.len());
+    let start = end.saturating_sub(config.visible_lines);
+    let lines: Vec<_> = console.scrollback.range(start..end).cloned().collect();
+    for mut text in &mut scrollbacks {
+        text.0 = lines.join("\n");
+    }
+    for mut text in &mut inputs {
+        text.0 = format!("> {}_", console.input);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::reflect::ReflectResource;
+    use bevy_reflect::Reflect;
+    use bevy_state::{app::StatesPlugin, prelude::*};
+
+    #[derive(States, Reflect, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    enum GameState {
+        #[default]
+        Playing,
+        Paused,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Settings {
+        speed: f32,
+        difficulty: u8,
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            bevy_input::InputPlugin,
+            StatesPlugin,
+            DevConsolePlugin::default(),
+        ))
+        .init_state::<GameState>()
+        .register_type_mutable_state::<GameState>()
+        .init_resource::<Settings>()
+        .register_type::<Settings>()
+        .register_console_command("add", "add <a> <b>", |In(args): In<ConsoleArgs>| {
+            Ok(format!(
+                "{}",
+                args.parse::<i32>(0, "a")? + args.parse::<i32>(1, "b")?
+            ))
+        });
+        app.update();
+        app
+    }
+
+    #[test]
+    fn run_commands() {
+        let mut app = app();
+        let world = app.world_mut();
+        assert_eq!(run_console_command(world, "add 2 3").unwrap(), "5");
+        assert!(run_console_command(world, "add 2")
+            .unwrap_err()
+            .to_string()
+            .starts_with("missing argument `b`"));
+        assert!(run_console_command(world, "spawn enemy")
+            .unwrap_err()
+            .to_string()
+            .starts_with("unknown command `spawn`"));
+
+        assert_eq!(
+            run_console_command(world, "set Settings speed 2.5").unwrap(),
+            "2.5"
+        );
+        assert_eq!(world.resource::<Settings>().speed, 2.5);
+        assert!(run_console_command(world, "set Settings difficulty x").is_err());
+        assert_eq!(
+            run_console_command(world, "get Settings difficulty").unwrap(),
+            "0"
+        );
+
+        assert_eq!(
+            run_console_command(world, "state GameState").unwrap(),
+            "Playing"
+        );
+        run_console_command(world, "state GameState Paused").unwrap();
+        assert!(run_console_command(world, "state GameState Stopped").is_err());
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Paused
+        );
+    }
+
+    #[test]
+    fn console_scrollback_and_history() {
+        let mut app = app();
+        let world = app.world_mut();
+        DevConsole::submit(world, "add 1 1");
+        DevConsole::submit(world, "add 1");
+        let console = world.resource::<DevConsole>();
+        assert_eq!(
+            console.lines().collect::<Vec<_>>(),
+            ["> add 1 1", "2", "> add 1", "error: missing argument `b`"]
+        );
+        assert_eq!(console.history().collect::<Vec<_>>(), ["add 1 1", "add 1"]);
+
+        let mut console = world.resource_mut::<DevConsole>();
+        console.recall_history(true);
+        console.recall_history(true);
+        assert_eq!(console.input(), "add 1 1");
+        console.recall_history(false);
+        console.recall_history(false);
+        assert_eq!(console.input(), "");
+
+        DevConsole::submit(world, "clear");
+        assert_eq!(world.resource::<DevConsole>().lines().count(), 0);
+    }
+
+    #[test]
+    fn complete() {
+        let mut app = app();
+        let world = app.world_mut();
+        let candidates = |world: &World, input| completions(world, input).1;
+        assert_eq!(candidates(world, "s"), ["set", "state"]);
+        assert_eq!(candidates(world, "get Sett"), ["Settings"]);
+        assert_eq!(candidates(world, "set Settings "), ["difficulty", "speed"]);
+        assert_eq!(
+            candidates(world, "state GameState P"),
+            ["Paused", "Playing"]
+        );
+
+        world.resource_mut::<DevConsole>().input = "state Game".into();
+        complete_input(world);
+        assert_eq!(world.resource::<DevConsole>().input(), "state GameState ");
+        world.resource_mut::<DevConsole>().input.push_str("Pa");
+        complete_input(world);
+        assert_eq!(
+            world.resource::<DevConsole>().input(),
+            "state GameState Paused "
+        );
+    }
+}
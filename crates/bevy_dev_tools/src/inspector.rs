@@ -0,0 +1,1187 @@
+//! Module containing logic for the in-game entity inspector.
+//!
+//! The inspector lists the entities of the world, following their hierarchy, and shows the
+//! components of the selected entity. The fields of reflected components can be edited: numbers
+//! and strings by clicking them and typing a new value, confirmed with Enter, booleans and
+//! field-less enums by clicking them.
+//!
+//! Entities are selected by clicking them in the list, or by clicking them in the world while
+//! holding the [`pick_key`](EntityInspectorConfig::pick_key), when a picking backend can hit
+//! them, like the `MeshPickingPlugin` or the sprite picking backend.
+
+use bevy_app::prelude::*;
+use bevy_color::Color;
+use bevy_ecs::{observer::Observer, prelude::*, reflect::AppTypeRegistry, system::SystemState};
+use bevy_input::{
+    keyboard::{Key, KeyCode, KeyboardInput},
+    ButtonInput, ButtonState,
+};
+use bevy_picking::{
+    events::{Click, Pointer, Scroll},
+    pointer::PointerButton,
+};
+use bevy_reflect::{GetPath, PartialReflect, ReflectRef};
+use bevy_text::{TextColor, TextFont};
+use bevy_time::{Real, Time};
+use bevy_ui::{
+    widget::Text, BackgroundColor, Display, FlexDirection, GlobalZIndex, Node, Overflow,
+    PositionType, ScrollPosition, UiRect, Val,
+};
+use core::{any::TypeId, time::Duration};
+
+use crate::reflect_text::{
+    cycle_variant, is_fieldless_enum, number_text, set_text, step_number, string_text,
+};
+
+/// [`GlobalZIndex`] used to render the inspector.
+///
+/// It is under the [`FPS_OVERLAY_ZINDEX`](crate::fps_overlay::FPS_OVERLAY_ZINDEX), so that the
+/// FPS overlay stays visible.
+pub const INSPECTOR_ZINDEX: i32 = i32::MAX - 64;
+
+/// The maximum depth of the fields shown for a component.
+const MAX_FIELD_DEPTH: usize = 6;
+
+/// The maximum number of elements shown for a list or an array.
+const MAX_LIST_ELEMENTS: usize = 16;
+
+/// The height of a line of the lists when scrolling, in logical pixels.
+const SCROLL_LINE_HEIGHT: f32 = 20.0;
+
+const PANEL_COLOR: Color = Color::srgba(0.08, 0.08, 0.1, 0.92);
+const LABEL_COLOR: Color = Color::srgb(0.75, 0.75, 0.8);
+const VALUE_COLOR: Color = Color::WHITE;
+const EDITABLE_COLOR: Color = Color::srgb(0.55, 0.8, 1.0);
+const SELECTED_COLOR: Color = Color::srgb(1.0, 0.8, 0.3);
+const READ_ONLY_COLOR: Color = Color::srgb(0.5, 0.5, 0.55);
+
+/// A plugin that adds an in-game entity inspector to the Bevy application.
+///
+/// The inspector needs the picking plugins and the UI picking backend to be able to click its
+/// entries, which are part of the `DefaultPlugins`.
+#[derive(Default)]
+pub struct EntityInspectorPlugin {
+    /// Starting configuration of the inspector, this can be later be changed through the
+    /// [`EntityInspectorConfig`] resource.
+    pub config: EntityInspectorConfig,
+}
+
+impl Plugin for EntityInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone())
+            .init_resource::<InspectorSelection>()
+            .init_resource::<InspectorState>()
+            .add_observer(on_click)
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (
+                    toggle_inspector,
+                    show_inspector.run_if(resource_changed::<EntityInspectorConfig>),
+                    edit_focused_field,
+                    refresh_inspector,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Configuration options for the entity inspector.
+#[derive(Resource, Clone)]
+pub struct EntityInspectorConfig {
+    /// Displays the inspector if true.
+    pub enabled: bool,
+    /// The key toggling the inspector, if any.
+    ///
+    /// Defaults to F12.
+    pub toggle_key: Option<KeyCode>,
+    /// Configuration of the text of the inspector.
+    pub text_font: TextFont,
+    /// The period after which the inspector shows the latest state of the world.
+    ///
+    /// Defaults to once every 250 ms.
+    pub refresh_interval: Duration,
+    /// The maximum number of entities listed.
+    pub max_entities: usize,
+    /// The key to hold while clicking an entity in the world to select it, if any.
+    ///
+    /// Defaults to the left Alt key.
+    pub pick_key: Option<KeyCode>,
+}
+
+impl Default for EntityInspectorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            toggle_key: Some(KeyCode::F12),
+            text_font: TextFont {
+                font_size: 14.0,
+                ..Default::default()
+            },
+            refresh_interval: Duration::from_millis(250),
+            max_entities: 256,
+            pick_key: Some(KeyCode::AltLeft),
+        }
+    }
+}
+
+/// The entity shown by the inspector, if any.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InspectorSelection(pub Option<Entity>);
+
+/// The root node of the inspector.
+#[derive(Component)]
+struct InspectorRoot;
+
+/// The node listing the entities.
+#[derive(Component)]
+struct EntityList;
+
+/// The node listing the components of the selected entity.
+#[derive(Component)]
+struct ComponentList;
+
+/// A field of a reflected component.
+#[derive(Debug, Clone, PartialEq)]
+struct InspectorField {
+    entity: Entity,
+    component: TypeId,
+    /// The reflection path of the field in the component, empty for the component itself.
+    path: String,
+}
+
+/// What happens when an entry of the inspector is clicked.
+#[derive(Component, Debug, Clone, PartialEq)]
+enum InspectorAction {
+    Select(Entity),
+    Toggle(InspectorField),
+    Step(InspectorField, f64),
+    CycleVariant(InspectorField),
+    Focus(InspectorField),
+}
+
+/// A field being edited with the keyboard.
+#[derive(Debug, Clone, PartialEq)]
+struct Focus {
+    field: InspectorField,
+    text: String,
+}
+
+/// What the inspector currently shows.
+#[derive(Resource, Default)]
+struct InspectorState {
+    focus: Option<Focus>,
+    last_refresh: Option<Duration>,
+    dirty: bool,
+    /// The rows of the entity list, with their nodes.
+    shown_entities: Vec<(Entity, RowSpec)>,
+    /// The rows of the component list, with their nodes.
+    shown_components: Vec<(Entity, RowSpec)>,
+}
+
+/// A row of one of the lists of the inspector.
+#[derive(Debug, Clone, PartialEq)]
+struct RowSpec {
+    depth: usize,
+    /// The action of the whole row, if any.
+    action: Option<InspectorAction>,
+    cells: Vec<Cell>,
+}
+
+/// An element of a [`RowSpec`].
+#[derive(Debug, Clone, PartialEq)]
+enum Cell {
+    Text {
+        text: String,
+        color: Color,
+        action: Option<InspectorAction>,
+    },
+    /// A square showing a color.
+    Swatch(Color),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct EntityRow {
+    entity: Entity,
+    depth: usize,
+    label: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ComponentView {
+    name: String,
+    /// The type of the component, if it is reflected.
+    type_id: Option<TypeId>,
+    fields: Vec<FieldView>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct FieldView {
+    label: String,
+    path: String,
+    depth: usize,
+    value: FieldValue,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FieldValue {
+    /// A struct, tuple, list or enum with fields, whose fields follow.
+    Group(Option<String>),
+    /// A color, whose fields follow.
+    Color(Color),
+    Number {
+        text: String,
+        step: f64,
+    },
+    Bool(bool),
+    Text(String),
+    /// An enum without fields.
+    Variant(String),
+    ReadOnly(String),
+}
+
+fn setup(mut commands: Commands, config: Res<EntityInspectorConfig>) {
+    let root = commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(0.0),
+                top: Val::Px(0.0),
+                width: Val::Px(420.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(6.0)),
+                row_gap: Val::Px(6.0),
+                display: display(config.enabled),
+                ..Default::default()
+            },
+            BackgroundColor(PANEL_COLOR),
+            GlobalZIndex(INSPECTOR_ZINDEX),
+            InspectorRoot,
+        ))
+        .id();
+    let list = |height| Node {
+        height,
+        flex_direction: FlexDirection::Column,
+        overflow: Overflow::scroll_y(),
+        ..Default::default()
+    };
+    commands.spawn((text("Entities", LABEL_COLOR, &config), ChildOf(root)));
+    commands
+        .spawn((list(Val::Percent(40.0)), EntityList, ChildOf(root)))
+        .observe(scroll_list);
+    commands.spawn((text("Components", LABEL_COLOR, &config), ChildOf(root)));
+    commands
+        .spawn((
+            Node {
+                flex_grow: 1.0,
+                ..list(Val::Auto)
+            },
+            ComponentList,
+            ChildOf(root),
+        ))
+        .observe(scroll_list);
+}
+
+fn display(enabled: bool) -> Display {
+    match enabled {
+        true => Display::Flex,
+        false => Display::None,
+    }
+}
+
+fn toggle_inspector(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut config: ResMut<EntityInspectorConfig>,
+    state: Res<InspectorState>,
+) {
+    // Don't toggle the inspector while typing in it.
+    if state.focus.is_some() {
+        return;
+    }
+    if config.toggle_key.is_some_and(|key| keys.just_pressed(key)) {
+        config.enabled = !config.enabled;
+    }
+}
+
+fn show_inspector(
+    config: Res<EntityInspectorConfig>,
+    mut roots: Query<&mut Node, With<InspectorRoot>>,
+) {
+    for mut node in &mut roots {
+        node.display = display(config.enabled);
+    }
+}
+
+fn scroll_list(trigger: On<Pointer<Scroll>>, mut lists: Query<&mut ScrollPosition>) {
+    if let Ok(mut position) = lists.get_mut(trigger.target()) {
+        let lines = trigger.event().event.y;
+        position.0.y = (position.0.y - lines * SCROLL_LINE_HEIGHT).max(0.0);
+    }
+}
+
+/// Applies the action of the inspector entries, or selects the entities clicked in the world.
+fn on_click(
+    trigger: On<Pointer<Click>>,
+    actions: Query<&InspectorAction>,
+    parents: Query<&ChildOf>,
+    roots: Query<(), With<InspectorRoot>>,
+    config: Res<EntityInspectorConfig>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+) {
+    // Only handle the click once, not as it bubbles up the hierarchy.
+    let target = trigger.original_target();
+    if trigger.target() != target
+        || trigger.event().event.button != PointerButton::Primary
+        || !config.enabled
+    {
+        return;
+    }
+
+    for entity in core::iter::once(target).chain(parents.iter_ancestors(target)) {
+        if let Ok(action) = actions.get(entity) {
+            let action = action.clone();
+            commands.queue(move |world: &mut World| apply_action(world, action));
+            return;
+        }
+        if roots.contains(entity) {
+            return;
+        }
+    }
+    // Clicks in the world are left to the app, unless the pick key is held.
+    if config.pick_key.is_some_and(|key| keys.pressed(key)) {
+        commands
+            .queue(move |world: &mut World| apply_action(world, InspectorAction::Select(target)));
+    }
+}
+
+fn apply_action(world: &mut World, action: InspectorAction) {
+    match action {
+        InspectorAction::Select(entity) => {
+            world.resource_mut::<InspectorSelection>().0 = Some(entity);
+            world.resource_mut::<InspectorState>().focus = None;
+        }
+        InspectorAction::Toggle(field) => {
+            edit_field(world, &field, |value| {
+                value
+                    .try_downcast_mut::<bool>()
+                    .map(|value| *value = !*value)
+                    .is_some()
+            });
+        }
+        InspectorAction::Step(field, step) => {
+            edit_field(world, &field, |value| step_number(value, step));
+        }
+        InspectorAction::CycleVariant(field) => {
+            edit_field(world, &field, cycle_variant);
+        }
+        InspectorAction::Focus(field) => {
+            let text = read_field(world, &field, |value| {
+                number_text(value).or_else(|| string_text(value))
+            })
+            .flatten()
+            .unwrap_or_default();
+            world.resource_mut::<InspectorState>().focus = Some(Focus { field, text });
+        }
+    }
+    world.resource_mut::<InspectorState>().dirty = true;
+}
+
+/// Types into the focused field, applying the new value on Enter.
+fn edit_focused_field(
+    mut events: EventReader<KeyboardInput>,
+    mut state: ResMut<InspectorState>,
+    mut commands: Commands,
+) {
+    let state = &mut *state;
+    for event in events.read() {
+        let Some(focus) = &mut state.focus else {
+            continue;
+        };
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter => {
+                let Focus { field, text } = focus.clone();
+                state.focus = None;
+                commands.queue(move |world: &mut World| {
+                    edit_field(world, &field, |value| set_text(value, &text));
+                });
+            }
+            Key::Escape => state.focus = None,
+            Key::Backspace => {
+                focus.text.pop();
+            }
+            _ => {
+                let Some(text) = &event.text else {
+                    continue;
+                };
+                focus
+                    .text
+                    .extend(text.chars().filter(|character| !character.is_control()));
+            }
+        }
+        state.dirty = true;
+    }
+}
+
+/// The nodes of the inspector.
+type InspectorNodes = (
+    Query<'static, 'static, Entity, With<InspectorRoot>>,
+    Query<'static, 'static, Entity, With<EntityList>>,
+    Query<'static, 'static, Entity, With<ComponentList>>,
+);
+
+/// Shows the latest state of the world, when it changed.
+fn refresh_inspector(world: &mut World, nodes: &mut SystemState<InspectorNodes>) {
+    let config = world.resource::<EntityInspectorConfig>().clone();
+    if !config.enabled {
+        return;
+    }
+    let now = world.resource::<Time<Real>>().elapsed();
+    let selection_changed = world.is_resource_changed::<InspectorSelection>();
+    let state = world.resource::<InspectorState>();
+    if !state.dirty
+        && !selection_changed
+        && state
+            .last_refresh
+            .is_some_and(|last_refresh| now - last_refresh < config.refresh_interval)
+    {
+        return;
+    }
+
+    let (roots, entity_lists, component_lists) = nodes.get(world);
+    let (Ok(root), Ok(entity_list), Ok(component_list)) = (
+        roots.single(),
+        entity_lists.single(),
+        component_lists.single(),
+    ) else {
+        return;
+    };
+
+    let entities = entity_rows(world, root, config.max_entities);
+    let selection = world
+        .resource::<InspectorSelection>()
+        .0
+        .filter(|&entity| world.get_entity(entity).is_ok());
+    let components = selection
+        .map(|entity| component_views(world, entity))
+        .unwrap_or_default();
+
+    let mut state = world.resource_mut::<InspectorState>();
+    state.last_refresh = Some(now);
+    state.dirty = false;
+    let entity_specs = entity_row_specs(&entities, selection);
+    let component_specs = selection
+        .map(|entity| component_row_specs(entity, &components, state.focus.as_ref()))
+        .unwrap_or_default();
+    let mut shown_entities = core::mem::take(&mut state.shown_entities);
+    let mut shown_components = core::mem::take(&mut state.shown_components);
+
+    sync_rows(
+        world,
+        entity_list,
+        &mut shown_entities,
+        entity_specs,
+        &config,
+    );
+    sync_rows(
+        world,
+        component_list,
+        &mut shown_components,
+        component_specs,
+        &config,
+    );
+    let mut state = world.resource_mut::<InspectorState>();
+    state.shown_entities = shown_entities;
+    state.shown_components = shown_components;
+}
+
+/// Lists the entities of the world following their hierarchy, except for the inspector itself
+/// and the observers.
+fn entity_rows(world: &mut World, inspector: Entity, max_entities: usize) -> Vec<EntityRow> {
+    let mut query = world.query_filtered::<(Entity, Option<&Name>, Option<&Children>), (
+        Without<ChildOf>,
+        Without<Observer>,
+    )>();
+    let mut roots: Vec<Entity> = query
+        .iter(world)
+        .map(|(entity, ..)| entity)
+        .filter(|&entity| entity != inspector)
+        .collect();
+    roots.sort();
+
+    let mut rows = Vec::new();
+    let mut stack: Vec<(Entity, usize)> = roots.into_iter().rev().map(|root| (root, 0)).collect();
+    while let Some((entity, depth)) = stack.pop() {
+        if rows.len() == max_entities {
+            break;
+        }
+        let entity_ref = world.entity(entity);
+        let label = match entity_ref.get::<Name>() {
+            Some(name) => format!("{name} ({entity})"),
+            None => format!("{entity}"),
+        };
+        rows.push(EntityRow {
+            entity,
+            depth,
+            label,
+        });
+        if let Some(children) = entity_ref.get::<Children>() {
+            stack.extend(children.iter().rev().map(|child| (child, depth + 1)));
+        }
+    }
+    rows
+}
+
+/// Describes the components of `entity`, with the fields of the reflected ones.
+fn component_views(world: &World, entity: Entity) -> Vec<ComponentView> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let Ok(components) = world.inspect_entity(entity) else {
+        return Vec::new();
+    };
+    let mut views: Vec<ComponentView> = components
+        .map(|info| {
+            let reflected = info.type_id().and_then(|type_id| {
+                let reflect_component = registry.get_type_data::<ReflectComponent>(type_id)?;
+                Some((type_id, reflect_component.reflect(world.entity(entity))?))
+            });
+            let mut fields = Vec::new();
+            if let Some((_, component)) = reflected {
+                describe_fields(component.as_partial_reflect(), "", 0, &mut fields);
+            }
+            // The type path is known even without the `debug` feature.
+            let name = info
+                .type_id()
+                .and_then(|type_id| registry.get_type_info(type_id))
+                .map(|type_info| type_info.type_path_table().short_path().to_owned())
+                .unwrap_or_else(|| info.name().shortname().to_string());
+            ComponentView {
+                name,
+                type_id: reflected.map(|(type_id, _)| type_id),
+                fields,
+            }
+        })
+        .collect();
+    views.sort_by(|a, b| a.name.cmp(&b.name));
+    views
+}
+
+/// Describes the fields of `value`, or `value` itself if it has no fields.
+fn describe_fields(value: &dyn PartialReflect, path: &str, depth: usize, out: &mut Vec<FieldView>) {
+    if let Some(leaf) = leaf_value(value) {
+        out.push(FieldView {
+            label: "value".to_owned(),
+            path: path.to_owned(),
+            depth,
+            value: leaf,
+        });
+        return;
+    }
+    for (label, field_path, field) in fields_of(value, path) {
+        describe(field, label, field_path, depth, out);
+    }
+}
+
+fn describe(
+    value: &dyn PartialReflect,
+    label: String,
+    path: String,
+    depth: usize,
+    out: &mut Vec<FieldView>,
+) {
+    if let Some(leaf) = leaf_value(value) {
+        out.push(FieldView {
+            label,
+            path,
+            depth,
+            value: leaf,
+        });
+        return;
+    }
+    if depth == MAX_FIELD_DEPTH {
+        out.push(FieldView {
+            label,
+            path,
+            depth,
+            value: FieldValue::ReadOnly("…".to_owned()),
+        });
+        return;
+    }
+
+    let header = match (value.try_downcast_ref::<Color>(), value.reflect_ref()) {
+        (Some(color), _) => FieldValue::Color(*color),
+        (None, ReflectRef::Enum(value)) => FieldValue::Group(Some(value.variant_name().to_owned())),
+        _ => FieldValue::Group(None),
+    };
+    out.push(FieldView {
+        label,
+        path: path.clone(),
+        depth,
+        value: header,
+    });
+    for (label, field_path, field) in fields_of(value, &path) {
+        describe(field, label, field_path, depth + 1, out);
+    }
+}
+
+/// Returns the fields of a struct, tuple, list or enum, with their labels and paths.
+fn fields_of<'a>(
+    value: &'a dyn PartialReflect,
+    path: &str,
+) -> Vec<(String, String, &'a dyn PartialReflect)> {
+    let named = |name: &str, field| (name.to_owned(), format!("{path}.{name}"), field);
+    let indexed = |index: usize, field| (format!("{index}"), format!("{path}.{index}"), field);
+    let listed = |index: usize, field| (format!("[{index}]"), format!("{path}[{index}]"), field);
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => (0..value.field_len())
+            .filter_map(|index| Some(named(value.name_at(index)?, value.field_at(index)?)))
+            .collect(),
+        ReflectRef::TupleStruct(value) => value
+            .iter_fields()
+            .enumerate()
+            .map(|(index, field)| indexed(index, field))
+            .collect(),
+        ReflectRef::Tuple(value) => value
+            .iter_fields()
+            .enumerate()
+            .map(|(index, field)| indexed(index, field))
+            .collect(),
+        ReflectRef::List(value) => value
+            .iter()
+            .take(MAX_LIST_ELEMENTS)
+            .enumerate()
+            .map(|(index, field)| listed(index, field))
+            .collect(),
+        ReflectRef::Array(value) => value
+            .iter()
+            .take(MAX_LIST_ELEMENTS)
+            .enumerate()
+            .map(|(index, field)| listed(index, field))
+            .collect(),
+        ReflectRef::Enum(value) => (0..value.field_len())
+            .filter_map(|index| {
+                let field = value.field_at(index)?;
+                Some(match value.name_at(index) {
+                    Some(name) => named(name, field),
+                    None => indexed(index, field),
+                })
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the value of a field that has no fields to show.
+fn leaf_value(value: &dyn PartialReflect) -> Option<FieldValue> {
+    if let Some(text) = number_text(value) {
+        let is_float =
+            value.try_downcast_ref::<f32>().is_some() || value.try_downcast_ref::<f64>().is_some();
+        let step = if is_float { 0.1 } else { 1.0 };
+        return Some(FieldValue::Number { text, step });
+    }
+    if let Some(value) = value.try_downcast_ref::<bool>() {
+        return Some(FieldValue::Bool(*value));
+    }
+    if let Some(text) = string_text(value) {
+        return Some(FieldValue::Text(text));
+    }
+    match value.reflect_ref() {
+        ReflectRef::Enum(value) if is_fieldless_enum(value.get_represented_type_info()) => {
+            Some(FieldValue::Variant(value.variant_name().to_owned()))
+        }
+        ReflectRef::Map(_) | ReflectRef::Set(_) | ReflectRef::Opaque(_) => {
+            let mut text = format!("{value:?}");
+            if text.chars().count() > 48 {
+                text = text.chars().take(47).chain(['…']).collect();
+            }
+            Some(FieldValue::ReadOnly(text))
+        }
+        _ => None,
+    }
+}
+
+fn read_field<T>(
+    world: &World,
+    field: &InspectorField,
+    read: impl FnOnce(&dyn PartialReflect) -> T,
+) -> Option<T> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let reflect_component = registry.get_type_data::<ReflectComponent>(field.component)?;
+    let component = reflect_component.reflect(world.get_entity(field.entity).ok()?)?;
+    let value = match field.path.as_str() {
+        "" => component.as_partial_reflect(),
+        path => component.reflect_path(path).ok()?,
+    };
+    Some(read(value))
+}
+
+/// Edits a field, returning whether it was changed.
+fn edit_field(
+    world: &mut World,
+    field: &InspectorField,
+    edit: impl FnOnce(&mut dyn PartialReflect) -> bool,
+) -> bool {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let Some(reflect_component) = registry.get_type_data::<ReflectComponent>(field.component)
+    else {
+        return false;
+    };
+    let Ok(entity) = world.get_entity_mut(field.entity) else {
+        return false;
+    };
+    let Some(mut component) = reflect_component.reflect_mut(entity) else {
+        return false;
+    };
+    let value = match field.path.as_str() {
+        "" => component.as_partial_reflect_mut(),
+        path => match component.reflect_path_mut(path) {
+            Ok(value) => value,
+            Err(_) => return false,
+        },
+    };
+    edit(value)
+}
+
+fn text(
+    text: impl Into<String>,
+    color: Color,
+    config: &EntityInspectorConfig,
+) -> (Text, TextFont, TextColor) {
+    (Text::new(text), config.text_font.clone(), TextColor(color))
+}
+
+fn row_node(depth: usize) -> Node {
+    Node {
+        flex_direction: FlexDirection::Row,
+        column_gap: Val::Px(6.0),
+        padding: UiRect::left(Val::Px(12.0 * depth as f32)),
+        flex_shrink: 0.0,
+        ..Default::default()
+    }
+}
+
+fn text_cell(text: impl Into<String>, color: Color, action: Option<InspectorAction>) -> Cell {
+    Cell::Text {
+        text: text.into(),
+        color,
+        action,
+    }
+}
+
+fn entity_row_specs(rows: &[EntityRow], selection: Option<Entity>) -> Vec<RowSpec> {
+    rows.iter()
+        .map(|entity_row| {
+            let color = if Some(entity_row.entity) == selection {
+                SELECTED_COLOR
+            } else {
+                VALUE_COLOR
+            };
+            RowSpec {
+                depth: entity_row.depth,
+                action: Some(InspectorAction::Select(entity_row.entity)),
+                cells: vec![text_cell(entity_row.label.clone(), color, None)],
+            }
+        })
+        .collect()
+}
+
+fn component_row_specs(
+    entity: Entity,
+    components: &[ComponentView],
+    focus: Option<&Focus>,
+) -> Vec<RowSpec> {
+    let mut rows = Vec::new();
+    for component in components {
+        let mut header = vec![text_cell(component.name.clone(), SELECTED_COLOR, None)];
+        let Some(type_id) = component.type_id else {
+            header.push(text_cell(" (not reflected)", READ_ONLY_COLOR, None));
+            rows.push(RowSpec {
+                depth: 0,
+                action: None,
+                cells: header,
+            });
+            continue;
+        };
+        rows.push(RowSpec {
+            depth: 0,
+            action: None,
+            cells: header,
+        });
+
+        for view in &component.fields {
+            let field = InspectorField {
+                entity,
+                component: type_id,
+                path: view.path.clone(),
+            };
+            let focused = focus.filter(|focus| focus.field == field);
+            let mut cells = vec![text_cell(format!("{}:", view.label), LABEL_COLOR, None)];
+            match &view.value {
+                FieldValue::Group(variant) => {
+                    if let Some(variant) = variant {
+                        cells.push(text_cell(variant.clone(), VALUE_COLOR, None));
+                    }
+                }
+                FieldValue::Color(color) => cells.push(Cell::Swatch(*color)),
+                FieldValue::Number { text, step } => {
+                    let step = *step;
+                    cells.push(text_cell(
+                        "-",
+                        EDITABLE_COLOR,
+                        Some(InspectorAction::Step(field.clone(), -step)),
+                    ));
+                    cells.push(editable_cell(&field, text, focused));
+                    cells.push(text_cell(
+                        "+",
+                        EDITABLE_COLOR,
+                        Some(InspectorAction::Step(field, step)),
+                    ));
+                }
+                FieldValue::Text(value) => cells.push(editable_cell(&field, value, focused)),
+                FieldValue::Bool(value) => cells.push(text_cell(
+                    format!("{value}"),
+                    EDITABLE_COLOR,
+                    Some(InspectorAction::Toggle(field)),
+                )),
+                FieldValue::Variant(variant) => cells.push(text_cell(
+                    format!("{variant} >"),
+                    EDITABLE_COLOR,
+                    Some(InspectorAction::CycleVariant(field)),
+                )),
+                FieldValue::ReadOnly(value) => {
+                    cells.push(text_cell(value.clone(), READ_ONLY_COLOR, None));
+                }
+            }
+            rows.push(RowSpec {
+                depth: view.depth + 1,
+                action: None,
+                cells,
+            });
+        }
+    }
+    rows
+}
+
+/// The value of a number or string field, which can be focused to type a new value.
+fn editable_cell(field: &InspectorField, value: &str, focus: Option<&Focus>) -> Cell {
+    let action = Some(InspectorAction::Focus(field.clone()));
+    match focus {
+        Some(focus) => text_cell(format!("{}_", focus.text), SELECTED_COLOR, action),
+        None => text_cell(value, EDITABLE_COLOR, action),
+    }
+}
+
+/// Updates the rows of `list` to show `specs`, only changing the nodes of the rows that differ
+/// from the `shown` ones.
+fn sync_rows(
+    world: &mut World,
+    list: Entity,
+    shown: &mut Vec<(Entity, RowSpec)>,
+    specs: Vec<RowSpec>,
+    config: &EntityInspectorConfig,
+) {
+    for (row, _) in shown.drain(specs.len().min(shown.len())..) {
+        world.entity_mut(row).despawn();
+    }
+    for (index, spec) in specs.into_iter().enumerate() {
+        match shown.get_mut(index) {
+            Some((_, shown_spec)) if *shown_spec == spec => {}
+            Some((row, shown_spec)) => {
+                update_row(world, *row, Some(shown_spec), &spec, config);
+                *shown_spec = spec;
+            }
+            None => {
+                let row = world.spawn(ChildOf(list)).id();
+                update_row(world, row, None, &spec, config);
+                shown.push((row, spec));
+            }
+        }
+    }
+}
+
+/// Updates the node of a row, and its cells, from the `shown` spec to the new one.
+fn update_row(
+    world: &mut World,
+    row: Entity,
+    shown: Option<&RowSpec>,
+    spec: &RowSpec,
+    config: &EntityInspectorConfig,
+) {
+    let mut row_entity = world.entity_mut(row);
+    if shown.is_none_or(|shown| shown.depth != spec.depth) {
+        row_entity.insert(row_node(spec.depth));
+    }
+    if shown.is_none_or(|shown| shown.action != spec.action) {
+        match &spec.action {
+            Some(action) => row_entity.insert(action.clone()),
+            None => row_entity.remove::<InspectorAction>(),
+        };
+    }
+
+    let cells: Vec<Entity> = row_entity
+        .get::<Children>()
+        .map(|children| children.to_vec())
+        .unwrap_or_default();
+    let shown_cells = shown.map_or(&[][..], |shown| &shown.cells);
+    for (index, cell) in spec.cells.iter().enumerate() {
+        let shown_cell = shown_cells.get(index);
+        if shown_cell == Some(cell) {
+            continue;
+        }
+        match (cells.get(index), shown_cell) {
+            (Some(&entity), Some(shown_cell))
+                if core::mem::discriminant(shown_cell) == core::mem::discriminant(cell) =>
+            {
+                update_cell(world.entity_mut(entity), cell, config);
+            }
+            (Some(&entity), _) => {
+                let new = world.spawn_empty().id();
+                update_cell(world.entity_mut(new), cell, config);
+                world.entity_mut(row).insert_child(index, new);
+                world.entity_mut(entity).despawn();
+            }
+            (None, _) => {
+                let new = world.spawn(ChildOf(row)).id();
+                update_cell(world.entity_mut(new), cell, config);
+            }
+        }
+    }
+    for &cell in cells.iter().skip(spec.cells.len()) {
+        world.entity_mut(cell).despawn();
+    }
+}
+
+fn update_cell(mut entity: EntityWorldMut, cell: &Cell, config: &EntityInspectorConfig) {
+    match cell {
+        Cell::Text {
+            text: value,
+            color,
+            action,
+        } => {
+            entity.insert(text(value.clone(), *color, config));
+            match action {
+                Some(action) => entity.insert(action.clone()),
+                None => entity.remove::<InspectorAction>(),
+            };
+        }
+        Cell::Swatch(color) => {
+            entity.insert((
+                Node {
+                    width: Val::Px(14.0),
+                    height: Val::Px(14.0),
+                    ..Default::default()
+                },
+                BackgroundColor(*color),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::Vec2;
+    use bevy_reflect::Reflect;
+
+    #[derive(Reflect, Debug, PartialEq, Clone, Copy)]
+    enum Mode {
+        Idle,
+        Running,
+    }
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Stats {
+        speed: f32,
+        lives: u8,
+        alive: bool,
+        title: String,
+        mode: Mode,
+        position: Vec2,
+        tint: Color,
+    }
+
+    fn world() -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.insert_resource(InspectorSelection::default());
+        world.insert_resource(InspectorState::default());
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Stats>();
+            registry.register::<Name>();
+        }
+        let entity = world
+            .spawn((
+                Name::new("Player"),
+                Stats {
+                    speed: 1.0,
+                    lives: 3,
+                    alive: true,
+                    title: "Hero".into(),
+                    mode: Mode::Idle,
+                    position: Vec2::new(1.0, 2.0),
+                    tint: Color::WHITE,
+                },
+            ))
+            .id();
+        world.spawn((Name::new("Sword"), ChildOf(entity)));
+        (world, entity)
+    }
+
+    fn field(entity: Entity, component: TypeId, path: &str) -> InspectorField {
+        InspectorField {
+            entity,
+            component,
+            path: path.into(),
+        }
+    }
+
+    #[test]
+    fn describe_entities_and_components() {
+        let (mut world, entity) = world();
+        let inspector = world.spawn(InspectorRoot).id();
+
+        let rows = entity_rows(&mut world, inspector, 16);
+        let labels: Vec<_> = rows
+            .iter()
+            .map(|row| (row.label.split(' ').next().unwrap(), row.depth))
+            .collect();
+        assert_eq!(labels, [("Player", 0), ("Sword", 1)]);
+
+        let views = component_views(&world, entity);
+        let stats = views.iter().find(|view| view.name == "Stats").unwrap();
+        let value = |path: &str| {
+            stats
+                .fields
+                .iter()
+                .find(|field| field.path == path)
+                .map(|field| field.value.clone())
+                .unwrap()
+        };
+        assert_eq!(
+            value(".speed"),
+            FieldValue::Number {
+                text: "1".into(),
+                step: 0.1
+            }
+        );
+        assert_eq!(value(".alive"), FieldValue::Bool(true));
+        assert_eq!(value(".title"), FieldValue::Text("Hero".into()));
+        assert_eq!(value(".mode"), FieldValue::Variant("Idle".into()));
+        assert_eq!(value(".position"), FieldValue::Group(None));
+        assert!(matches!(value(".position.y"), FieldValue::Number { .. }));
+        assert_eq!(value(".tint"), FieldValue::Color(Color::WHITE));
+
+        let name = views.iter().find(|view| view.name == "Name").unwrap();
+        assert_eq!(name.fields[0].value, FieldValue::Text("Player".into()));
+    }
+
+    #[test]
+    fn edit_fields() {
+        let (mut world, entity) = world();
+        let stats = TypeId::of::<Stats>();
+
+        apply_action(
+            &mut world,
+            InspectorAction::Step(field(entity, stats, ".speed"), 0.5),
+        );
+        apply_action(
+            &mut world,
+            InspectorAction::Step(field(entity, stats, ".lives"), -5.0),
+        );
+        apply_action(
+            &mut world,
+            InspectorAction::Toggle(field(entity, stats, ".alive")),
+        );
+        apply_action(
+            &mut world,
+            InspectorAction::CycleVariant(field(entity, stats, ".mode")),
+        );
+        assert!(edit_field(
+            &mut world,
+            &field(entity, stats, ".title"),
+            |value| { set_text(value, "Villain") }
+        ));
+        assert!(edit_field(
+            &mut world,
+            &field(entity, stats, ".position.x"),
+            |value| set_text(value, " 4.5 ")
+        ));
+        assert!(!edit_field(
+            &mut world,
+            &field(entity, stats, ".position.x"),
+            |value| set_text(value, "four")
+        ));
+        assert!(edit_field(
+            &mut world,
+            &field(entity, TypeId::of::<Name>(), ""),
+            |value| set_text(value, "Boss")
+        ));
+
+        let stats = world.get::<Stats>(entity).unwrap();
+        assert_eq!(stats.speed, 1.5);
+        // Integers saturate at their bounds.
+        assert_eq!(stats.lives, 0);
+        assert!(!stats.alive);
+        assert_eq!(stats.mode, Mode::Running);
+        assert_eq!(stats.title, "Villain");
+        assert_eq!(stats.position, Vec2::new(4.5, 2.0));
+        assert_eq!(world.get::<Name>(entity).unwrap().as_str(), "Boss");
+
+        apply_action(
+            &mut world,
+            InspectorAction::Focus(field(entity, TypeId::of::<Stats>(), ".title")),
+        );
+        let focus = world.resource::<InspectorState>().focus.clone().unwrap();
+        assert_eq!(focus.text, "Villain");
+    }
+
+    #[test]
+    fn inspector_ui() {
+        let mut app = App::new();
+        app.add_plugins((
+            bevy_time::TimePlugin,
+            bevy_input::InputPlugin,
+            EntityInspectorPlugin::default(),
+        ))
+        .register_type::<Stats>();
+        let player = app.world_mut().spawn(Name::new("Player")).id();
+        app.update();
+
+        let texts = |app: &mut App| {
+            app.world_mut()
+                .query::<&Text>()
+                .iter(app.world())
+                .map(|text| text.0.clone())
+                .collect::<Vec<_>>()
+        };
+        assert!(texts(&mut app)
+            .iter()
+            .any(|text| text.starts_with("Player")));
+        assert!(!texts(&mut app).iter().any(|text| text == "Name"));
+
+        app.world_mut().resource_mut::<InspectorSelection>().0 = Some(player);
+        app.update();
+        assert!(texts(&mut app).iter().any(|text| text == "Name"));
+
+        // Rows are updated in place when the world changes.
+        let label = |app: &mut App, prefix: &str| {
+            app.world_mut()
+                .query::<(Entity, &Text)>()
+                .iter(app.world())
+                .find(|(_, text)| text.0.starts_with(prefix))
+                .map(|(entity, _)| entity)
+        };
+        let player_label = label(&mut app, "Player (").unwrap();
+        app.world_mut().entity_mut(player).insert(Name::new("Boss"));
+        app.world_mut().resource_mut::<InspectorState>().dirty = true;
+        app.update();
+        assert_eq!(label(&mut app, "Boss ("), Some(player_label));
+        assert_eq!(label(&mut app, "Player ("), None);
+    }
+}
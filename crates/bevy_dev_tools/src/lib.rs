@@ -8,17 +8,31 @@
 //! This crate provides additional utilities for the [Bevy game engine](https://bevy.org),
 //! focused on improving developer experience.
 
+extern crate alloc;
+
 use bevy_app::prelude::*;
 
 #[cfg(feature = "bevy_ci_testing")]
 pub mod ci_testing;
 
+pub mod console;
+
 pub mod fps_overlay;
 
+pub mod inspector;
+
+pub mod log_viewer;
+
 pub mod picking_debug;
 
+mod reflect_text;
+
+mod simulated_input;
+
 pub mod states;
 
+pub mod test_app;
+
 /// Enables developer tools in an [`App`]. This plugin is added automatically with `bevy_dev_tools`
 /// feature.
 ///
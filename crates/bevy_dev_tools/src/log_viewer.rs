@@ -0,0 +1,458 @@
+//! Module containing logic for the in-game log viewer.
+//!
+//! The viewer shows the recent log events captured by the `LogPlugin` in its
+//! [`LogBuffer`], filtered by level and target. Click a level to only show the events at this
+//! level or more severe, and click the target filter to type the part of the target to look for.
+//! The `Save` button writes all the captured events to a file, to attach it to a bug report.
+
+use bevy_app::prelude::*;
+use bevy_color::Color;
+use bevy_ecs::prelude::*;
+use bevy_input::{
+    keyboard::{Key, KeyCode, KeyboardInput},
+    ButtonInput, ButtonState,
+};
+use bevy_log::{info, warn, CapturedLog, Level, LogBuffer};
+use bevy_picking::events::{Click, Pointer};
+use bevy_text::{TextColor, TextFont};
+use bevy_ui::{
+    widget::Text, BackgroundColor, Display, FlexDirection, GlobalZIndex, Node, PositionType,
+    UiRect, Val,
+};
+use std::{fs::File, io::Write, path::PathBuf};
+
+/// [`GlobalZIndex`] used to render the log viewer.
+///
+/// It is under the [`INSPECTOR_ZINDEX`](crate::inspector::INSPECTOR_ZINDEX).
+pub const LOG_VIEWER_ZINDEX: i32 = i32::MAX - 80;
+
+const PANEL_COLOR: Color = Color::srgba(0.06, 0.06, 0.08, 0.9);
+const BUTTON_COLOR: Color = Color::srgb(0.55, 0.8, 1.0);
+const SELECTED_COLOR: Color = Color::srgb(1.0, 0.8, 0.3);
+
+/// The levels that can be selected, from the most severe.
+const LEVELS: [Level; 5] = [
+    Level::ERROR,
+    Level::WARN,
+    Level::INFO,
+    Level::DEBUG,
+    Level::TRACE,
+];
+
+/// A plugin that adds an in-game viewer of the recent log events to the Bevy application.
+///
+/// The events are captured by the `LogPlugin` when its `log_buffer_capacity` is set, as it is 0
+/// by default. The viewer needs the picking plugins and the UI picking backend to be able to
+/// click its buttons, which are part of the `DefaultPlugins`.
+#[derive(Default)]
+pub struct LogViewerPlugin {
+    /// Starting configuration of the viewer, this can be later be changed through the
+    /// [`LogViewerConfig`] resource.
+    pub config: LogViewerConfig,
+}
+
+impl Plugin for LogViewerPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone())
+            .init_resource::<TargetFocus>()
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (
+                    toggle_log_viewer,
+                    edit_target_filter,
+                    show_log_viewer.run_if(resource_changed::<LogViewerConfig>),
+                    update_log_viewer.run_if(
+                        resource_exists::<LogBuffer>.and(
+                            resource_changed::<LogBuffer>
+                                .or(resource_changed::<LogViewerConfig>)
+                                .or(resource_changed::<TargetFocus>),
+                        ),
+                    ),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Configuration options for the log viewer.
+#[derive(Resource, Clone)]
+pub struct LogViewerConfig {
+    /// Displays the viewer if true.
+    pub enabled: bool,
+    /// The key toggling the viewer, if any.
+    ///
+    /// Defaults to F9.
+    pub toggle_key: Option<KeyCode>,
+    /// Configuration of the text of the viewer.
+    pub text_font: TextFont,
+    /// Only shows the events at this level or more severe.
+    ///
+    /// Defaults to [`Level::INFO`].
+    pub min_level: Level,
+    /// Only shows the events whose target contains this text, if it isn't empty.
+    pub target_filter: String,
+    /// The number of most recent events shown.
+    pub visible_lines: usize,
+    /// The file the `Save` button writes the captured events to.
+    pub save_path: PathBuf,
+}
+
+impl Default for LogViewerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            toggle_key: Some(KeyCode::F9),
+            text_font: TextFont {
+                font_size: 13.0,
+                ..Default::default()
+            },
+            min_level: Level::INFO,
+            target_filter: String::new(),
+            visible_lines: 24,
+            save_path: PathBuf::from("bevy_log.txt"),
+        }
+    }
+}
+
+impl LogViewerConfig {
+    /// Returns `true` if the `log` passes the level and target filters.
+    pub fn shows(&self, log: &CapturedLog) -> bool {
+        log.level <= self.min_level && log.target.contains(self.target_filter.as_str())
+    }
+}
+
+/// Whether the target filter is being typed.
+#[derive(Resource, Default)]
+struct TargetFocus(bool);
+
+/// The root node of the viewer.
+#[derive(Component)]
+struct LogViewerRoot;
+
+/// The node listing the events.
+#[derive(Component)]
+struct LogList;
+
+/// A line of the [`LogList`].
+#[derive(Component)]
+struct LogLine;
+
+/// The button selecting a level.
+#[derive(Component)]
+struct LevelButton(Level);
+
+/// The button editing the target filter.
+#[derive(Component)]
+struct TargetButton;
+
+fn setup(mut commands: Commands, config: Res<LogViewerConfig>) {
+    let root = commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                bottom: Val::Px(0.0),
+                width: Val::Percent(60.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(6.0)),
+                row_gap: Val::Px(4.0),
+                display: display(config.enabled),
+                ..Default::default()
+            },
+            BackgroundColor(PANEL_COLOR),
+            GlobalZIndex(LOG_VIEWER_ZINDEX),
+            LogViewerRoot,
+        ))
+        .id();
+    let header = commands
+        .spawn((
+            Node {
+                column_gap: Val::Px(10.0),
+                ..Default::default()
+            },
+            ChildOf(root),
+        ))
+        .id();
+    for level in LEVELS {
+        commands
+            .spawn((
+                text(level.as_str(), BUTTON_COLOR, &config),
+                LevelButton(level),
+                ChildOf(header),
+            ))
+            .observe(
+                move |_: On<Pointer<Click>>, mut config: ResMut<LogViewerConfig>| {
+                    config.min_level = level;
+                },
+            );
+    }
+    commands
+        .spawn((
+            text("", BUTTON_COLOR, &config),
+            TargetButton,
+            ChildOf(header),
+        ))
+        .observe(|_: On<Pointer<Click>>, mut focus: ResMut<TargetFocus>| {
+            focus.0 = true;
+        });
+    commands
+        .spawn((text("Save", BUTTON_COLOR, &config), ChildOf(header)))
+        .observe(
+            |_: On<Pointer<Click>>,
+             buffer: Option<Res<LogBuffer>>,
+             config: Res<LogViewerConfig>| {
+                if let Some(buffer) = buffer {
+                    save_logs(&buffer, &config);
+                }
+            },
+        );
+    commands.spawn((
+        Node {
+            flex_direction: FlexDirection::Column,
+            ..Default::default()
+        },
+        LogList,
+        ChildOf(root),
+    ));
+}
+
+fn text(value: &str, color: Color, config: &LogViewerConfig) -> (Text, TextFont, TextColor) {
+    (Text::new(value), config.text_font.clone(), TextColor(color))
+}
+
+fn display(enabled: bool) -> Display {
+    match enabled {
+        true => Display::Flex,
+        false => Display::None,
+    }
+}
+
+fn level_color(level: Level) -> Color {
+    match level {
+        Level::ERROR => Color::srgb(1.0, 0.4, 0.4),
+        Level::WARN => Color::srgb(1.0, 0.8, 0.3),
+        Level::INFO => Color::srgb(0.85, 0.85, 0.9),
+        Level::DEBUG => Color::srgb(0.6, 0.75, 1.0),
+        Level::TRACE => Color::srgb(0.6, 0.6, 0.65),
+    }
+}
+
+/// Writes all the captured events to the file of the [`LogViewerConfig`].
+fn save_logs(buffer: &LogBuffer, config: &LogViewerConfig) {
+    let result = File::create(&config.save_path)
+        .and_then(|mut file| buffer.iter().try_for_each(|log| writeln!(file, "{log}")));
+    match result {
+        Ok(()) => info!(
+            "Saved {} log events to {}",
+            buffer.len(),
+            config.save_path.display()
+        ),
+        Err(error) => warn!(
+            "Failed to save the log events to {}: {error}",
+            config.save_path.display()
+        ),
+    }
+}
+
+fn toggle_log_viewer(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut config: ResMut<LogViewerConfig>,
+    focus: Res<TargetFocus>,
+) {
+    // Don't toggle the viewer while typing in it.
+    if focus.0 {
+        return;
+    }
+    if config.toggle_key.is_some_and(|key| keys.just_pressed(key)) {
+        config.enabled = !config.enabled;
+    }
+}
+
+/// Types into the target filter, while it is focused.
+fn edit_target_filter(
+    mut events: EventReader<KeyboardInput>,
+    mut focus: ResMut<TargetFocus>,
+    mut config: ResMut<LogViewerConfig>,
+) {
+    for event in events.read() {
+        if !focus.0 || event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter | Key::Escape => focus.0 = false,
+            Key::Backspace => {
+                config.target_filter.pop();
+            }
+            _ => {
+                let Some(text) = &event.text else {
+                    continue;
+                };
+                config
+                    .target_filter
+                    .extend(text.chars().filter(|character| !character.is_control()));
+            }
+        }
+    }
+}
+
+fn show_log_viewer(config: Res<LogViewerConfig>, mut roots: Query<&mut Node, With<LogViewerRoot>>) {
+    for mut node in &mut roots {
+        node.display = display(config.enabled);
+    }
+}
+
+/// The most recent events passing the filters, from the oldest.
+fn visible_logs<'a>(buffer: &'a LogBuffer, config: &LogViewerConfig) -> Vec<&'a CapturedLog> {
+    let mut logs: Vec<_> = buffer
+        .iter()
+        .rev()
+        .filter(|log| config.shows(log))
+        .take(config.visible_lines)
+        .collect();
+    logs.reverse();
+    logs
+}
+
+fn update_log_viewer(
+    buffer: Res<LogBuffer>,
+    config: Res<LogViewerConfig>,
+    focus: Res<TargetFocus>,
+    lists: Query<(Entity, Option<&Children>), With<LogList>>,
+    mut lines: Query<
+        (&mut Text, &mut TextColor),
+        (With<LogLine>, Without<LevelButton>, Without<TargetButton>),
+    >,
+    mut level_buttons: Query<(&LevelButton, &mut TextColor)>,
+    mut target_buttons: Query<&mut Text, With<TargetButton>>,
+    mut commands: Commands,
+) {
+    if !config.enabled {
+        return;
+    }
+    for (button, mut color) in &mut level_buttons {
+        color.0 = match button.0 == config.min_level {
+            true => SELECTED_COLOR,
+            false => BUTTON_COLOR,
+        };
+    }
+    for mut text in &mut target_buttons {
+        let cursor = if focus.0 { "_" } else { "" };
+        text.0 = format!("target: {}{cursor}", config.target_filter);
+    }
+
+    // Update the existing lines in place, only spawning or despawning the lines in excess.
+    let logs = visible_logs(&buffer, &config);
+    for (list, children) in &lists {
+        let shown = children.map_or(&[][..], |children| &children[..]);
+        for (index, log) in logs.iter().enumerate() {
+            let (value, color) = (log.to_string(), level_color(log.level));
+            match shown.get(index).and_then(|&line| lines.get_mut(line).ok()) {
+                Some((mut line_text, mut line_color)) => {
+                    if line_text.0 != value {
+                        line_text.0 = value;
+                    }
+                    if line_color.0 != color {
+                        line_color.0 = color;
+                    }
+                }
+                None => {
+                    commands.spawn((text(&value, color, &config), LogLine, ChildOf(list)));
+                }
+            }
+        }
+        for &line in shown.iter().skip(logs.len()) {
+            commands.entity(line).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_log::tracing::{self, debug, error, info};
+    use bevy_log::tracing_subscriber::{prelude::*, Registry};
+
+    fn app() -> App {
+        let buffer = LogBuffer::new(16);
+        let subscriber = Registry::default().with(buffer.layer());
+        let mut app = App::new();
+        app.add_plugins((
+            bevy_input::InputPlugin,
+            LogViewerPlugin {
+                config: LogViewerConfig {
+                    enabled: true,
+                    ..Default::default()
+                },
+            },
+        ))
+        .insert_resource(buffer)
+        .add_event::<CapturedLog>()
+        .add_systems(Last, bevy_log::collect_captured_logs);
+        tracing::subscriber::with_default(subscriber, || {
+            info!(target: "game::enemy", "spawned");
+            debug!(target: "game::enemy", "thinking");
+            error!(target: "bevy_render", "lost device");
+        });
+        app.update();
+        app
+    }
+
+    fn messages(logs: Vec<&CapturedLog>) -> Vec<&str> {
+        logs.iter().map(|log| log.message.as_str()).collect()
+    }
+
+    #[test]
+    fn filter_logs() {
+        let app = app();
+        let buffer = app.world().resource::<LogBuffer>();
+        let mut config = LogViewerConfig::default();
+        assert_eq!(
+            messages(visible_logs(buffer, &config)),
+            ["spawned", "lost device"]
+        );
+        config.min_level = Level::TRACE;
+        config.target_filter = "enemy".into();
+        assert_eq!(
+            messages(visible_logs(buffer, &config)),
+            ["spawned", "thinking"]
+        );
+        config.visible_lines = 1;
+        assert_eq!(messages(visible_logs(buffer, &config)), ["thinking"]);
+    }
+
+    #[test]
+    fn log_viewer_ui() {
+        let mut app = app();
+        app.update();
+        let texts = |app: &mut App| {
+            app.world_mut()
+                .query::<&Text>()
+                .iter(app.world())
+                .map(|text| text.0.clone())
+                .collect::<Vec<_>>()
+        };
+        assert!(texts(&mut app)
+            .iter()
+            .any(|text| text.ends_with("game::enemy: spawned")));
+        assert!(!texts(&mut app).iter().any(|text| text.contains("thinking")));
+
+        let lines = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<Entity, With<LogLine>>()
+                .iter(app.world())
+                .collect::<Vec<_>>()
+        };
+        let shown = lines(&mut app);
+        assert_eq!(shown.len(), 2);
+
+        app.world_mut().resource_mut::<LogViewerConfig>().min_level = Level::ERROR;
+        app.update();
+        assert!(!texts(&mut app).iter().any(|text| text.contains("spawned")));
+        assert!(texts(&mut app)
+            .iter()
+            .any(|text| text.ends_with("bevy_render: lost device")));
+        // The first line is reused, and the second one despawned.
+        assert_eq!(lines(&mut app), shown[..1]);
+    }
+}
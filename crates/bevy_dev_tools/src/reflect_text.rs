@@ -0,0 +1,118 @@
+//! Conversions between reflected values and the text shown or typed in the developer tools.
+
+use bevy_ecs::name::Name;
+use bevy_reflect::{
+    DynamicEnum, DynamicVariant, PartialReflect, ReflectRef, TypeInfo, VariantInfo,
+};
+
+pub(crate) fn is_fieldless_enum(info: Option<&TypeInfo>) -> bool {
+    match info {
+        Some(TypeInfo::Enum(info)) => info
+            .iter()
+            .all(|variant| matches!(variant, VariantInfo::Unit(_))),
+        _ => false,
+    }
+}
+
+macro_rules! numbers {
+    ($macro:ident) => {
+        $macro!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize)
+    };
+}
+
+pub(crate) fn number_text(value: &dyn PartialReflect) -> Option<String> {
+    macro_rules! format_number {
+        ($($ty:ty),*) => {$(
+            if let Some(value) = value.try_downcast_ref::<$ty>() {
+                return Some(format!("{value}"));
+            }
+        )*};
+    }
+    numbers!(format_number);
+    None
+}
+
+pub(crate) fn string_text(value: &dyn PartialReflect) -> Option<String> {
+    if let Some(name) = value.try_downcast_ref::<Name>() {
+        return Some(name.as_str().to_owned());
+    }
+    value.try_downcast_ref::<String>().cloned()
+}
+
+pub(crate) fn step_number(value: &mut dyn PartialReflect, step: f64) -> bool {
+    macro_rules! step {
+        ($($ty:ty),*) => {$(
+            if let Some(value) = value.try_downcast_mut::<$ty>() {
+                // Float to integer casts saturate at the bounds of the integer.
+                *value = (*value as f64 + step) as $ty;
+                return true;
+            }
+        )*};
+    }
+    numbers!(step);
+    false
+}
+
+/// Sets a number, boolean, string, [`Name`] or field-less enum from text, returning whether the
+/// text was valid.
+pub(crate) fn set_text(value: &mut dyn PartialReflect, text: &str) -> bool {
+    if let Some(name) = value.try_downcast_mut::<Name>() {
+        name.set(text.to_owned());
+        return true;
+    }
+    if let Some(value) = value.try_downcast_mut::<String>() {
+        *value = text.to_owned();
+        return true;
+    }
+    if let Some(value) = value.try_downcast_mut::<bool>() {
+        let Ok(parsed) = text.trim().parse() else {
+            return false;
+        };
+        *value = parsed;
+        return true;
+    }
+    if is_fieldless_enum(value.get_represented_type_info()) {
+        return set_variant(value, text.trim());
+    }
+    macro_rules! parse {
+        ($($ty:ty),*) => {$(
+            if let Some(value) = value.try_downcast_mut::<$ty>() {
+                let Ok(parsed) = text.trim().parse() else {
+                    return false;
+                };
+                *value = parsed;
+                return true;
+            }
+        )*};
+    }
+    numbers!(parse);
+    false
+}
+
+/// Switches an enum without fields to its next variant.
+pub(crate) fn cycle_variant(value: &mut dyn PartialReflect) -> bool {
+    let Some(TypeInfo::Enum(info)) = value.get_represented_type_info() else {
+        return false;
+    };
+    let ReflectRef::Enum(current) = value.reflect_ref() else {
+        return false;
+    };
+    let Some(next) = info.variant_at((current.variant_index() + 1) % info.variant_len()) else {
+        return false;
+    };
+    let next = DynamicEnum::new(next.name(), DynamicVariant::Unit);
+    value.try_apply(&next).is_ok()
+}
+
+/// Switches an enum without fields to the variant named `variant`, returning whether it exists.
+pub(crate) fn set_variant(value: &mut dyn PartialReflect, variant: &str) -> bool {
+    let Some(TypeInfo::Enum(info)) = value.get_represented_type_info() else {
+        return false;
+    };
+    if !info.contains_variant(variant) {
+        return false;
+    }
+    value
+        .try_apply(&DynamicEnum::new(variant, DynamicVariant::Unit))
+        .is_ok()
+}
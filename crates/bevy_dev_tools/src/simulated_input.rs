@@ -0,0 +1,254 @@
+//! Injection of input events, as if they were produced by real devices, used by the
+//! [`TestApp`](crate::test_app::TestApp) and the CI testing events.
+
+use bevy_ecs::prelude::*;
+use bevy_input::{
+    gamepad::{
+        GamepadAxis, GamepadButton, GamepadConnection, GamepadConnectionEvent,
+        RawGamepadAxisChangedEvent, RawGamepadButtonChangedEvent, RawGamepadEvent,
+    },
+    keyboard::{Key, KeyCode, KeyboardInput, NativeKey, NativeKeyCode},
+    mouse::{MouseButton, MouseButtonInput},
+    ButtonState,
+};
+use bevy_math::Vec2;
+use bevy_window::{CursorMoved, PrimaryWindow, Window, WindowEvent, WindowResized};
+use tracing::warn;
+
+/// The virtual gamepads used by the simulated input, by number.
+#[derive(Resource, Default)]
+pub(crate) struct SimulatedGamepads(Vec<Entity>);
+
+/// Returns the primary window, warning that the event can't be sent if there is none.
+fn primary_window(world: &mut World) -> Option<Entity> {
+    let window = world
+        .query_filtered::<Entity, With<PrimaryWindow>>()
+        .iter(world)
+        .next();
+    if window.is_none() {
+        warn!("No primary window to send the input to.");
+    }
+    window
+}
+
+/// Sends an event of a window, both on its own and as a [`WindowEvent`], like the windowing
+/// backend does.
+fn send_window_event<E: BufferedEvent + Clone + Into<WindowEvent>>(world: &mut World, event: E) {
+    world.send_event(event.clone());
+    world.send_event(event.into());
+}
+
+/// Returns the logical key of a key, on a US QWERTY layout without modifiers.
+///
+/// Letters are lowercase. The keys without an equivalent here are [`Key::Unidentified`].
+fn logical_key(key_code: KeyCode) -> Key {
+    let character = match key_code {
+        KeyCode::KeyA => "a",
+        KeyCode::KeyB => "b",
+        KeyCode::KeyC => "c",
+        KeyCode::KeyD => "d",
+        KeyCode::KeyE => "e",
+        KeyCode::KeyF => "f",
+        KeyCode::KeyG => "g",
+        KeyCode::KeyH => "h",
+        KeyCode::KeyI => "i",
+        KeyCode::KeyJ => "j",
+        KeyCode::KeyK => "k",
+        KeyCode::KeyL => "l",
+        KeyCode::KeyM => "m",
+        KeyCode::KeyN => "n",
+        KeyCode::KeyO => "o",
+        KeyCode::KeyP => "p",
+        KeyCode::KeyQ => "q",
+        KeyCode::KeyR => "r",
+        KeyCode::KeyS => "s",
+        KeyCode::KeyT => "t",
+        KeyCode::KeyU => "u",
+        KeyCode::KeyV => "v",
+        KeyCode::KeyW => "w",
+        KeyCode::KeyX => "x",
+        KeyCode::KeyY => "y",
+        KeyCode::KeyZ => "z",
+        KeyCode::Digit0 => "0",
+        KeyCode::Digit1 => "1",
+        KeyCode::Digit2 => "2",
+        KeyCode::Digit3 => "3",
+        KeyCode::Digit4 => "4",
+        KeyCode::Digit5 => "5",
+        KeyCode::Digit6 => "6",
+        KeyCode::Digit7 => "7",
+        KeyCode::Digit8 => "8",
+        KeyCode::Digit9 => "9",
+        KeyCode::Minus => "-",
+        KeyCode::Equal => "=",
+        KeyCode::BracketLeft => "[",
+        KeyCode::BracketRight => "]",
+        KeyCode::Backslash => "\\",
+        KeyCode::Semicolon => ";",
+        KeyCode::Quote => "'",
+        KeyCode::Comma => ",",
+        KeyCode::Period => ".",
+        KeyCode::Slash => "/",
+        KeyCode::Backquote => "`",
+        KeyCode::Space => return Key::Space,
+        KeyCode::Enter | KeyCode::NumpadEnter => return Key::Enter,
+        KeyCode::Tab => return Key::Tab,
+        KeyCode::Backspace => return Key::Backspace,
+        KeyCode::Delete => return Key::Delete,
+        KeyCode::Escape => return Key::Escape,
+        KeyCode::Insert => return Key::Insert,
+        KeyCode::Home => return Key::Home,
+        KeyCode::End => return Key::End,
+        KeyCode::PageUp => return Key::PageUp,
+        KeyCode::PageDown => return Key::PageDown,
+        KeyCode::ArrowUp => return Key::ArrowUp,
+        KeyCode::ArrowDown => return Key::ArrowDown,
+        KeyCode::ArrowLeft => return Key::ArrowLeft,
+        KeyCode::ArrowRight => return Key::ArrowRight,
+        KeyCode::ShiftLeft | KeyCode::ShiftRight => return Key::Shift,
+        KeyCode::ControlLeft | KeyCode::ControlRight => return Key::Control,
+        KeyCode::AltLeft | KeyCode::AltRight => return Key::Alt,
+        KeyCode::SuperLeft | KeyCode::SuperRight => return Key::Super,
+        _ => return Key::Unidentified(NativeKey::Unidentified),
+    };
+    Key::Character(character.into())
+}
+
+pub(crate) fn key(world: &mut World, key_code: KeyCode, state: ButtonState) {
+    let Some(window) = primary_window(world) else {
+        return;
+    };
+    let logical_key = logical_key(key_code);
+    // Like the windowing backend, only the presses produce text.
+    let text = match (&logical_key, state) {
+        (Key::Character(character), ButtonState::Pressed) => Some(character.clone()),
+        (Key::Space, ButtonState::Pressed) => Some(" ".into()),
+        _ => None,
+    };
+    send_window_event(
+        world,
+        KeyboardInput {
+            key_code,
+            logical_key,
+            state,
+            text,
+            repeat: false,
+            window,
+        },
+    );
+}
+
+pub(crate) fn text(world: &mut World, text: &str) {
+    let Some(window) = primary_window(world) else {
+        return;
+    };
+    for character in text.chars() {
+        let character = character.to_string();
+        for (state, text) in [
+            (ButtonState::Pressed, Some(character.as_str().into())),
+            (ButtonState::Released, None),
+        ] {
+            send_window_event(
+                world,
+                KeyboardInput {
+                    key_code: KeyCode::Unidentified(NativeKeyCode::Unidentified),
+                    logical_key: Key::Character(character.as_str().into()),
+                    state,
+                    text,
+                    repeat: false,
+                    window,
+                },
+            );
+        }
+    }
+}
+
+pub(crate) fn cursor_move(world: &mut World, position: Vec2) {
+    let Some(window) = primary_window(world) else {
+        return;
+    };
+    let Some(mut window_component) = world.get_mut::<Window>(window) else {
+        return;
+    };
+    let delta = window_component
+        .cursor_position()
+        .map(|last_position| position - last_position);
+    // Bypass change detection, so the windowing backend doesn't warp the OS cursor to the
+    // simulated position.
+    window_component
+        .bypass_change_detection()
+        .set_cursor_position(Some(position));
+    send_window_event(
+        world,
+        CursorMoved {
+            window,
+            position,
+            delta,
+        },
+    );
+}
+
+pub(crate) fn mouse_button(world: &mut World, button: MouseButton, state: ButtonState) {
+    let Some(window) = primary_window(world) else {
+        return;
+    };
+    send_window_event(
+        world,
+        MouseButtonInput {
+            button,
+            state,
+            window,
+        },
+    );
+}
+
+pub(crate) fn window_resize(world: &mut World, size: Vec2) {
+    let Some(window) = primary_window(world) else {
+        return;
+    };
+    let Some(mut window_component) = world.get_mut::<Window>(window) else {
+        return;
+    };
+    window_component.resolution.set(size.x, size.y);
+    let (width, height) = (window_component.width(), window_component.height());
+    send_window_event(
+        world,
+        WindowResized {
+            window,
+            width,
+            height,
+        },
+    );
+}
+
+/// Returns the entity of the virtual gamepad with the given number, connecting it if needed.
+fn gamepad(world: &mut World, number: usize) -> Entity {
+    let connected = world.get_resource_or_init::<SimulatedGamepads>().0.len();
+    for index in connected..=number {
+        let gamepad = world.spawn_empty().id();
+        world.resource_mut::<SimulatedGamepads>().0.push(gamepad);
+        let event = GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Connected {
+                name: format!("Virtual gamepad {index}"),
+                vendor_id: None,
+                product_id: None,
+            },
+        );
+        world.send_event(RawGamepadEvent::from(event.clone()));
+        world.send_event(event);
+    }
+    world.resource::<SimulatedGamepads>().0[number]
+}
+
+pub(crate) fn gamepad_button(world: &mut World, number: usize, button: GamepadButton, value: f32) {
+    let event = RawGamepadButtonChangedEvent::new(gamepad(world, number), button, value);
+    world.send_event(RawGamepadEvent::from(event));
+    world.send_event(event);
+}
+
+pub(crate) fn gamepad_axis(world: &mut World, number: usize, axis: GamepadAxis, value: f32) {
+    let event = RawGamepadAxisChangedEvent::new(gamepad(world, number), axis, value);
+    world.send_event(RawGamepadEvent::from(event));
+    world.send_event(event);
+}
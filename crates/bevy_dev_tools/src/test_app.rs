@@ -0,0 +1,642 @@
+//! A headless, deterministic harness for writing integration tests of apps.
+//!
+//! ```
+//! # use bevy_app::prelude::*;
+//! # use bevy_dev_tools::test_app::TestApp;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_input::prelude::*;
+//! # use core::time::Duration;
+//! #[derive(Resource, Default, PartialEq, Debug)]
+//! struct Jumps(u32);
+//!
+//! #[derive(Event, BufferedEvent, Clone, PartialEq, Debug)]
+//! struct Jumped;
+//!
+//! fn jump(keys: Res<ButtonInput<KeyCode>>, mut jumps: ResMut<Jumps>, mut jumped: EventWriter<Jumped>) {
+//!     if keys.just_pressed(KeyCode::Space) {
+//!         jumps.0 += 1;
+//!         jumped.write(Jumped);
+//!     }
+//! }
+//!
+//! let mut app = TestApp::new();
+//! app.init_resource::<Jumps>()
+//!     .add_event::<Jumped>()
+//!     .add_systems(Update, jump);
+//! app.capture_events::<Jumped>();
+//!
+//! app.press_key(KeyCode::Space).update();
+//! app.assert_resource_eq(Jumps(1));
+//! app.assert_event(|_: &Jumped| true);
+//!
+//! app.release_key(KeyCode::Space).update();
+//! app.press_key(KeyCode::Space);
+//! app.advance_until(|world| world.resource::<Jumps>().0 == 2, Duration::from_secs(1));
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
+
+use bevy_app::{prelude::*, TaskPoolPlugin};
+use bevy_diagnostic::FrameCountPlugin;
+use bevy_ecs::{
+    prelude::*,
+    query::{QueryData, ROQueryItem},
+    schedule::ExecutorKind,
+};
+use bevy_input::{
+    gamepad::{GamepadAxis, GamepadButton},
+    keyboard::KeyCode,
+    mouse::MouseButton,
+    ButtonState, InputPlugin,
+};
+use bevy_math::Vec2;
+use bevy_time::{TimePlugin, TimeUpdateStrategy};
+use bevy_window::WindowPlugin;
+
+use crate::simulated_input as input;
+
+/// An [`App`] updated manually with a fixed frame time, with helpers to simulate input and to
+/// check what happened, for integration tests.
+///
+/// It dereferences to the [`App`], to add plugins and systems and to access the world. Its
+/// [`Time`](bevy_time::Time) advances by the [frame time](TestApp::frame_time) on every
+/// [update](TestApp::update), except for the first one, like in a real app.
+///
+/// The schedules of the main app use the [`ExecutorKind::SingleThreaded`] executor by default,
+/// while the schedules of the sub-apps, like the render app, keep their own executor. The input
+/// is sent as the events of real devices, in the primary window, and is processed on the next
+/// update. The assertions panic with a message describing the difference, like [`assert_eq`].
+pub struct TestApp {
+    app: App,
+    frame_time: Duration,
+    frames: u32,
+    executor_kind: ExecutorKind,
+}
+
+impl TestApp {
+    /// The default frame time, of a 60 Hz display.
+    pub const DEFAULT_FRAME_TIME: Duration = Duration::from_nanos(16_666_667);
+
+    /// Creates an app with the plugins needed by the harness: the task pools, the frame count,
+    /// the time, the input and a headless primary window.
+    pub fn new() -> Self {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            FrameCountPlugin,
+            TimePlugin,
+            InputPlugin,
+            WindowPlugin::default(),
+        ));
+        Self::from_app(app)
+    }
+
+    /// Wraps an existing app, which should contain the [`TimePlugin`] and, to simulate input,
+    /// the [`InputPlugin`] and a primary window.
+    pub fn from_app(mut app: App) -> Self {
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Self::DEFAULT_FRAME_TIME));
+        Self {
+            app,
+            frame_time: Self::DEFAULT_FRAME_TIME,
+            frames: 0,
+            executor_kind: ExecutorKind::SingleThreaded,
+        }
+    }
+
+    /// Returns the wrapped app.
+    pub fn into_app(self) -> App {
+        self.app
+    }
+
+    /// Returns the duration of a frame.
+    pub fn frame_time(&self) -> Duration {
+        self.frame_time
+    }
+
+    /// Sets the duration of the next frames.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frame_time` is zero.
+    pub fn set_frame_time(&mut self, frame_time: Duration) -> &mut Self {
+        assert!(!frame_time.is_zero(), "the frame time can't be zero");
+        self.frame_time = frame_time;
+        self.app
+            .insert_resource(TimeUpdateStrategy::ManualDuration(frame_time));
+        self
+    }
+
+    /// Returns the number of updates run by the harness.
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    /// Sets the executor of the schedules of the main app.
+    ///
+    /// Defaults to [`ExecutorKind::SingleThreaded`], so that the systems run in the same order
+    /// on every run.
+    pub fn set_executor_kind(&mut self, executor_kind: ExecutorKind) -> &mut Self {
+        self.executor_kind = executor_kind;
+        self
+    }
+
+    /// Runs one frame.
+    ///
+    /// The [executor](Self::set_executor_kind) is only set on the schedules of the main app, not
+    /// on the schedules of the sub-apps.
+    pub fn update(&mut self) -> &mut Self {
+        // Also covers the schedules added since the last frame.
+        let executor_kind = self.executor_kind;
+        for (_, schedule) in self.app.world_mut().resource_mut::<Schedules>().iter_mut() {
+            schedule.set_executor_kind(executor_kind);
+        }
+        self.app.update();
+        self.frames += 1;
+        self
+    }
+
+    /// Runs `frames` frames.
+    pub fn advance(&mut self, frames: u32) -> &mut Self {
+        for _ in 0..frames {
+            self.update();
+        }
+        self
+    }
+
+    /// Runs the frames covering `duration`, rounded up to a whole number of frames.
+    pub fn advance_time(&mut self, duration: Duration) -> &mut Self {
+        let frames = self.frames_in(duration);
+        self.advance(frames)
+    }
+
+    /// Runs frames until `condition` holds, checking it before each frame, and returns the number
+    /// of frames run.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the condition doesn't hold within `timeout`, rounded up to a whole number of
+    /// frames.
+    #[track_caller]
+    pub fn advance_until(
+        &mut self,
+        mut condition: impl FnMut(&World) -> bool,
+        timeout: Duration,
+    ) -> u32 {
+        let max_frames = self.frames_in(timeout);
+        for frames in 0..=max_frames {
+            if condition(self.app.world()) {
+                return frames;
+            }
+            if frames < max_frames {
+                self.update();
+            }
+        }
+        panic!("the condition didn't hold within {timeout:?} ({max_frames} frames)");
+    }
+
+    fn frames_in(&self, duration: Duration) -> u32 {
+        u32::try_from(duration.as_nanos().div_ceil(self.frame_time.as_nanos())).unwrap_or(u32::MAX)
+    }
+
+    /// Sends an event, to be read by the next update.
+    pub fn send_event<E: BufferedEvent>(&mut self, event: E) -> &mut Self {
+        self.app.world_mut().send_event(event);
+        self
+    }
+
+    /// Presses a key of the keyboard.
+    ///
+    /// Its logical key and text are those of a US QWERTY layout without modifiers: the letters
+    /// are lowercase, and the keys without a simple equivalent, like the function keys, are
+    /// [`Key::Unidentified`](bevy_input::keyboard::Key::Unidentified). Use
+    /// [`type_text`](Self::type_text) to type other characters.
+    pub fn press_key(&mut self, key_code: KeyCode) -> &mut Self {
+        input::key(self.app.world_mut(), key_code, ButtonState::Pressed);
+        self
+    }
+
+    /// Releases a key of the keyboard.
+    pub fn release_key(&mut self, key_code: KeyCode) -> &mut Self {
+        input::key(self.app.world_mut(), key_code, ButtonState::Released);
+        self
+    }
+
+    /// Types `text`, pressing and releasing a key for each character.
+    pub fn type_text(&mut self, text: &str) -> &mut Self {
+        input::text(self.app.world_mut(), text);
+        self
+    }
+
+    /// Moves the cursor to `position` in the primary window, in logical pixels.
+    pub fn move_cursor(&mut self, position: Vec2) -> &mut Self {
+        input::cursor_move(self.app.world_mut(), position);
+        self
+    }
+
+    /// Presses a mouse button.
+    pub fn press_mouse_button(&mut self, button: MouseButton) -> &mut Self {
+        input::mouse_button(self.app.world_mut(), button, ButtonState::Pressed);
+        self
+    }
+
+    /// Releases a mouse button.
+    pub fn release_mouse_button(&mut self, button: MouseButton) -> &mut Self {
+        input::mouse_button(self.app.world_mut(), button, ButtonState::Released);
+        self
+    }
+
+    /// Changes the value of a button of a virtual gamepad, between `0.0` (released) and `1.0`
+    /// (fully pressed).
+    ///
+    /// Virtual gamepads are numbered from `0`, and are connected the first time they are used.
+    pub fn set_gamepad_button(
+        &mut self,
+        gamepad: usize,
+        button: GamepadButton,
+        value: f32,
+    ) -> &mut Self {
+        input::gamepad_button(self.app.world_mut(), gamepad, button, value);
+        self
+    }
+
+    /// Changes the value of an axis of a virtual gamepad, between `-1.0` and `1.0`.
+    ///
+    /// Virtual gamepads are numbered from `0`, and are connected the first time they are used.
+    pub fn set_gamepad_axis(&mut self, gamepad: usize, axis: GamepadAxis, value: f32) -> &mut Self {
+        input::gamepad_axis(self.app.world_mut(), gamepad, axis, value);
+        self
+    }
+
+    /// Resizes the primary window, in logical pixels.
+    pub fn resize_window(&mut self, size: Vec2) -> &mut Self {
+        input::window_resize(self.app.world_mut(), size);
+        self
+    }
+
+    /// Starts recording the events of type `E`, at the end of each frame.
+    pub fn capture_events<E: BufferedEvent + Clone>(&mut self) -> &mut Self {
+        if !self.app.world().contains_resource::<CapturedEvents<E>>() {
+            self.app
+                .add_event::<E>()
+                .insert_resource(CapturedEvents::<E>(Vec::new()))
+                .add_systems(Last, capture_events::<E>);
+        }
+        self
+    }
+
+    /// Returns the events of type `E` recorded since they were last [taken](Self::take_events).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the events of type `E` are not [captured](Self::capture_events).
+    #[track_caller]
+    pub fn events<E: BufferedEvent>(&self) -> &[E] {
+        &self.captured::<E>().0
+    }
+
+    /// Returns the events of type `E` recorded since they were last taken, and clears them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the events of type `E` are not [captured](Self::capture_events).
+    #[track_caller]
+    pub fn take_events<E: BufferedEvent>(&mut self) -> Vec<E> {
+        self.captured::<E>();
+        core::mem::take(&mut self.app.world_mut().resource_mut::<CapturedEvents<E>>().0)
+    }
+
+    #[track_caller]
+    fn captured<E: BufferedEvent>(&self) -> &CapturedEvents<E> {
+        self.app
+            .world()
+            .get_resource::<CapturedEvents<E>>()
+            .unwrap_or_else(|| {
+                panic!(
+                    "the events of type `{}` are not captured, call `capture_events` first",
+                    core::any::type_name::<E>()
+                )
+            })
+    }
+
+    /// Checks that one of the recorded events of type `E` matches `predicate`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no recorded event matches.
+    #[track_caller]
+    pub fn assert_event<E: BufferedEvent + Debug>(&self, predicate: impl Fn(&E) -> bool) {
+        let events = self.events::<E>();
+        assert!(
+            events.iter().any(predicate),
+            "no event of type `{}` matches, the recorded events are {events:?}",
+            core::any::type_name::<E>()
+        );
+    }
+
+    /// Checks that no event of type `E` was recorded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an event was recorded.
+    #[track_caller]
+    pub fn assert_no_events<E: BufferedEvent + Debug>(&self) {
+        let events = self.events::<E>();
+        assert!(
+            events.is_empty(),
+            "expected no event of type `{}`, the recorded events are {events:?}",
+            core::any::type_name::<E>()
+        );
+    }
+
+    /// Checks that the resource `R` equals `expected`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource doesn't exist or is different.
+    #[track_caller]
+    pub fn assert_resource_eq<R: Resource + PartialEq + Debug>(&self, expected: R) {
+        let Some(resource) = self.app.world().get_resource::<R>() else {
+            panic!(
+                "the resource `{}` doesn't exist",
+                core::any::type_name::<R>()
+            );
+        };
+        assert_eq!(*resource, expected);
+    }
+
+    /// Returns the items of the query `D`, formatted with [`Debug`] and sorted, to describe the
+    /// state of the world independently of the order of the entities.
+    pub fn snapshot<D: QueryData>(&mut self) -> Vec<String>
+    where
+        for<'w, 's> ROQueryItem<'w, 's, D>: Debug,
+    {
+        let world = self.app.world_mut();
+        let mut items: Vec<_> = world
+            .query::<D>()
+            .iter(world)
+            .map(|item| format!("{item:?}"))
+            .collect();
+        items.sort();
+        items
+    }
+
+    /// Checks that the [snapshot](Self::snapshot) of the query `D` has the lines of `expected`,
+    /// in any order, ignoring the indentation and the empty lines.
+    ///
+    /// ```
+    /// # use bevy_dev_tools::test_app::TestApp;
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component, Debug)]
+    /// struct Health(u32);
+    ///
+    /// let mut app = TestApp::new();
+    /// app.world_mut().spawn((Name::new("Player"), Health(100)));
+    /// app.world_mut().spawn((Name::new("Enemy"), Health(30)));
+    /// app.assert_snapshot::<(&Name, &Health)>(
+    ///     r#"
+    ///     ("Enemy", Health(30))
+    ///     ("Player", Health(100))
+    ///     "#,
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lines are different.
+    #[track_caller]
+    pub fn assert_snapshot<D: QueryData>(&mut self, expected: &str)
+    where
+        for<'w, 's> ROQueryItem<'w, 's, D>: Debug,
+    {
+        let actual = self.snapshot::<D>();
+        let mut expected: Vec<_> = expected
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+        expected.sort_unstable();
+        assert!(
+            actual == expected,
+            "the snapshot of `{}` is different\n  actual:\n    {}\nexpected:\n    {}",
+            core::any::type_name::<D>(),
+            actual.join("\n    "),
+            expected.join("\n    ")
+        );
+    }
+}
+
+impl Default for TestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for TestApp {
+    type Target = App;
+
+    fn deref(&self) -> &App {
+        &self.app
+    }
+}
+
+impl DerefMut for TestApp {
+    fn deref_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+}
+
+/// The events of type `E` recorded by a [`TestApp`].
+#[derive(Resource)]
+struct CapturedEvents<E: BufferedEvent>(Vec<E>);
+
+fn capture_events<E: BufferedEvent + Clone>(
+    mut events: EventReader<E>,
+    mut captured: ResMut<CapturedEvents<E>>,
+) {
+    captured.0.extend(events.read().cloned());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TestApp;
+    use bevy_app::prelude::*;
+    use bevy_ecs::{prelude::*, schedule::ExecutorKind};
+    use bevy_input::{gamepad::Gamepad, keyboard::Key, prelude::*};
+    use bevy_math::Vec2;
+    use bevy_time::Time;
+    use bevy_window::{PrimaryWindow, Window};
+    use core::time::Duration;
+
+    #[derive(Event, BufferedEvent, Clone, PartialEq, Debug)]
+    struct Scored(u32);
+
+    #[derive(Resource, Default, PartialEq, Debug)]
+    struct Score(u32);
+
+    #[derive(Component, Debug)]
+    struct Health(#[expect(dead_code, reason = "Only read in the snapshots.")] u32);
+
+    fn score(
+        keys: Res<ButtonInput<KeyCode>>,
+        mut score: ResMut<Score>,
+        mut scored: EventWriter<Scored>,
+    ) {
+        if keys.just_pressed(KeyCode::Space) {
+            score.0 += 10;
+            scored.write(Scored(score.0));
+        }
+    }
+
+    fn app() -> TestApp {
+        let mut app = TestApp::new();
+        app.init_resource::<Score>()
+            .add_event::<Scored>()
+            .add_systems(Update, score);
+        app
+    }
+
+    #[test]
+    fn fixed_time() {
+        let mut app = app();
+        app.set_frame_time(Duration::from_millis(100));
+        app.advance(3);
+        assert_eq!(app.frames(), 3);
+        assert_eq!(
+            app.world().resource::<Time>().elapsed(),
+            Duration::from_millis(200)
+        );
+
+        app.advance_time(Duration::from_millis(250));
+        assert_eq!(app.frames(), 6);
+        assert_eq!(
+            app.world().resource::<Time>().elapsed(),
+            Duration::from_millis(500)
+        );
+
+        let frames = app.advance_until(
+            |world| world.resource::<Time>().elapsed() >= Duration::from_secs(1),
+            Duration::from_secs(1),
+        );
+        assert_eq!(frames, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "the condition didn't hold within 300ms (3 frames)")]
+    fn advance_until_timeout() {
+        let mut app = app();
+        app.set_frame_time(Duration::from_millis(100))
+            .advance_until(|_| false, Duration::from_millis(300));
+    }
+
+    #[test]
+    fn events() {
+        let mut app = app();
+        app.capture_events::<Scored>();
+        app.update().assert_no_events::<Scored>();
+
+        app.press_key(KeyCode::Space).update();
+        app.assert_resource_eq(Score(10));
+        app.assert_event(|scored: &Scored| scored.0 == 10);
+
+        app.release_key(KeyCode::Space).update();
+        app.press_key(KeyCode::Space).update();
+        assert_eq!(app.take_events::<Scored>(), [Scored(10), Scored(20)]);
+        app.assert_no_events::<Scored>();
+
+        app.send_event(Scored(0)).update();
+        assert_eq!(app.events::<Scored>(), [Scored(0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "no event of type")]
+    fn assert_event_fails() {
+        let mut app = app();
+        app.capture_events::<Scored>()
+            .update()
+            .assert_event(|_: &Scored| true);
+    }
+
+    #[test]
+    fn input() {
+        let mut app = app();
+        app.update();
+        app.type_text("hi")
+            .move_cursor(Vec2::new(10.0, 20.0))
+            .press_mouse_button(MouseButton::Left)
+            .set_gamepad_button(0, GamepadButton::South, 1.0)
+            .resize_window(Vec2::new(640.0, 480.0))
+            .update();
+
+        assert!(app
+            .world()
+            .resource::<ButtonInput<Key>>()
+            .just_released(Key::Character("i".into())));
+        assert!(app
+            .world()
+            .resource::<ButtonInput<MouseButton>>()
+            .pressed(MouseButton::Left));
+        let window = app
+            .world_mut()
+            .query_filtered::<&Window, With<PrimaryWindow>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(window.cursor_position(), Some(Vec2::new(10.0, 20.0)));
+        assert_eq!(window.width(), 640.0);
+        let gamepad = app
+            .world_mut()
+            .query::<&Gamepad>()
+            .single(app.world())
+            .unwrap();
+        assert!(gamepad.pressed(GamepadButton::South));
+    }
+
+    #[test]
+    fn logical_keys() {
+        let mut app = app();
+        app.press_key(KeyCode::KeyA)
+            .press_key(KeyCode::ShiftLeft)
+            .press_key(KeyCode::F1)
+            .update();
+        let keys = app.world().resource::<ButtonInput<Key>>();
+        assert!(keys.pressed(Key::Character("a".into())));
+        assert!(keys.pressed(Key::Shift));
+
+        // The schedules run on the single-threaded executor by default.
+        let schedules = app.world().resource::<Schedules>();
+        assert!(schedules
+            .iter()
+            .all(|(_, schedule)| schedule.get_executor_kind() == ExecutorKind::SingleThreaded));
+    }
+
+    #[test]
+    fn snapshot() {
+        let mut app = app();
+        app.world_mut().spawn((Name::new("Player"), Health(100)));
+        app.world_mut().spawn((Name::new("Enemy"), Health(30)));
+        app.world_mut().spawn(Health(1));
+
+        assert_eq!(
+            app.snapshot::<&Health>(),
+            ["Health(1)", "Health(100)", "Health(30)"]
+        );
+        app.assert_snapshot::<(&Name, &Health)>(
+            r#"
+            ("Player", Health(100))
+            ("Enemy", Health(30))
+            "#,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the snapshot of")]
+    fn snapshot_fails() {
+        let mut app = app();
+        app.world_mut().spawn(Health(100));
+        app.assert_snapshot::<&Health>("Health(99)");
+    }
+}
@@ -1,4 +1,4 @@
-use alloc::{borrow::Cow, collections::VecDeque, string::String};
+use alloc::{borrow::Cow, collections::VecDeque, string::String, vec, vec::Vec};
 use core::{
     hash::{Hash, Hasher},
     time::Duration,
@@ -131,6 +131,7 @@ pub struct Diagnostic {
     ema: f64,
     ema_smoothing_factor: f64,
     max_history_length: usize,
+    percentiles: Vec<f64>,
     /// Disabled [`Diagnostic`]s are not measured or logged.
     pub is_enabled: bool,
 }
@@ -182,6 +183,7 @@ impl Diagnostic {
             sum: 0.0,
             ema: 0.0,
             ema_smoothing_factor: 2.0 / 21.0,
+            percentiles: Vec::new(),
             is_enabled: true,
         }
     }
@@ -223,6 +225,21 @@ impl Diagnostic {
         self
     }
 
+    /// Set the percentiles, between `0.0` and `100.0`, that are reported for this diagnostic, for
+    /// example by the [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin).
+    ///
+    /// Any percentile can be computed with [`percentile`](Self::percentile) regardless of this.
+    #[must_use]
+    pub fn with_percentiles(mut self, percentiles: impl Into<Vec<f64>>) -> Self {
+        self.percentiles = percentiles.into();
+        self
+    }
+
+    /// Get the percentiles that are reported for this diagnostic.
+    pub fn percentiles(&self) -> &[f64] {
+        &self.percentiles
+    }
+
     /// Get the [`DiagnosticPath`] that identifies this [`Diagnostic`].
     pub fn path(&self) -> &DiagnosticPath {
         &self.path
@@ -262,6 +279,77 @@ impl Diagnostic {
         }
     }
 
+    /// Return the given percentile, between `0.0` and `100.0`, of this diagnostic's recent values.
+    ///
+    /// This uses the nearest-rank method, so the result is always one of the values. `NaN` values
+    /// are ignored.
+    pub fn percentile(&self, percentile: f64) -> Option<f64> {
+        nearest_rank_percentile(&self.sorted_values(), percentile)
+    }
+
+    /// Return the given percentiles, between `0.0` and `100.0`, of this diagnostic's recent
+    /// values, in the same order.
+    ///
+    /// This is cheaper than calling [`percentile`](Self::percentile) for each of them.
+    pub fn percentiles_of(&self, percentiles: &[f64]) -> Option<Vec<f64>> {
+        let sorted = self.sorted_values();
+        percentiles
+            .iter()
+            .map(|&percentile| nearest_rank_percentile(&sorted, percentile))
+            .collect()
+    }
+
+    /// Return the median of this diagnostic's recent values.
+    pub fn median(&self) -> Option<f64> {
+        self.percentile(50.0)
+    }
+
+    /// Return the smallest of this diagnostic's recent values.
+    pub fn min(&self) -> Option<f64> {
+        self.values()
+            .copied()
+            .filter(|value| !value.is_nan())
+            .min_by(f64::total_cmp)
+    }
+
+    /// Return the largest of this diagnostic's recent values.
+    pub fn max(&self) -> Option<f64> {
+        self.values()
+            .copied()
+            .filter(|value| !value.is_nan())
+            .max_by(f64::total_cmp)
+    }
+
+    /// Return the number of this diagnostic's recent values that are greater than `threshold`.
+    pub fn count_above(&self, threshold: f64) -> usize {
+        self.values().filter(|&&value| value > threshold).count()
+    }
+
+    /// Count this diagnostic's recent values into the buckets delimited by `bounds`, which must
+    /// be sorted.
+    ///
+    /// The returned `Vec` has one more element than `bounds`: the first bucket counts the values
+    /// lower than `bounds[0]`, the bucket `i` counts the values from `bounds[i - 1]` to
+    /// `bounds[i]` (excluded), and the last bucket counts the values from the last bound. `NaN`
+    /// values are ignored.
+    pub fn histogram(&self, bounds: &[f64]) -> Vec<usize> {
+        let mut buckets = vec![0; bounds.len() + 1];
+        for &value in self.values().filter(|value| !value.is_nan()) {
+            buckets[bounds.partition_point(|&bound| bound <= value)] += 1;
+        }
+        buckets
+    }
+
+    fn sorted_values(&self) -> Vec<f64> {
+        let mut values: Vec<f64> = self
+            .values()
+            .copied()
+            .filter(|value| !value.is_nan())
+            .collect();
+        values.sort_by(f64::total_cmp);
+        values
+    }
+
     /// Return the number of elements for this diagnostic.
     pub fn history_len(&self) -> usize {
         self.history.len()
@@ -305,6 +393,18 @@ impl Diagnostic {
     }
 }
 
+/// Return the given percentile, between `0.0` and `100.0`, of sorted values, with the
+/// nearest-rank method.
+pub(crate) fn nearest_rank_percentile(sorted: &[f64], percentile: f64) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = percentile / 100.0 * sorted.len() as f64;
+    // Round the rank up, without `f64::ceil` which requires `std`.
+    let rank = rank as usize + usize::from(rank > (rank as usize) as f64);
+    Some(sorted[rank.clamp(1, sorted.len()) - 1])
+}
+
 /// A collection of [`Diagnostic`]s.
 #[derive(Debug, Default, Resource)]
 pub struct DiagnosticsStore {
@@ -356,6 +456,14 @@ pub struct Diagnostics<'w, 's> {
 }
 
 impl<'w, 's> Diagnostics<'w, 's> {
+    /// Get the [`Diagnostic`] with the given [`DiagnosticPath`], if it exists, for example to read
+    /// its statistics.
+    ///
+    /// The measurements added by this system param are only applied once the system has run.
+    pub fn get(&self, path: &DiagnosticPath) -> Option<&Diagnostic> {
+        self.store.get(path)
+    }
+
     /// Add a measurement to an enabled [`Diagnostic`]. The measurement is passed as a function so that
     /// it will be evaluated only if the [`Diagnostic`] is enabled. This can be useful if the value is
     /// costly to calculate.
@@ -458,4 +566,31 @@ mod tests {
             diagnostic.clear_history();
         }
     }
+
+    #[test]
+    fn statistics() {
+        let mut diagnostic = Diagnostic::new(DiagnosticPath::new("test"))
+            .with_max_history_length(10)
+            .with_percentiles([50.0, 99.0]);
+        assert_eq!(diagnostic.percentile(50.0), None);
+        assert_eq!(diagnostic.max(), None);
+
+        let now = Instant::now();
+        // The oldest value is dropped from the history.
+        for value in [100.0, 4.0, 1.0, 3.0, 2.0, 5.0, 8.0, 6.0, 9.0, 7.0, 10.0] {
+            diagnostic.add_measurement(DiagnosticMeasurement { time: now, value });
+        }
+
+        assert_eq!(diagnostic.min(), Some(1.0));
+        assert_eq!(diagnostic.max(), Some(10.0));
+        assert_eq!(diagnostic.median(), Some(5.0));
+        assert_eq!(diagnostic.percentile(0.0), Some(1.0));
+        assert_eq!(diagnostic.percentile(95.0), Some(10.0));
+        assert_eq!(
+            diagnostic.percentiles_of(diagnostic.percentiles()),
+            Some(vec![5.0, 10.0])
+        );
+        assert_eq!(diagnostic.count_above(7.0), 3);
+        assert_eq!(diagnostic.histogram(&[2.0, 5.0, 10.0]), vec![1, 3, 5, 1]);
+    }
 }
@@ -0,0 +1,514 @@
+use super::{nearest_rank_percentile, DiagnosticPath, DiagnosticsStore};
+
+use alloc::{collections::VecDeque, format, string::String, vec::Vec};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_platform::{
+    collections::{HashMap, HashSet},
+    time::Instant,
+};
+use log::{error, info};
+use serde_json::{json, Map, Value};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+};
+
+/// The number of the most recent measurements of each diagnostic kept by the
+/// [`ExportDiagnosticsPlugin`] to compute the percentiles of its summary.
+pub const SUMMARY_PERCENTILE_SAMPLES: usize = 4096;
+
+/// The file format used by the [`ExportDiagnosticsPlugin`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiagnosticsExportFormat {
+    /// Comma-separated values, with a header row and one row per diagnostic and period.
+    #[default]
+    Csv,
+    /// One JSON object per line, for each diagnostic and period.
+    JsonLines,
+    /// A JSON array of counter events, which can be opened as a timeline in `chrome://tracing`
+    /// or [Perfetto](https://ui.perfetto.dev).
+    ChromeTrace,
+}
+
+/// An App Plugin that writes diagnostics to a file, to compare them between runs.
+///
+/// The measurements of each [`Diagnostic`](crate::Diagnostic) are aggregated over
+/// [`frames_per_record`](Self::frames_per_record) frames, and a record with their mean, minimum
+/// and maximum is written for each period. When the app exits, a summary of the measurements is
+/// logged and optionally written to [`summary_path`](Self::summary_path). Its sample count,
+/// minimum, maximum and mean cover every measurement, while its percentiles cover the last
+/// [`SUMMARY_PERCENTILE_SAMPLES`] measurements of each diagnostic.
+///
+/// Diagnostics are collected by plugins such as
+/// [`FrameTimeDiagnosticsPlugin`](crate::FrameTimeDiagnosticsPlugin)
+/// or can be provided by the user.
+pub struct ExportDiagnosticsPlugin {
+    /// The file the diagnostics are written to. It is created or truncated when the plugin is
+    /// built.
+    pub path: PathBuf,
+    /// The format of the file.
+    ///
+    /// Defaults to [`DiagnosticsExportFormat::Csv`].
+    pub format: DiagnosticsExportFormat,
+    /// The number of frames over which measurements are aggregated into a single record.
+    ///
+    /// Defaults to `1`, writing a record each frame.
+    pub frames_per_record: u32,
+    /// If `Some` then only these diagnostics are exported.
+    pub filter: Option<HashSet<DiagnosticPath>>,
+    /// If `Some`, the file the summary is written to as JSON when the app exits.
+    pub summary_path: Option<PathBuf>,
+}
+
+impl ExportDiagnosticsPlugin {
+    /// Creates a plugin writing the diagnostics to `path`, in the given `format`.
+    pub fn new(path: impl Into<PathBuf>, format: DiagnosticsExportFormat) -> Self {
+        Self {
+            path: path.into(),
+            format,
+            frames_per_record: 1,
+            filter: None,
+            summary_path: None,
+        }
+    }
+
+    /// Aggregates the measurements over `frames` frames for each record.
+    #[must_use]
+    pub fn with_frames_per_record(mut self, frames: u32) -> Self {
+        self.frames_per_record = frames.max(1);
+        self
+    }
+
+    /// Only exports the diagnostics in `filter`.
+    #[must_use]
+    pub fn with_filter(mut self, filter: HashSet<DiagnosticPath>) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Writes the summary of the measurements to `path` when the app exits.
+    #[must_use]
+    pub fn with_summary(mut self, path: impl Into<PathBuf>) -> Self {
+        self.summary_path = Some(path.into());
+        self
+    }
+}
+
+impl Plugin for ExportDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        let file = match File::create(&self.path) {
+            Ok(file) => file,
+            Err(err) => {
+                error!(
+                    "Failed to create the diagnostics export file {}: {err}",
+                    self.path.display()
+                );
+                return;
+            }
+        };
+
+        let mut state = ExportDiagnosticsState {
+            writer: BufWriter::new(file),
+            format: self.format,
+            frames_per_record: self.frames_per_record.max(1),
+            filter: self.filter.clone(),
+            summary_path: self.summary_path.clone(),
+            start: Instant::now(),
+            frame: 0,
+            records: 0,
+            last_measurements: HashMap::default(),
+            periods: HashMap::default(),
+            summaries: HashMap::default(),
+            finished: false,
+        };
+        if let Err(err) = state.write_header() {
+            error!("Failed to write diagnostics: {err}");
+        }
+
+        app.insert_resource(state)
+            .add_systems(Last, export_diagnostics_system);
+    }
+}
+
+/// The measurements of a diagnostic during a period.
+#[derive(Default)]
+struct Period {
+    sum: f64,
+    min: f64,
+    max: f64,
+    count: usize,
+}
+
+#[derive(Resource)]
+struct ExportDiagnosticsState {
+    writer: BufWriter<File>,
+    format: DiagnosticsExportFormat,
+    frames_per_record: u32,
+    filter: Option<HashSet<DiagnosticPath>>,
+    summary_path: Option<PathBuf>,
+    start: Instant,
+    /// The number of frames since the plugin was built.
+    frame: u64,
+    /// The number of records written.
+    records: u64,
+    /// When the last measurement of each diagnostic was taken, to only count it once.
+    last_measurements: HashMap<DiagnosticPath, Instant>,
+    periods: HashMap<DiagnosticPath, Period>,
+    /// The aggregates of every value of each diagnostic, for the summary.
+    summaries: HashMap<DiagnosticPath, SummaryAccumulator>,
+    /// Whether the app exited and the export was completed.
+    finished: bool,
+}
+
+impl ExportDiagnosticsState {
+    fn write_header(&mut self) -> io::Result<()> {
+        match self.format {
+            DiagnosticsExportFormat::Csv => {
+                writeln!(self.writer, "frame,time,diagnostic,mean,min,max,samples")
+            }
+            DiagnosticsExportFormat::JsonLines => Ok(()),
+            DiagnosticsExportFormat::ChromeTrace => writeln!(self.writer, "["),
+        }
+    }
+
+    /// Adds the latest measurements of the diagnostics to the current period.
+    fn collect(&mut self, diagnostics: &DiagnosticsStore) {
+        for diagnostic in diagnostics.iter() {
+            if !diagnostic.is_enabled
+                || self
+                    .filter
+                    .as_ref()
+                    .is_some_and(|filter| !filter.contains(diagnostic.path()))
+            {
+                continue;
+            }
+            let Some(measurement) = diagnostic.measurement() else {
+                continue;
+            };
+            if !measurement.value.is_finite() {
+                continue;
+            }
+            let last = self
+                .last_measurements
+                .insert(diagnostic.path().clone(), measurement.time);
+            if last.is_some_and(|last| last >= measurement.time) {
+                continue;
+            }
+
+            let value = measurement.value;
+            let period = self.periods.entry(diagnostic.path().clone()).or_default();
+            if period.count == 0 {
+                period.min = value;
+                period.max = value;
+            }
+            period.sum += value;
+            period.min = period.min.min(value);
+            period.max = period.max.max(value);
+            period.count += 1;
+            self.summaries
+                .entry(diagnostic.path().clone())
+                .or_default()
+                .add(value);
+        }
+    }
+
+    /// Writes a record for each diagnostic measured during the current period.
+    fn write_records(&mut self) -> io::Result<()> {
+        let time = self.start.elapsed().as_secs_f64();
+        let mut periods: Vec<_> = self
+            .periods
+            .iter_mut()
+            .filter(|(_, period)| period.count > 0)
+            .collect();
+        periods.sort_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+
+        for (path, period) in periods {
+            let mean = period.sum / period.count as f64;
+            match self.format {
+                DiagnosticsExportFormat::Csv => writeln!(
+                    self.writer,
+                    "{},{time},{},{mean},{},{},{}",
+                    self.frame,
+                    csv_field(path.as_str()),
+                    period.min,
+                    period.max,
+                    period.count
+                )?,
+                DiagnosticsExportFormat::JsonLines => {
+                    let record = json!({
+                        "frame": self.frame,
+                        "time": time,
+                        "diagnostic": path.as_str(),
+                        "mean": mean,
+                        "min": period.min,
+                        "max": period.max,
+                        "samples": period.count,
+                    });
+                    serde_json::to_writer(&mut self.writer, &record)?;
+                    writeln!(self.writer)?;
+                }
+                DiagnosticsExportFormat::ChromeTrace => {
+                    if self.records > 0 {
+                        writeln!(self.writer, ",")?;
+                    }
+                    let event = json!({
+                        "name": path.as_str(),
+                        "ph": "C",
+                        "ts": (time * 1_000_000.0) as u64,
+                        "pid": 0,
+                        "tid": 0,
+                        "args": { "value": mean },
+                    });
+                    serde_json::to_writer(&mut self.writer, &event)?;
+                }
+            }
+            self.records += 1;
+            *period = Period::default();
+        }
+        Ok(())
+    }
+
+    /// Computes the summary of the values measured for each diagnostic.
+    fn summaries(&self) -> Vec<(&DiagnosticPath, DiagnosticSummary)> {
+        let mut summaries: Vec<_> = self
+            .summaries
+            .iter()
+            .filter_map(|(path, summary)| Some((path, summary.summary()?)))
+            .collect();
+        summaries.sort_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+        summaries
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.finished = true;
+        self.write_records()?;
+        if self.format == DiagnosticsExportFormat::ChromeTrace {
+            writeln!(self.writer, "\n]")?;
+        }
+        self.writer.flush()?;
+
+        let summaries = self.summaries();
+        for (path, summary) in &summaries {
+            info!(
+                target: "bevy_diagnostic",
+                "{path}: {} samples, min {:.6}, max {:.6}, mean {:.6}, p50 {:.6}, p90 {:.6}, p95 {:.6}, p99 {:.6}",
+                summary.samples,
+                summary.min,
+                summary.max,
+                summary.mean,
+                summary.p50,
+                summary.p90,
+                summary.p95,
+                summary.p99,
+            );
+        }
+
+        if let Some(summary_path) = &self.summary_path {
+            let summaries: Map<_, _> = summaries
+                .iter()
+                .map(|(path, summary)| {
+                    let summary = json!({
+                        "samples": summary.samples,
+                        "min": summary.min,
+                        "max": summary.max,
+                        "mean": summary.mean,
+                        "p50": summary.p50,
+                        "p90": summary.p90,
+                        "p95": summary.p95,
+                        "p99": summary.p99,
+                    });
+                    (path.as_str().into(), summary)
+                })
+                .collect();
+            let mut writer = BufWriter::new(File::create(summary_path)?);
+            serde_json::to_writer_pretty(&mut writer, &Value::Object(summaries))?;
+            writeln!(writer)?;
+            writer.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// The running aggregates of the values measured for a diagnostic.
+#[derive(Default)]
+struct SummaryAccumulator {
+    samples: u64,
+    sum: f64,
+    min: f64,
+    max: f64,
+    /// The last [`SUMMARY_PERCENTILE_SAMPLES`] values, for the percentiles.
+    recent: VecDeque<f64>,
+}
+
+impl SummaryAccumulator {
+    fn add(&mut self, value: f64) {
+        if self.samples == 0 {
+            self.min = value;
+            self.max = value;
+        }
+        self.samples += 1;
+        self.sum += value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        if self.recent.len() == SUMMARY_PERCENTILE_SAMPLES {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(value);
+    }
+
+    fn summary(&self) -> Option<DiagnosticSummary> {
+        if self.samples == 0 {
+            return None;
+        }
+        let mut sorted: Vec<f64> = self.recent.iter().copied().collect();
+        sorted.sort_by(f64::total_cmp);
+        let percentile = |percentile| nearest_rank_percentile(&sorted, percentile).unwrap_or(0.0);
+        Some(DiagnosticSummary {
+            samples: self.samples,
+            min: self.min,
+            max: self.max,
+            mean: self.sum / self.samples as f64,
+            p50: percentile(50.0),
+            p90: percentile(90.0),
+            p95: percentile(95.0),
+            p99: percentile(99.0),
+        })
+    }
+}
+
+/// Statistics over the values measured for a diagnostic.
+struct DiagnosticSummary {
+    samples: u64,
+    min: f64,
+    max: f64,
+    mean: f64,
+    p50: f64,
+    p90: f64,
+    p95: f64,
+    p99: f64,
+}
+
+fn export_diagnostics_system(
+    mut state: ResMut<ExportDiagnosticsState>,
+    diagnostics: Res<DiagnosticsStore>,
+    mut exit: EventReader<AppExit>,
+) {
+    if state.finished {
+        return;
+    }
+    state.collect(&diagnostics);
+    state.frame += 1;
+
+    let result = if !exit.is_empty() {
+        exit.clear();
+        state.finish()
+    } else if state
+        .frame
+        .is_multiple_of(u64::from(state.frames_per_record))
+    {
+        state.write_records()
+    } else {
+        Ok(())
+    };
+    if let Err(err) = result {
+        error!("Failed to write diagnostics: {err}");
+    }
+}
+
+/// Quotes a CSV field if needed.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Diagnostic, Diagnostics, DiagnosticsPlugin, RegisterDiagnostic};
+    use alloc::string::ToString;
+
+    const VALUE: DiagnosticPath = DiagnosticPath::const_new("test/value");
+
+    fn run(format: DiagnosticsExportFormat, name: &str) -> (String, String) {
+        // Include the process id, so concurrent runs of the tests don't share files.
+        let name = format!("bevy_diagnostic_{}_{name}", std::process::id());
+        let path = std::env::temp_dir().join(&name);
+        let summary_path = std::env::temp_dir().join(format!("{name}_summary"));
+
+        let mut app = App::new();
+        app.add_plugins(DiagnosticsPlugin)
+            .register_diagnostic(Diagnostic::new(VALUE))
+            .add_event::<AppExit>()
+            .add_plugins(
+                ExportDiagnosticsPlugin::new(&path, format)
+                    .with_frames_per_record(2)
+                    .with_summary(&summary_path),
+            )
+            .add_systems(
+                Update,
+                |mut diagnostics: Diagnostics, mut value: Local<u32>| {
+                    *value += 1;
+                    diagnostics.add_measurement(&VALUE, || f64::from(*value));
+                    // Measurements must be taken at different times to be exported.
+                    std::thread::sleep(core::time::Duration::from_millis(1));
+                },
+            );
+        for _ in 0..4 {
+            app.update();
+        }
+        app.world_mut().send_event(AppExit::Success);
+        app.update();
+
+        let export = std::fs::read_to_string(&path).unwrap();
+        let summary = std::fs::read_to_string(&summary_path).unwrap();
+        let _ = std::fs::remove_file(path);
+        let _ = std::fs::remove_file(summary_path);
+        (export, summary)
+    }
+
+    #[test]
+    fn export_csv() {
+        let (export, summary) = run(DiagnosticsExportFormat::Csv, "export_csv");
+        let rows: Vec<Vec<_>> = export
+            .lines()
+            .map(|line| line.split(',').map(ToString::to_string).collect())
+            .collect();
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[0][2], "diagnostic");
+        // Measurements are aggregated over two frames, and the last one is written at exit.
+        assert_eq!(rows[1][0], "2");
+        assert_eq!(rows[1][2..], ["test/value", "1.5", "1", "2", "2"]);
+        assert_eq!(rows[2][2..], ["test/value", "3.5", "3", "4", "2"]);
+        assert_eq!(rows[3][2..], ["test/value", "5", "5", "5", "1"]);
+
+        let summary: Value = serde_json::from_str(&summary).unwrap();
+        assert_eq!(
+            summary["test/value"],
+            json!({"samples": 5, "min": 1.0, "max": 5.0, "mean": 3.0, "p50": 3.0, "p90": 5.0, "p95": 5.0, "p99": 5.0})
+        );
+    }
+
+    #[test]
+    fn export_json() {
+        let (export, _) = run(DiagnosticsExportFormat::JsonLines, "export_json");
+        let records: Vec<Value> = export
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0]["frame"], 2);
+        assert_eq!(records[0]["diagnostic"], "test/value");
+        assert_eq!(records[0]["mean"], 1.5);
+
+        let (export, _) = run(DiagnosticsExportFormat::ChromeTrace, "export_trace");
+        let events: Vec<Value> = serde_json::from_str(&export).unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0]["name"], "test/value");
+        assert_eq!(events[0]["ph"], "C");
+    }
+}
@@ -28,6 +28,11 @@ impl Plugin for FrameCountPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<FrameCount>();
         app.add_systems(Last, update_frame_count);
+        #[cfg(feature = "std")]
+        app.add_crash_report_section("Frame count", |world| {
+            let frame_count = world.get_resource::<FrameCount>()?;
+            Some(alloc::format!("{}", frame_count.0))
+        });
     }
 }
 
@@ -0,0 +1,162 @@
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+
+/// Adds "frame pacing" diagnostics to an App, measuring how consistent frame times are over a
+/// window of recent frames: the median, 95th and 99th percentiles and maximum of the frame time,
+/// and the number of stutters, frames taking much longer than the median.
+///
+/// # See also
+///
+/// [`FrameTimeDiagnosticsPlugin`](crate::FrameTimeDiagnosticsPlugin) for the average frame
+/// time and fps.
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct FramePacingDiagnosticsPlugin {
+    /// The number of recent frames the statistics are computed over.
+    pub window: usize,
+    /// A frame is a stutter if its frame time exceeds the median by this factor.
+    pub stutter_threshold: f64,
+}
+
+impl Default for FramePacingDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            window: 300,
+            stutter_threshold: 2.0,
+        }
+    }
+}
+
+impl Plugin for FramePacingDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(
+            Diagnostic::new(Self::FRAME_TIME)
+                .with_suffix("ms")
+                .with_max_history_length(self.window)
+                .with_percentiles([50.0, 95.0, 99.0]),
+        );
+        // The statistics are already computed over a window, so they are not averaged.
+        for path in [
+            Self::FRAME_TIME_MEDIAN,
+            Self::FRAME_TIME_P95,
+            Self::FRAME_TIME_P99,
+            Self::FRAME_TIME_MAX,
+        ] {
+            app.register_diagnostic(
+                Diagnostic::new(path)
+                    .with_suffix("ms")
+                    .with_smoothing_factor(0.0)
+                    .with_max_history_length(0),
+            );
+        }
+        app.register_diagnostic(
+            Diagnostic::new(Self::STUTTERS)
+                .with_smoothing_factor(0.0)
+                .with_max_history_length(0),
+        )
+        .insert_resource(FramePacingSettings {
+            stutter_threshold: self.stutter_threshold,
+        })
+        .add_systems(
+            Update,
+            (Self::frame_time_system, Self::diagnostic_system).chain(),
+        );
+    }
+}
+
+/// The settings of the [`FramePacingDiagnosticsPlugin`].
+#[derive(Resource)]
+struct FramePacingSettings {
+    stutter_threshold: f64,
+}
+
+impl FramePacingDiagnosticsPlugin {
+    /// The frame time in ms, over the window of the plugin.
+    pub const FRAME_TIME: DiagnosticPath = DiagnosticPath::const_new("frame_pacing/frame_time");
+
+    /// The median frame time in ms over the window.
+    pub const FRAME_TIME_MEDIAN: DiagnosticPath =
+        DiagnosticPath::const_new("frame_pacing/frame_time_median");
+
+    /// The 95th percentile of the frame time in ms over the window.
+    pub const FRAME_TIME_P95: DiagnosticPath =
+        DiagnosticPath::const_new("frame_pacing/frame_time_p95");
+
+    /// The 99th percentile of the frame time in ms over the window.
+    pub const FRAME_TIME_P99: DiagnosticPath =
+        DiagnosticPath::const_new("frame_pacing/frame_time_p99");
+
+    /// The maximum frame time in ms over the window.
+    pub const FRAME_TIME_MAX: DiagnosticPath =
+        DiagnosticPath::const_new("frame_pacing/frame_time_max");
+
+    /// The number of frames over the window whose frame time exceeds the median by the stutter
+    /// threshold.
+    pub const STUTTERS: DiagnosticPath = DiagnosticPath::const_new("frame_pacing/stutters");
+
+    /// Measures the frame time.
+    fn frame_time_system(mut diagnostics: Diagnostics, time: Res<Time<Real>>) {
+        let delta_seconds = time.delta_secs_f64();
+        if delta_seconds == 0.0 {
+            return;
+        }
+
+        diagnostics.add_measurement(&Self::FRAME_TIME, || delta_seconds * 1000.0);
+    }
+
+    /// Updates the frame pacing measurements from the frame times of the window.
+    fn diagnostic_system(mut diagnostics: Diagnostics, settings: Res<FramePacingSettings>) {
+        let Some(frame_time) = diagnostics.get(&Self::FRAME_TIME) else {
+            return;
+        };
+        let Some([median, p95, p99]) = frame_time
+            .percentiles_of(&[50.0, 95.0, 99.0])
+            .and_then(|values| <[f64; 3]>::try_from(values).ok())
+        else {
+            return;
+        };
+        let max = frame_time.max().unwrap_or(median);
+        let stutters = frame_time.count_above(median * settings.stutter_threshold) as f64;
+
+        diagnostics.add_measurement(&Self::FRAME_TIME_MEDIAN, || median);
+        diagnostics.add_measurement(&Self::FRAME_TIME_P95, || p95);
+        diagnostics.add_measurement(&Self::FRAME_TIME_P99, || p99);
+        diagnostics.add_measurement(&Self::FRAME_TIME_MAX, || max);
+        diagnostics.add_measurement(&Self::STUTTERS, || stutters);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DiagnosticsPlugin, DiagnosticsStore};
+    use bevy_time::TimeUpdateStrategy;
+    use core::time::Duration;
+
+    #[test]
+    fn frame_pacing() {
+        let mut app = App::new();
+        app.add_plugins((
+            DiagnosticsPlugin,
+            bevy_time::TimePlugin,
+            FramePacingDiagnosticsPlugin::default(),
+        ));
+
+        for frame in 0..20 {
+            // One stutter every ten frames.
+            let frame_time = if frame % 10 == 9 { 50 } else { 10 };
+            app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                frame_time,
+            )));
+            app.update();
+        }
+
+        let diagnostics = app.world().resource::<DiagnosticsStore>();
+        let value = |path| diagnostics.get(&path).and_then(Diagnostic::value).unwrap();
+        assert!((value(FramePacingDiagnosticsPlugin::FRAME_TIME_MEDIAN) - 10.0).abs() < 1e-6);
+        assert!((value(FramePacingDiagnosticsPlugin::FRAME_TIME_P99) - 50.0).abs() < 1e-6);
+        assert!((value(FramePacingDiagnosticsPlugin::FRAME_TIME_MAX) - 50.0).abs() < 1e-6);
+        assert_eq!(value(FramePacingDiagnosticsPlugin::STUTTERS), 2.0);
+    }
+}
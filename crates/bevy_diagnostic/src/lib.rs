@@ -17,18 +17,31 @@ extern crate alloc;
 
 mod diagnostic;
 mod entity_count_diagnostics_plugin;
+#[cfg(feature = "std")]
+mod export_diagnostics_plugin;
 mod frame_count_diagnostics_plugin;
+mod frame_pacing_diagnostics_plugin;
 mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
+mod memory_usage_diagnostics_plugin;
 #[cfg(feature = "sysinfo_plugin")]
 mod system_information_diagnostics_plugin;
 
 pub use diagnostic::*;
 
 pub use entity_count_diagnostics_plugin::EntityCountDiagnosticsPlugin;
+#[cfg(feature = "std")]
+pub use export_diagnostics_plugin::{
+    DiagnosticsExportFormat, ExportDiagnosticsPlugin, SUMMARY_PERCENTILE_SAMPLES,
+};
 pub use frame_count_diagnostics_plugin::{update_frame_count, FrameCount, FrameCountPlugin};
+pub use frame_pacing_diagnostics_plugin::FramePacingDiagnosticsPlugin;
 pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
 pub use log_diagnostics_plugin::{LogDiagnosticsPlugin, LogDiagnosticsState};
+pub use memory_usage_diagnostics_plugin::{
+    MemoryUsageDiagnosticsPlugin, MemoryUsageEntry, MemoryUsageEstimators, MemoryUsageKind,
+    MemoryUsageReport, RegisterMemoryUsage,
+};
 #[cfg(feature = "sysinfo_plugin")]
 pub use system_information_diagnostics_plugin::{SystemInfo, SystemInformationDiagnosticsPlugin};
 
@@ -43,7 +56,21 @@ impl Plugin for DiagnosticsPlugin {
         app.init_resource::<DiagnosticsStore>();
 
         #[cfg(feature = "sysinfo_plugin")]
-        app.init_resource::<SystemInfo>();
+        {
+            app.init_resource::<SystemInfo>();
+            #[cfg(feature = "std")]
+            app.add_crash_report_section("System information", |world| {
+                let info = world.get_resource::<SystemInfo>()?;
+                Some(alloc::format!(
+                    "OS: {}\nKernel: {}\nCPU: {}\nCore count: {}\nMemory: {}",
+                    info.os,
+                    info.kernel,
+                    info.cpu,
+                    info.core_count,
+                    info.memory
+                ))
+            });
+        }
     }
 }
 
@@ -1,10 +1,11 @@
 use super::{Diagnostic, DiagnosticPath, DiagnosticsStore};
 
+use alloc::string::String;
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_platform::collections::HashSet;
 use bevy_time::{Real, Time, Timer, TimerMode};
-use core::time::Duration;
+use core::{fmt::Write, time::Duration};
 use log::{debug, info};
 
 /// An App Plugin that logs diagnostics to the console.
@@ -159,9 +160,10 @@ impl LogDiagnosticsPlugin {
                 // so we reserve two columns for it; however,
                 // Do not reserve columns for the suffix in the average
                 // The ) hugging the value is more aesthetically pleasing
-                "{path:<path_width$}: {value:>11.6}{suffix:2} (avg {average:>.6}{suffix:})",
+                "{path:<path_width$}: {value:>11.6}{suffix:2} (avg {average:>.6}{suffix:}{percentiles})",
                 path = diagnostic.path(),
                 suffix = diagnostic.suffix,
+                percentiles = Self::format_percentiles(diagnostic),
             );
         } else {
             info!(
@@ -173,6 +175,32 @@ impl LogDiagnosticsPlugin {
         }
     }
 
+    /// Formats the percentiles configured for the diagnostic, and its maximum, if there are any.
+    fn format_percentiles(diagnostic: &Diagnostic) -> String {
+        let mut formatted = String::new();
+        let Some(values) = diagnostic.percentiles_of(diagnostic.percentiles()) else {
+            return formatted;
+        };
+        if values.is_empty() {
+            return formatted;
+        }
+        for (percentile, value) in diagnostic.percentiles().iter().zip(values) {
+            let _ = write!(
+                formatted,
+                ", p{percentile} {value:.6}{suffix}",
+                suffix = diagnostic.suffix
+            );
+        }
+        if let Some(max) = diagnostic.max() {
+            let _ = write!(
+                formatted,
+                ", max {max:.6}{suffix}",
+                suffix = diagnostic.suffix
+            );
+        }
+        formatted
+    }
+
     fn log_diagnostics(state: &LogDiagnosticsState, diagnostics: &DiagnosticsStore) {
         let mut path_width = 0;
         Self::for_each_diagnostic(state, diagnostics, |diagnostic| {
@@ -0,0 +1,354 @@
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use core::any::TypeId;
+
+use bevy_app::{prelude::*, SubApp};
+use bevy_ecs::{
+    component::{ComponentId, ComponentInfo},
+    prelude::*,
+};
+use bevy_platform::collections::HashMap;
+use log::info;
+
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+
+/// Adds "memory usage" diagnostics to an App, measuring the bytes allocated by the ECS storages
+/// for the components in tables and sparse sets, and for the resources.
+///
+/// Use a [`MemoryUsageReport`] to find out which components and resources use the memory.
+///
+/// The memory owned by the components and resources themselves, such as the contents of a `Vec`,
+/// is only counted for the resources registered with [`RegisterMemoryUsage`], like the
+/// `Assets<A>` collections when the `memory_usage` feature of `bevy_asset` is enabled.
+///
+/// # See also
+///
+/// [`EntityCountDiagnosticsPlugin`](crate::EntityCountDiagnosticsPlugin) for the number of
+/// entities.
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct MemoryUsageDiagnosticsPlugin;
+
+impl Plugin for MemoryUsageDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        for path in [
+            Self::TOTAL,
+            Self::TABLES,
+            Self::SPARSE_SETS,
+            Self::RESOURCES,
+        ] {
+            app.register_diagnostic(Diagnostic::new(path).with_suffix("KiB"));
+        }
+        app.init_resource::<MemoryUsageEstimators>()
+            .add_systems(Update, Self::diagnostic_system);
+    }
+}
+
+impl MemoryUsageDiagnosticsPlugin {
+    /// The KiB allocated for the components and resources.
+    pub const TOTAL: DiagnosticPath = DiagnosticPath::const_new("memory/total");
+
+    /// The KiB allocated for the components stored in tables.
+    pub const TABLES: DiagnosticPath = DiagnosticPath::const_new("memory/tables");
+
+    /// The KiB allocated for the components stored in sparse sets.
+    pub const SPARSE_SETS: DiagnosticPath = DiagnosticPath::const_new("memory/sparse_sets");
+
+    /// The KiB allocated for the resources.
+    pub const RESOURCES: DiagnosticPath = DiagnosticPath::const_new("memory/resources");
+
+    /// Updates the memory usage measurements.
+    ///
+    /// Only the totals are computed, without the names and sorting of a [`MemoryUsageReport`].
+    pub fn diagnostic_system(mut diagnostics: Diagnostics, world: &World) {
+        let (mut tables, mut sparse_sets, mut resources) = (0, 0, 0);
+        for_each_memory_usage(world, |_, kind, bytes| match kind {
+            MemoryUsageKind::Table => tables += bytes,
+            MemoryUsageKind::SparseSet => sparse_sets += bytes,
+            MemoryUsageKind::Resource => resources += bytes,
+        });
+        let kib = |bytes: usize| bytes as f64 / 1024.0;
+        diagnostics.add_measurement(&Self::TABLES, || kib(tables));
+        diagnostics.add_measurement(&Self::SPARSE_SETS, || kib(sparse_sets));
+        diagnostics.add_measurement(&Self::RESOURCES, || kib(resources));
+        diagnostics.add_measurement(&Self::TOTAL, || kib(tables + sparse_sets + resources));
+    }
+}
+
+/// Calls `f` with the bytes allocated for each table column, sparse set and resource of the
+/// `world`, including the estimates registered in the [`MemoryUsageEstimators`], if any.
+///
+/// A component stored in several tables is passed once per table.
+fn for_each_memory_usage(world: &World, mut f: impl FnMut(ComponentId, MemoryUsageKind, usize)) {
+    let components = world.components();
+    let storages = world.storages();
+    let estimators = world.get_resource::<MemoryUsageEstimators>();
+
+    for table in storages.tables.iter() {
+        for id in table.component_ids() {
+            let bytes = table.get_column_allocated_bytes(id).unwrap_or_default();
+            f(id, MemoryUsageKind::Table, bytes);
+        }
+    }
+    for (id, set) in storages.sparse_sets.iter() {
+        f(id, MemoryUsageKind::SparseSet, set.allocated_bytes());
+    }
+    let resources = storages
+        .resources
+        .iter()
+        .map(|(id, data)| (id, data.allocated_bytes()))
+        .chain(
+            storages
+                .non_send_resources
+                .iter()
+                .map(|(id, data)| (id, data.allocated_bytes())),
+        );
+    for (id, bytes) in resources {
+        let estimate = components
+            .get_info(id)
+            .and_then(ComponentInfo::type_id)
+            .zip(estimators)
+            .and_then(|(type_id, estimators)| estimators.estimators.get(&type_id))
+            .map(|estimator| estimator(world))
+            .unwrap_or_default();
+        f(id, MemoryUsageKind::Resource, bytes + estimate);
+    }
+}
+
+/// Where the memory of a [`MemoryUsageEntry`] is allocated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MemoryUsageKind {
+    /// The columns of a component in all the tables storing it.
+    Table,
+    /// The sparse set of a component.
+    SparseSet,
+    /// A resource, `Send` or not.
+    Resource,
+}
+
+/// The memory used by a component or a resource, in a [`MemoryUsageReport`].
+#[derive(Debug, Clone)]
+pub struct MemoryUsageEntry {
+    /// The component or resource.
+    pub id: ComponentId,
+    /// The name of the component or resource.
+    pub name: String,
+    /// Where the memory is allocated.
+    pub kind: MemoryUsageKind,
+    /// The number of bytes allocated.
+    pub bytes: usize,
+}
+
+/// The memory used by each component and resource of a [`World`], from the largest to the
+/// smallest.
+///
+/// ```
+/// # use bevy_ecs::world::World;
+/// # use bevy_diagnostic::MemoryUsageReport;
+/// fn log_memory_usage(world: &World) {
+///     MemoryUsageReport::new(world).log_top(20);
+/// }
+/// # log_memory_usage(&World::new());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MemoryUsageReport {
+    entries: Vec<MemoryUsageEntry>,
+}
+
+impl MemoryUsageReport {
+    /// Measures the memory used by the components and resources of the `world`.
+    ///
+    /// This includes the estimates registered in the [`MemoryUsageEstimators`], if any.
+    pub fn new(world: &World) -> Self {
+        let components = world.components();
+        let mut bytes = HashMap::<(ComponentId, MemoryUsageKind), usize>::default();
+        for_each_memory_usage(world, |id, kind, allocated| {
+            *bytes.entry((id, kind)).or_default() += allocated;
+        });
+
+        let mut entries: Vec<_> = bytes
+            .into_iter()
+            .map(|((id, kind), bytes)| MemoryUsageEntry {
+                id,
+                name: components
+                    .get_info(id)
+                    .map(|info| format!("{}", info.name()))
+                    .unwrap_or_default(),
+                kind,
+                bytes,
+            })
+            .collect();
+        entries.sort_by_key(|entry| core::cmp::Reverse(entry.bytes));
+        Self { entries }
+    }
+
+    /// Returns all the entries, from the largest to the smallest.
+    pub fn entries(&self) -> &[MemoryUsageEntry] {
+        &self.entries
+    }
+
+    /// Returns the `count` largest entries.
+    pub fn top(&self, count: usize) -> &[MemoryUsageEntry] {
+        &self.entries[..count.min(self.entries.len())]
+    }
+
+    /// Returns the number of bytes allocated for all the entries.
+    pub fn total_bytes(&self) -> usize {
+        self.entries.iter().map(|entry| entry.bytes).sum()
+    }
+
+    /// Returns the number of bytes allocated for the entries of the given kind.
+    pub fn bytes_of(&self, kind: MemoryUsageKind) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.kind == kind)
+            .map(|entry| entry.bytes)
+            .sum()
+    }
+
+    /// Logs the `count` largest entries.
+    pub fn log_top(&self, count: usize) {
+        info!(
+            "Top {} memory users, of {} allocated:",
+            count.min(self.entries.len()),
+            format_bytes(self.total_bytes())
+        );
+        for entry in self.top(count) {
+            info!(
+                "{:>12} {:<10} {}",
+                format_bytes(entry.bytes),
+                format!("{:?}", entry.kind),
+                entry.name
+            );
+        }
+    }
+}
+
+/// Formats a number of bytes with a binary prefix.
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+/// Estimates of the memory owned by resources, added to the bytes allocated for them in a
+/// [`MemoryUsageReport`].
+///
+/// Use [`RegisterMemoryUsage`] to add an estimate.
+#[derive(Resource, Default)]
+pub struct MemoryUsageEstimators {
+    estimators: HashMap<TypeId, Box<dyn Fn(&World) -> usize + Send + Sync>>,
+}
+
+impl MemoryUsageEstimators {
+    /// Adds an estimate of the bytes owned by the resource `R`, replacing the previous one.
+    pub fn register<R: Resource>(&mut self, estimate: fn(&R) -> usize) {
+        self.estimators.insert(
+            TypeId::of::<R>(),
+            Box::new(move |world| world.get_resource::<R>().map_or(0, estimate)),
+        );
+    }
+}
+
+/// Extend [`App`] with a `register_memory_usage` function.
+pub trait RegisterMemoryUsage {
+    /// Registers an estimate of the bytes owned by the resource `R`, such as the contents of its
+    /// collections, in the [`MemoryUsageEstimators`].
+    ///
+    /// Will initialize the [`MemoryUsageEstimators`] if they don't exist.
+    fn register_memory_usage<R: Resource>(&mut self, estimate: fn(&R) -> usize) -> &mut Self;
+}
+
+impl RegisterMemoryUsage for SubApp {
+    fn register_memory_usage<R: Resource>(&mut self, estimate: fn(&R) -> usize) -> &mut Self {
+        self.init_resource::<MemoryUsageEstimators>();
+        self.world_mut()
+            .resource_mut::<MemoryUsageEstimators>()
+            .register(estimate);
+        self
+    }
+}
+
+impl RegisterMemoryUsage for App {
+    fn register_memory_usage<R: Resource>(&mut self, estimate: fn(&R) -> usize) -> &mut Self {
+        SubApp::register_memory_usage(self.main_mut(), estimate);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DiagnosticsPlugin, DiagnosticsStore};
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component)]
+    #[expect(dead_code, reason = "Only the size of the component matters.")]
+    struct Position([f32; 4]);
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    #[expect(dead_code, reason = "Only the size of the component matters.")]
+    struct Marker(u64);
+
+    #[derive(Resource)]
+    struct Buffer(Vec<u8>);
+
+    #[test]
+    fn memory_usage() {
+        let mut app = App::new();
+        app.add_plugins((DiagnosticsPlugin, MemoryUsageDiagnosticsPlugin))
+            .insert_resource(Buffer(vec![0; 4096]))
+            .register_memory_usage::<Buffer>(|buffer| buffer.0.capacity());
+        for _ in 0..100 {
+            app.world_mut().spawn((Position([0.0; 4]), Marker(0)));
+        }
+        app.update();
+
+        let report = MemoryUsageReport::new(app.world());
+        let entry = |kind| {
+            report
+                .entries()
+                .iter()
+                .find(|entry| entry.kind == kind && entry.bytes > 0)
+                .unwrap()
+        };
+        // The components themselves, without the change detection ticks.
+        assert!(entry(MemoryUsageKind::Table).bytes >= 100 * size_of::<Position>());
+        assert!(entry(MemoryUsageKind::SparseSet).bytes >= 100 * size_of::<Marker>());
+        // The buffer is the largest user, because of its estimate.
+        assert_eq!(report.top(1)[0].kind, MemoryUsageKind::Resource);
+        assert!(report.top(1)[0].bytes >= 4096);
+        assert!(report
+            .entries()
+            .windows(2)
+            .all(|pair| pair[0].bytes >= pair[1].bytes));
+
+        // The measurements sum the same storages as the report.
+        let diagnostics = app.world().resource::<DiagnosticsStore>();
+        let kib =
+            |path: &DiagnosticPath| diagnostics.get(path).and_then(Diagnostic::value).unwrap();
+        assert!(kib(&MemoryUsageDiagnosticsPlugin::TOTAL) >= 4.0);
+        for (path, kind) in [
+            (MemoryUsageDiagnosticsPlugin::TABLES, MemoryUsageKind::Table),
+            (
+                MemoryUsageDiagnosticsPlugin::SPARSE_SETS,
+                MemoryUsageKind::SparseSet,
+            ),
+            (
+                MemoryUsageDiagnosticsPlugin::RESOURCES,
+                MemoryUsageKind::Resource,
+            ),
+        ] {
+            assert_eq!(kib(&path), report.bytes_of(kind) as f64 / 1024.0);
+        }
+    }
+}
@@ -21,7 +21,7 @@ use bevy_platform::{
     collections::{HashMap, HashSet},
     sync::PoisonError,
 };
-use bevy_ptr::{OwningPtr, UnsafeCellDeref};
+use bevy_ptr::{OwningPtr, Ptr, UnsafeCellDeref};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
 use bevy_utils::{prelude::DebugName, TypeIdMap};
@@ -2060,10 +2060,10 @@ impl Components {
                     // SAFETY: Component ID and constructor match the ones on the original requiree.
                     //         The original requiree is responsible for making sure the registration is safe.
                     unsafe {
-                        required_components.register_dynamic_with(
+                        required_components.register_required(
                             *component_id,
                             component.inheritance_depth + depth + 1,
-                            || component.constructor.clone(),
+                            || component.clone(),
                         );
                     };
                 }
@@ -2096,10 +2096,10 @@ impl Components {
                 (
                     *component_id,
                     RequiredComponent {
-                        constructor: required_component.constructor.clone(),
                         // Add `1` to the inheritance depth since this will be registered
                         // for the component that requires `required`.
                         inheritance_depth: required_component.inheritance_depth + 1,
+                        ..required_component.clone()
                     },
                 )
             })
@@ -2110,10 +2110,10 @@ impl Components {
             // Register the required component for the requiree.
             // SAFETY: Component ID and constructor match the ones on the original requiree.
             unsafe {
-                required_components.register_dynamic_with(
+                required_components.register_required(
                     *component_id,
                     component.inheritance_depth,
-                    || component.constructor.clone(),
+                    || component.clone(),
                 );
             };
 
@@ -2656,12 +2656,26 @@ impl RequiredComponentConstructor {
     }
 }
 
+/// Constructs the value of a required component outside of a [`World`], and passes a pointer to it to
+/// the given function. See [`RequiredComponent::with_value`].
+#[derive(Clone)]
+struct RequiredComponentValue {
+    /// The type of the constructed value.
+    #[cfg(feature = "bevy_reflect")]
+    type_id: TypeId,
+    construct: Arc<dyn Fn(&mut dyn FnMut(Ptr<'_>))>,
+}
+
 /// Metadata associated with a required component. See [`Component`] for details.
 #[derive(Clone)]
 pub struct RequiredComponent {
     /// The constructor used for the required component.
     pub constructor: RequiredComponentConstructor,
 
+    /// Constructs the value of the required component without writing it to a [`World`]. This is
+    /// `None` for components registered with [`RequiredComponents::register_dynamic_with`].
+    value: Option<RequiredComponentValue>,
+
     /// The depth of the component requirement in the requirement hierarchy for this component.
     /// This is used for determining which constructor is used in cases where there are duplicate requires.
     ///
@@ -2675,6 +2689,46 @@ pub struct RequiredComponent {
     pub inheritance_depth: u16,
 }
 
+impl RequiredComponent {
+    /// Constructs the value of the required component, like when it is inserted by a component
+    /// requiring it, and passes a pointer to it to `f`. The value is dropped once `f` returns.
+    ///
+    /// Unlike inserting the component, this doesn't need a [`World`] and doesn't run any hooks or
+    /// observers. Returns `false` without calling `f` if the component was registered with
+    /// [`RequiredComponents::register_dynamic_with`], whose constructor can only write to a world.
+    pub fn with_value(&self, f: impl FnOnce(Ptr<'_>)) -> bool {
+        let Some(value) = &self.value else {
+            return false;
+        };
+        let mut f = Some(f);
+        (value.construct)(&mut |ptr| {
+            if let Some(f) = f.take() {
+                f(ptr);
+            }
+        });
+        true
+    }
+
+    /// Like [`with_value`](Self::with_value), but passes the value to `f` as a [`Reflect`] value.
+    ///
+    /// Returns `false` without calling `f` if `reflect_from_ptr` wasn't registered for the type of
+    /// the required component.
+    #[cfg(feature = "bevy_reflect")]
+    pub fn with_reflect_value(
+        &self,
+        reflect_from_ptr: &bevy_reflect::ReflectFromPtr,
+        f: impl FnOnce(&dyn Reflect),
+    ) -> bool {
+        if self.value.as_ref().map(|value| value.type_id) != Some(reflect_from_ptr.type_id()) {
+            return false;
+        }
+        self.with_value(|ptr| {
+            // SAFETY: The value has the type `reflect_from_ptr` was registered for, checked above.
+            f(unsafe { reflect_from_ptr.as_reflect(ptr) });
+        })
+    }
+}
+
 /// The collection of metadata for components that are required for a given component.
 ///
 /// For more information, see the "Required Components" section of [`Component`].
@@ -2706,6 +2760,31 @@ impl RequiredComponents {
         component_id: ComponentId,
         inheritance_depth: u16,
         constructor: impl FnOnce() -> RequiredComponentConstructor,
+    ) {
+        // SAFETY: The caller ensures that `constructor` initializes a component for `component_id`.
+        unsafe {
+            self.register_required(component_id, inheritance_depth, || RequiredComponent {
+                constructor: constructor(),
+                value: None,
+                inheritance_depth,
+            });
+        }
+    }
+
+    /// Registers a required component, with the same overwriting rules as
+    /// [`register_dynamic_with`](Self::register_dynamic_with). The inheritance depth of the
+    /// registered component is set to `inheritance_depth`.
+    ///
+    /// # Safety
+    ///
+    /// The constructor of the returned [`RequiredComponent`] must follow the rules of
+    /// [`register_dynamic_with`](Self::register_dynamic_with), and its value must have the type of
+    /// `component_id`.
+    unsafe fn register_required(
+        &mut self,
+        component_id: ComponentId,
+        inheritance_depth: u16,
+        required: impl FnOnce() -> RequiredComponent,
     ) {
         let entry = self.0.entry(component_id);
         match entry {
@@ -2713,15 +2792,15 @@ impl RequiredComponents {
                 let current = occupied.get_mut();
                 if current.inheritance_depth > inheritance_depth {
                     *current = RequiredComponent {
-                        constructor: constructor(),
                         inheritance_depth,
+                        ..required()
                     }
                 }
             }
             bevy_platform::collections::hash_map::Entry::Vacant(vacant) => {
                 vacant.insert(RequiredComponent {
-                    constructor: constructor(),
                     inheritance_depth,
+                    ..required()
                 });
             }
         }
@@ -2804,13 +2883,47 @@ impl RequiredComponents {
                 Arc::from(boxed)
             })
         };
+        let value = || -> Arc<dyn Fn(&mut dyn FnMut(Ptr<'_>))> {
+            #[cfg(not(target_has_atomic = "ptr"))]
+            use alloc::boxed::Box;
+
+            #[cfg(not(target_has_atomic = "ptr"))]
+            type Intermediate<T> = Box<T>;
+
+            #[cfg(target_has_atomic = "ptr")]
+            type Intermediate<T> = Arc<T>;
+
+            let boxed: Intermediate<dyn Fn(&mut dyn FnMut(Ptr<'_>))> =
+                Intermediate::new(move |f: &mut dyn FnMut(Ptr<'_>)| {
+                    let value = constructor();
+                    f(Ptr::from(&value));
+                });
+            Arc::from(boxed)
+        };
 
         // SAFETY:
-        // `component_id` matches the type initialized by the `erased` constructor above.
+        // `component_id` matches the type initialized by the `erased` constructor above, and the type
+        // of the value of `value`.
         // `erased` initializes a component for `component_id` in such a way that
         // matches the storage type of the component. It only uses the given `table_row` or `Entity` to
         // initialize the storage corresponding to the given entity.
-        unsafe { self.register_dynamic_with(component_id, inheritance_depth, erased) };
+        unsafe {
+            self.register_required(component_id, inheritance_depth, || RequiredComponent {
+                constructor: erased(),
+                value: Some(RequiredComponentValue {
+                    #[cfg(feature = "bevy_reflect")]
+                    type_id: TypeId::of::<C>(),
+                    construct: value(),
+                }),
+                inheritance_depth,
+            });
+        };
+    }
+
+    /// Returns the [`RequiredComponent`] registered for `component_id`, if it is required. This
+    /// includes recursive required components.
+    pub fn get(&self, component_id: ComponentId) -> Option<&RequiredComponent> {
+        self.0.get(&component_id)
     }
 
     /// Iterates the ids of all required components. This includes recursive required components.
@@ -2834,18 +2947,11 @@ impl RequiredComponents {
     ///
     /// See [`register_dynamic_with`](Self::register_dynamic_with) for details.
     pub(crate) fn merge(&mut self, required_components: &RequiredComponents) {
-        for (
-            component_id,
-            RequiredComponent {
-                constructor,
-                inheritance_depth,
-            },
-        ) in required_components.0.iter()
-        {
+        for (component_id, required_component) in required_components.0.iter() {
             // SAFETY: This exact registration must have been done on `required_components`, so safety is ensured by that caller.
             unsafe {
-                self.register_dynamic_with(*component_id, *inheritance_depth, || {
-                    constructor.clone()
+                self.register_required(*component_id, required_component.inheritance_depth, || {
+                    required_component.clone()
                 });
             }
         }
@@ -1902,6 +1902,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn required_component_values() {
+        #[derive(Component)]
+        #[require(Y)]
+        struct X;
+
+        #[derive(Component, Default)]
+        #[require(Z = Z(7))]
+        struct Y;
+
+        #[derive(Component)]
+        struct Z(u32);
+
+        let mut world = World::new();
+        let x = world.register_component::<X>();
+        let z = world.register_component::<Z>();
+        let required = world
+            .components()
+            .get_info(x)
+            .unwrap()
+            .required_components()
+            .get(z)
+            .unwrap();
+        let mut value = None;
+        // The value of an inherited requirement is constructed without a world.
+        assert!(required.with_value(|ptr| {
+            // SAFETY: The value constructed for `Z` is a `Z`.
+            value = Some(unsafe { ptr.deref::<Z>() }.0);
+        }));
+        assert_eq!(value, Some(7));
+        assert_eq!(world.entities().len(), 0);
+    }
+
     #[test]
     fn generic_required_components() {
         #[derive(Component)]
@@ -1,5 +1,7 @@
 #[cfg(feature = "std")]
 mod multi_threaded;
+#[cfg(feature = "std")]
+mod panic_context;
 mod simple;
 mod single_threaded;
 
@@ -13,6 +15,9 @@ pub use self::{simple::SimpleExecutor, single_threaded::SingleThreadedExecutor};
 #[cfg(feature = "std")]
 pub use self::multi_threaded::{MainThreadExecutor, MultiThreadedExecutor};
 
+#[cfg(feature = "std")]
+pub use self::panic_context::PanicContext;
+
 use fixedbitset::FixedBitSet;
 
 use crate::{
@@ -2,6 +2,7 @@ use alloc::{boxed::Box, vec::Vec};
 use bevy_platform::cell::SyncUnsafeCell;
 use bevy_platform::sync::Arc;
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
+use bevy_utils::prelude::DebugName;
 use concurrent_queue::ConcurrentQueue;
 use core::{any::Any, panic::AssertUnwindSafe};
 use fixedbitset::FixedBitSet;
@@ -16,8 +17,8 @@ use crate::{
     error::{ErrorContext, ErrorHandler, Result},
     prelude::Resource,
     schedule::{
-        is_apply_deferred, ConditionWithAccess, ExecutorKind, SystemExecutor, SystemSchedule,
-        SystemWithAccess,
+        is_apply_deferred, ConditionWithAccess, ExecutorKind, PanicContext, SystemExecutor,
+        SystemSchedule, SystemWithAccess,
     },
     system::{RunSystemError, ScheduleSystem},
     world::{unsafe_world_cell::UnsafeWorldCell, World},
@@ -95,6 +96,8 @@ pub struct MultiThreadedExecutor {
     apply_final_deferred: bool,
     /// When set, tells the executor that a thread has panicked.
     panic_payload: Mutex<Option<Box<dyn Any + Send>>>,
+    /// The system that panicked, recorded in the [`PanicContext`] of the executor's thread.
+    panicked_system: Mutex<Option<DebugName>>,
     starting_systems: FixedBitSet,
     /// Cached tracing span
     #[cfg(feature = "trace")]
@@ -310,6 +313,9 @@ impl SystemExecutor for MultiThreadedExecutor {
         // check to see if there was a panic
         let payload = self.panic_payload.get_mut().unwrap();
         if let Some(payload) = payload.take() {
+            if let Some(name) = self.panicked_system.get_mut().unwrap().take() {
+                PanicContext::record_system(&name);
+            }
             std::panic::resume_unwind(payload);
         }
 
@@ -348,6 +354,7 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
             {
                 let mut panic_payload = self.environment.executor.panic_payload.lock().unwrap();
                 *panic_payload = Some(payload);
+                *self.environment.executor.panicked_system.lock().unwrap() = Some(system.name());
             }
         }
         self.tick_executor();
@@ -396,6 +403,7 @@ impl MultiThreadedExecutor {
             starting_systems: FixedBitSet::new(),
             apply_final_deferred: true,
             panic_payload: Mutex::new(None),
+            panicked_system: Mutex::new(None),
             #[cfg(feature = "trace")]
             executor_span: info_span!("multithreaded executor"),
         }
@@ -810,6 +818,7 @@ fn apply_deferred(
                     system.name()
                 );
             }
+            PanicContext::record_system(&system.name());
             return Err(payload);
         }
     }
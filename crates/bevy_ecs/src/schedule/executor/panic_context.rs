@@ -0,0 +1,62 @@
+use alloc::{format, string::String};
+use bevy_utils::prelude::DebugName;
+use core::cell::RefCell;
+
+use crate::schedule::InternedScheduleLabel;
+
+std::thread_local! {
+    static PANIC_CONTEXT: RefCell<PanicContext> = const { RefCell::new(PanicContext::new()) };
+}
+
+/// The schedule and system that were running when a panic unwound through a schedule run on
+/// this thread.
+///
+/// The executors record them while the panic unwinds, so that they can be reported once it is
+/// caught, for example in a crash report. Only the innermost schedule and the first system are
+/// recorded, until the context is [taken](PanicContext::take).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PanicContext {
+    /// The label of the innermost schedule that was running.
+    pub schedule: Option<String>,
+    /// The name of the system that panicked.
+    pub system: Option<String>,
+}
+
+impl PanicContext {
+    const fn new() -> Self {
+        Self {
+            schedule: None,
+            system: None,
+        }
+    }
+
+    /// Returns the context recorded on this thread since it was last taken, and clears it.
+    pub fn take() -> Self {
+        PANIC_CONTEXT.with_borrow_mut(core::mem::take)
+    }
+
+    /// Clears the context recorded on this thread.
+    ///
+    /// This is done before running the schedules again, so that a context recorded for a panic
+    /// that was caught and handled isn't reported for a later one.
+    pub fn clear() {
+        PANIC_CONTEXT.with_borrow_mut(|context| *context = Self::new());
+    }
+
+    /// Returns `true` if nothing was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.schedule.is_none() && self.system.is_none()
+    }
+
+    pub(crate) fn record_system(name: &DebugName) {
+        PANIC_CONTEXT.with_borrow_mut(|context| {
+            context.system.get_or_insert_with(|| format!("{name}"));
+        });
+    }
+
+    pub(crate) fn record_schedule(label: InternedScheduleLabel) {
+        PANIC_CONTEXT.with_borrow_mut(|context| {
+            context.schedule.get_or_insert_with(|| format!("{label:?}"));
+        });
+    }
+}
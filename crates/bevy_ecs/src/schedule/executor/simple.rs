@@ -147,6 +147,7 @@ impl SystemExecutor for SimpleExecutor {
             {
                 if let Err(payload) = std::panic::catch_unwind(f) {
                     eprintln!("Encountered a panic in system `{}`!", system.name());
+                    super::PanicContext::record_system(&system.name());
                     std::panic::resume_unwind(payload);
                 }
             }
@@ -149,6 +149,7 @@ impl SystemExecutor for SingleThreadedExecutor {
             {
                 if let Err(payload) = std::panic::catch_unwind(f) {
                     eprintln!("Encountered a panic in system `{}`!", system.name());
+                    super::PanicContext::record_system(&system.name());
                     std::panic::resume_unwind(payload);
                 }
             }
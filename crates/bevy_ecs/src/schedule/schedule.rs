@@ -15,6 +15,7 @@ use bevy_utils::{default, prelude::DebugName, TypeIdMap};
 use core::{
     any::{Any, TypeId},
     fmt::{Debug, Write},
+    panic::AssertUnwindSafe,
 };
 use fixedbitset::FixedBitSet;
 use log::{error, info, warn};
@@ -496,23 +497,33 @@ impl Schedule {
         let error_handler = world.default_error_handler();
 
         #[cfg(not(feature = "bevy_debug_stepping"))]
-        self.executor
-            .run(&mut self.executable, world, None, error_handler);
+        let skip_systems: Option<FixedBitSet> = None;
 
         #[cfg(feature = "bevy_debug_stepping")]
-        {
-            let skip_systems = match world.get_resource_mut::<Stepping>() {
-                None => None,
-                Some(mut stepping) => stepping.skipped_systems(self),
-            };
+        let skip_systems = match world.get_resource_mut::<Stepping>() {
+            None => None,
+            Some(mut stepping) => stepping.skipped_systems(self),
+        };
 
+        let f = AssertUnwindSafe(|| {
             self.executor.run(
                 &mut self.executable,
                 world,
                 skip_systems.as_ref(),
                 error_handler,
             );
+        });
+
+        // The panic hook already ran, and `resume_unwind` propagates the same payload without
+        // calling it again. With `panic = "abort"`, nothing is caught.
+        #[cfg(feature = "std")]
+        if let Err(payload) = std::panic::catch_unwind(f) {
+            PanicContext::record_schedule(self.label);
+            std::panic::resume_unwind(payload);
         }
+
+        #[cfg(not(feature = "std"))]
+        (f)();
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -94,6 +94,13 @@ impl BlobVec {
         self.item_layout
     }
 
+    /// Returns the number of bytes allocated for the elements of the vector.
+    #[inline]
+    pub fn allocated_bytes(&self) -> usize {
+        // Zero-sized types never allocate, even though their capacity is `usize::MAX`.
+        self.item_layout.pad_to_align().size() * self.capacity
+    }
+
     /// Reserves the minimum capacity for at least `additional` more elements to be inserted in the given `BlobVec`.
     /// After calling `reserve_exact`, capacity will be greater than or equal to `self.len() + additional`. Does nothing if
     /// the capacity is already sufficient.
@@ -89,6 +89,15 @@ impl<const SEND: bool> ResourceData<SEND> {
         !self.data.is_empty()
     }
 
+    /// Returns the number of bytes allocated for the resource.
+    ///
+    /// This doesn't include the memory owned by the resource itself, such as the contents of a
+    /// `Vec`.
+    #[inline]
+    pub fn allocated_bytes(&self) -> usize {
+        self.data.allocated_bytes()
+    }
+
     /// Returns a reference to the resource, if it exists.
     ///
     /// # Panics
@@ -155,6 +155,15 @@ impl ComponentSparseSet {
         self.dense.len() == 0
     }
 
+    /// Returns the number of bytes allocated for the component values, their change detection
+    /// ticks and the entity lookup of this sparse set.
+    pub fn allocated_bytes(&self) -> usize {
+        fn vec_bytes<T>(vec: &Vec<T>) -> usize {
+            vec.capacity() * size_of::<T>()
+        }
+        self.dense.allocated_bytes() + vec_bytes(&self.entities) + vec_bytes(&self.sparse.values)
+    }
+
     /// Inserts the `entity` key and component `value` pair into this sparse
     /// set.
     ///
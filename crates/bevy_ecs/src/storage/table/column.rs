@@ -326,6 +326,18 @@ impl ThinColumn {
             .as_ref()
             .map(|changed_by| changed_by.as_slice(len))
     }
+
+    /// Returns the number of bytes allocated for the components and change detection ticks of
+    /// this column, given the `capacity` of its [`Table`].
+    pub fn allocated_bytes(&self, capacity: usize) -> usize {
+        let location_size = self
+            .changed_by
+            .as_ref()
+            .map(|_| size_of::<&'static Location<'static>>())
+            .unwrap_or_default();
+        (self.data.layout().pad_to_align().size() + 2 * size_of::<Tick>() + location_size)
+            * capacity
+    }
 }
 
 /// A type-erased contiguous container for data of a homogeneous type.
@@ -695,4 +707,17 @@ impl Column {
     pub fn get_drop(&self) -> Option<unsafe fn(OwningPtr<'_>)> {
         self.data.get_drop()
     }
+
+    /// Returns the number of bytes allocated for the components and change detection ticks of
+    /// this column.
+    pub fn allocated_bytes(&self) -> usize {
+        let changed_by_bytes = self
+            .changed_by
+            .as_ref()
+            .map(|changed_by| changed_by.capacity() * size_of::<&'static Location<'static>>())
+            .unwrap_or_default();
+        self.data.allocated_bytes()
+            + (self.added_ticks.capacity() + self.changed_ticks.capacity()) * size_of::<Tick>()
+            + changed_by_bytes
+    }
 }
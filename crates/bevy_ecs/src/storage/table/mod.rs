@@ -642,6 +642,29 @@ impl Table {
         self.columns.values()
     }
 
+    /// Iterates over the [`ComponentId`]s of the components stored in the [`Table`].
+    pub fn component_ids(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.columns.indices()
+    }
+
+    /// Returns the number of bytes allocated for the [`ThinColumn`] of a given [`Component`],
+    /// or `None` if the table doesn't store it.
+    ///
+    /// [`Component`]: crate::component::Component
+    pub fn get_column_allocated_bytes(&self, component_id: ComponentId) -> Option<usize> {
+        self.get_column(component_id)
+            .map(|column| column.allocated_bytes(self.capacity()))
+    }
+
+    /// Returns the number of bytes allocated for the entities and the columns of the [`Table`].
+    pub fn allocated_bytes(&self) -> usize {
+        self.entities.capacity() * size_of::<Entity>()
+            + self
+                .iter_columns()
+                .map(|column| column.allocated_bytes(self.capacity()))
+                .sum::<usize>()
+    }
+
     /// Clears all of the stored components in the [`Table`].
     pub(crate) fn clear(&mut self) {
         let len = self.entity_count() as usize;
@@ -0,0 +1,312 @@
+use alloc::{collections::VecDeque, string::String, sync::Arc, vec::Vec};
+use core::{
+    fmt::{self, Debug, Display},
+    sync::atomic::{AtomicU32, Ordering},
+};
+use std::sync::{Mutex, PoisonError, TryLockError};
+
+use bevy_ecs::{
+    event::{BufferedEvent, Event, EventWriter},
+    resource::Resource,
+    system::ResMut,
+};
+use tracing::{field::Field, Level, Subscriber};
+use tracing_log::NormalizeEvent;
+use tracing_subscriber::{field::Visit, layer::Context, registry::LookupSpan, Layer};
+
+/// A log event captured by the [`LogPlugin`](crate::LogPlugin), stored in the [`LogBuffer`].
+///
+/// It is also sent as a [`BufferedEvent`] at the end of the frame it was logged in, or at the end
+/// of the first frame for the events logged before the app started.
+#[derive(Event, BufferedEvent, Debug, Clone, PartialEq, Eq)]
+pub struct CapturedLog {
+    /// The level of the event.
+    pub level: Level,
+    /// The target of the event, usually the module it was logged from.
+    pub target: String,
+    /// The message of the event.
+    pub message: String,
+    /// The other fields of the event, formatted with [`Debug`].
+    pub fields: Vec<(String, String)>,
+    /// The names of the spans the event was logged in, from the outermost.
+    pub spans: Vec<String>,
+    /// The frame the event was logged in: the number of times [`collect_captured_logs`] ran
+    /// before it was logged, which is once per frame when the [`LogPlugin`](crate::LogPlugin)
+    /// captures the events.
+    pub frame: u32,
+}
+
+impl Display for CapturedLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {:>5} ", self.frame, self.level)?;
+        for span in &self.spans {
+            write!(f, "{span}: ")?;
+        }
+        write!(f, "{}: {}", self.target, self.message)?;
+        for (name, value) in &self.fields {
+            write!(f, " {name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The log events captured by the [`LogPlugin`](crate::LogPlugin), from the oldest.
+///
+/// Only the most recent [`LogPlugin::log_buffer_capacity`](crate::LogPlugin::log_buffer_capacity)
+/// events are kept. The events filtered out by the [`LogPlugin`](crate::LogPlugin) are not
+/// captured.
+///
+/// ```
+/// # use bevy_ecs::system::Res;
+/// # use bevy_log::LogBuffer;
+/// fn print_warnings(logs: Res<LogBuffer>) {
+///     for log in logs.iter().filter(|log| log.level <= bevy_log::Level::WARN) {
+///         println!("{log}");
+///     }
+/// }
+/// ```
+#[derive(Resource)]
+pub struct LogBuffer {
+    entries: VecDeque<CapturedLog>,
+    capacity: usize,
+    shared: Arc<SharedLogs>,
+}
+
+/// The events captured by a [`LogCaptureLayer`], until they are moved to the [`LogBuffer`].
+struct SharedLogs {
+    pending: Mutex<VecDeque<CapturedLog>>,
+    frame: AtomicU32,
+}
+
+impl LogBuffer {
+    /// Creates an empty buffer, keeping the `capacity` most recent events.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            shared: Arc::new(SharedLogs {
+                pending: Mutex::new(VecDeque::new()),
+                frame: AtomicU32::new(0),
+            }),
+        }
+    }
+
+    /// Returns a tracing [`Layer`] capturing the events into this buffer.
+    ///
+    /// The events are moved to the buffer by [`collect_captured_logs`].
+    pub fn layer(&self) -> LogCaptureLayer {
+        LogCaptureLayer {
+            shared: self.shared.clone(),
+            capacity: self.capacity,
+        }
+    }
+
+    /// Iterates over the captured events, from the oldest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &CapturedLog> + ExactSizeIterator {
+        self.entries.iter()
+    }
+
+    /// Returns the number of captured events.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no event was captured.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the maximum number of events kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Removes the captured events.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Iterates over the `count` most recent events, from the oldest.
+    pub fn last(&self, count: usize) -> impl Iterator<Item = &CapturedLog> {
+        self.entries
+            .iter()
+            .skip(self.entries.len().saturating_sub(count))
+    }
+
+    /// Returns the `count` most recent events, from the oldest, including the events captured
+    /// since they were last moved to the buffer.
+    ///
+    /// This is useful when the frame didn't end, like in a crash report. The events being
+    /// captured by another thread are left out, as well as all the pending events if the thread
+    /// calling this panicked while capturing an event, instead of waiting for them.
+    pub fn recent(&self, count: usize) -> Vec<CapturedLog> {
+        let pending = match self.shared.pending.try_lock() {
+            Ok(pending) => Some(pending),
+            Err(TryLockError::Poisoned(error)) => Some(error.into_inner()),
+            Err(TryLockError::WouldBlock) => None,
+        };
+        let mut logs: Vec<_> = self
+            .entries
+            .iter()
+            .chain(pending.iter().flat_map(|pending| pending.iter()))
+            .rev()
+            .take(count)
+            .cloned()
+            .collect();
+        logs.reverse();
+        logs
+    }
+}
+
+/// Moves the events captured since the last frame to the [`LogBuffer`], and sends them as
+/// [`CapturedLog`] events.
+pub fn collect_captured_logs(mut buffer: ResMut<LogBuffer>, mut events: EventWriter<CapturedLog>) {
+    let pending: Vec<_> = buffer
+        .shared
+        .pending
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .drain(..)
+        .collect();
+    buffer.shared.frame.fetch_add(1, Ordering::Relaxed);
+    // Only mark the buffer as changed when it is.
+    if pending.is_empty() {
+        return;
+    }
+    let buffer = &mut *buffer;
+    let excess = (buffer.entries.len() + pending.len()).saturating_sub(buffer.capacity);
+    buffer.entries.drain(..excess.min(buffer.entries.len()));
+    let skipped = pending.len().saturating_sub(buffer.capacity);
+    buffer.entries.extend(pending.iter().skip(skipped).cloned());
+    events.write_batch(pending);
+}
+
+/// A tracing [`Layer`] capturing the events into a [`LogBuffer`], created by
+/// [`LogBuffer::layer`].
+pub struct LogCaptureLayer {
+    shared: Arc<SharedLogs>,
+    capacity: usize,
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for LogCaptureLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, ctx: Context<'_, S>) {
+        let normalized = event.normalized_metadata();
+        let metadata = normalized.as_ref().unwrap_or_else(|| event.metadata());
+
+        let mut visitor = CaptureVisitor::default();
+        event.record(&mut visitor);
+        let spans = ctx
+            .event_scope(event)
+            .map(|scope| {
+                scope
+                    .from_root()
+                    .map(|span| String::from(span.name()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let log = CapturedLog {
+            level: *metadata.level(),
+            target: String::from(metadata.target()),
+            message: visitor.message,
+            fields: visitor.fields,
+            spans,
+            frame: self.shared.frame.load(Ordering::Relaxed),
+        };
+        let mut pending = self
+            .shared
+            .pending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        // Only keep what fits in the buffer if the app doesn't update.
+        if pending.len() >= self.capacity {
+            pending.pop_front();
+        }
+        pending.push_back(log);
+    }
+}
+
+#[derive(Default)]
+struct CaptureVisitor {
+    message: String,
+    fields: Vec<(String, String)>,
+}
+
+impl Visit for CaptureVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        match field.name() {
+            "message" => self.message = format!("{value:?}"),
+            // The metadata of the events from the `log` crate, already normalized.
+            name if name.starts_with("log.") => {}
+            name => self.fields.push((String::from(name), format!("{value:?}"))),
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "message" => self.message = String::from(value),
+            name if name.starts_with("log.") => {}
+            name => self.fields.push((String::from(name), String::from(value))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::{App, Last};
+    use bevy_ecs::event::Events;
+    use tracing::{info, info_span, warn};
+    use tracing_subscriber::{prelude::*, Registry};
+
+    #[test]
+    fn capture_logs() {
+        let buffer = LogBuffer::new(2);
+        let subscriber = Registry::default().with(buffer.layer());
+        let mut app = App::new();
+        app.insert_resource(buffer)
+            .add_event::<CapturedLog>()
+            .add_systems(Last, collect_captured_logs);
+
+        tracing::subscriber::with_default(subscriber, || {
+            info!("dropped");
+            app.update();
+            info!("first");
+            let _span = info_span!("outer").entered();
+            let _span = info_span!("inner").entered();
+            warn!(answer = 42, name = "deep", "second");
+        });
+        app.update();
+
+        let buffer = app.world().resource::<LogBuffer>();
+        let logs: Vec<_> = buffer.iter().collect();
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].message, "first");
+        assert_eq!(logs[0].frame, 1);
+        assert_eq!(logs[1].level, Level::WARN);
+        assert_eq!(logs[1].spans, ["outer", "inner"]);
+        assert_eq!(
+            logs[1].fields,
+            [
+                ("answer".into(), "42".into()),
+                ("name".into(), "deep".into())
+            ]
+        );
+        assert_eq!(
+            logs[1].to_string(),
+            format!(
+                "[1]  WARN outer: inner: {}: second answer=42 name=deep",
+                module_path!()
+            )
+        );
+        assert_eq!(buffer.last(1).next().unwrap().message, "second");
+        assert_eq!(buffer.recent(1), [logs[1].clone()]);
+
+        // The buffer can be read while the events are being captured.
+        let _pending = buffer.shared.pending.lock().unwrap();
+        assert_eq!(buffer.recent(1), [logs[1].clone()]);
+
+        let events = app.world().resource::<Events<CapturedLog>>();
+        assert_eq!(events.len(), 3);
+    }
+}
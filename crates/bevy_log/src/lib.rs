@@ -22,8 +22,11 @@ use core::error::Error;
 
 #[cfg(target_os = "android")]
 mod android_tracing;
+mod capture;
 mod once;
 
+pub use capture::{collect_captured_logs, CapturedLog, LogBuffer, LogCaptureLayer};
+
 #[cfg(feature = "trace_tracy_memory")]
 #[global_allocator]
 static GLOBAL: tracy_client::ProfiledAllocator<std::alloc::System> =
@@ -52,7 +55,7 @@ pub use tracing::{
 };
 pub use tracing_subscriber;
 
-use bevy_app::{App, Plugin};
+use bevy_app::{App, CrashReportSettings, Last, Plugin};
 use tracing_log::LogTracer;
 use tracing_subscriber::{
     filter::{FromEnvError, ParseError},
@@ -99,6 +102,7 @@ pub(crate) struct FlushGuard(SyncCell<tracing_chrome::FlushGuard>);
 ///             filter: "wgpu=error,bevy_render=info,bevy_ecs=trace".to_string(),
 ///             custom_layer: |_| None,
 ///             fmt_layer: |_| None,
+///             log_buffer_capacity: 1000,
 ///         }))
 ///         .run();
 /// }
@@ -255,6 +259,14 @@ pub struct LogPlugin {
     ///
     /// Please see the `examples/log_layers.rs` for a complete example.
     pub fmt_layer: fn(app: &mut App) -> Option<BoxedFmtLayer>,
+
+    /// The number of recent log events kept in the [`LogBuffer`] resource, and sent as
+    /// [`CapturedLog`] events.
+    ///
+    /// The log events are not captured if this is 0, which is the default, as capturing clones
+    /// every event passing the filter. Set it, for example to 1000, to show the recent events in
+    /// the log viewer of `bevy_dev_tools` or in crash reports.
+    pub log_buffer_capacity: usize,
 }
 
 /// A boxed [`Layer`] that can be used with [`LogPlugin::custom_layer`].
@@ -284,6 +296,7 @@ impl Default for LogPlugin {
             level: Level::INFO,
             custom_layer: |_| None,
             fmt_layer: |_| None,
+            log_buffer_capacity: 0,
         }
     }
 }
@@ -399,6 +412,30 @@ impl Plugin for LogPlugin {
             finished_subscriber = subscriber.with(tracing_oslog::OsLogger::default());
         }
 
+        // Capture the log events that pass the filter, whatever the platform.
+        let capture_layer = (self.log_buffer_capacity > 0).then(|| {
+            let buffer = LogBuffer::new(self.log_buffer_capacity);
+            let layer = buffer.layer();
+            app.insert_resource(buffer)
+                .add_event::<CapturedLog>()
+                .add_systems(Last, collect_captured_logs)
+                .add_crash_report_section("Log", |world| {
+                    let buffer = world.get_resource::<LogBuffer>()?;
+                    let count = world
+                        .get_resource::<CrashReportSettings>()
+                        .map_or(100, |settings| settings.log_lines);
+                    let logs = buffer.recent(count);
+                    (!logs.is_empty()).then(|| {
+                        logs.iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    })
+                });
+            layer
+        });
+        let finished_subscriber = finished_subscriber.with(capture_layer);
+
         let logger_already_set = LogTracer::init().is_err();
         let subscriber_already_set =
             tracing::subscriber::set_global_default(finished_subscriber).is_err();
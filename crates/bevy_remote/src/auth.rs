@@ -0,0 +1,710 @@
+//! Authentication and access control for the Bevy Remote Protocol.
+//!
+//! By default, every client connected to a BRP transport can call every method of the
+//! [`RemotePlugin`](crate::RemotePlugin), including the ones despawning entities or replacing
+//! resources. This is fine when the transport only listens on localhost, but anything else should
+//! restrict what clients are allowed to do:
+//!
+//! - [`BrpPermissions`] whitelist the methods a client can call, the component, resource and event
+//!   types it can access, and the systems it can run with `bevy/run_system`.
+//!   [`BrpPermissions::read_only`] only allows the methods that don't modify the world.
+//! - A [`BrpAuthenticator`] decides which permissions a client gets from the [`BrpCredentials`] it
+//!   presents. [`BearerTokenAuth`] maps secret tokens to permissions.
+//!
+//! Clients present their credentials with an `Authorization: Bearer <token>` HTTP header, sent with
+//! each request over HTTP or during the handshake of a WebSocket connection. Clients of transports
+//! keeping a connection open, like WebSocket and stdio, can also authenticate the connection with
+//! an [`RPC_AUTHENTICATE_METHOD`] request, whose credentials are used for the following requests of
+//! the connection.
+//!
+//! Requests denied by the permissions of the client fail with the
+//! [`ACCESS_DENIED`](error_codes::ACCESS_DENIED) error code. Methods are checked before the request
+//! is handled, while types are checked by the handlers resolving them, which find the permissions
+//! of the client in the [`BrpRequestPermissions`] resource. The built-in methods also hide the
+//! denied types from their listings, like `bevy/list` and `bevy/registry/schema`: custom methods
+//! accessing values by type should do the same.
+//!
+//! After a few failed authentication attempts, the credentials presenting a token are rejected
+//! for a growing cooldown, to slow down the guessing of tokens.
+
+use crate::{builtin_methods, error_codes, BrpError, BrpMessage, BrpResult};
+use alloc::sync::Arc;
+use bevy_derive::Deref;
+use bevy_ecs::{resource::Resource, system::In, system::ResMut};
+use bevy_platform::{
+    collections::{HashMap, HashSet},
+    time::Instant,
+};
+use core::time::Duration;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The method name for authenticating the connection of a client.
+///
+/// This method accepts a `token` as its params, and fails with the
+/// [`ACCESS_DENIED`](error_codes::ACCESS_DENIED) error code if the token is rejected. Transports
+/// keeping a connection open use the token for the following requests of the connection.
+pub const RPC_AUTHENTICATE_METHOD: &str = "rpc.authenticate";
+
+/// The methods allowed by [`BrpPermissions::read_only`].
+pub const READ_ONLY_METHODS: &[&str] = &[
+    builtin_methods::BRP_GET_METHOD,
+    builtin_methods::BRP_QUERY_METHOD,
+    builtin_methods::BRP_LIST_METHOD,
+    builtin_methods::BRP_GET_AND_WATCH_METHOD,
+    builtin_methods::BRP_LIST_AND_WATCH_METHOD,
+    builtin_methods::BRP_GET_RESOURCE_METHOD,
+    builtin_methods::BRP_LIST_RESOURCES_METHOD,
+    builtin_methods::BRP_REGISTRY_SCHEMA_METHOD,
+    #[cfg(feature = "bevy_asset")]
+    builtin_methods::BRP_ASSET_DEPENDENCY_GRAPH_METHOD,
+    #[cfg(feature = "bevy_asset")]
+    builtin_methods::BRP_ASSET_LOAD_STATE_METHOD,
+    #[cfg(feature = "bevy_scene")]
+    builtin_methods::BRP_EXPORT_SCENE_METHOD,
+    builtin_methods::RPC_DISCOVER_METHOD,
+    builtin_methods::RPC_UNWATCH_METHOD,
+];
+
+/// The built-in methods that can access values of any type, which are denied when types are
+/// restricted.
+const ANY_TYPE_METHODS: &[&str] = &[
+    #[cfg(feature = "bevy_scene")]
+    builtin_methods::BRP_EXPORT_SCENE_METHOD,
+    #[cfg(feature = "bevy_scene")]
+    builtin_methods::BRP_SPAWN_SCENE_METHOD,
+];
+
+/// The number of failed authentication attempts allowed before the cooldown starts.
+const FREE_AUTHENTICATION_FAILURES: u32 = 3;
+
+/// The longest cooldown after failed authentication attempts.
+const MAX_AUTHENTICATION_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// The credentials presented by a BRP client.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrpCredentials {
+    /// The secret token of the client, if any.
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+impl BrpCredentials {
+    /// Creates credentials from a secret token.
+    pub fn bearer(token: impl Into<String>) -> Self {
+        Self {
+            token: Some(token.into()),
+        }
+    }
+
+    /// Parses the value of an `Authorization` HTTP header using the `Bearer` scheme.
+    ///
+    /// Returns anonymous credentials if the header uses another scheme.
+    pub fn from_authorization_header(value: &str) -> Self {
+        let mut parts = value.trim().splitn(2, ' ');
+        match (parts.next(), parts.next()) {
+            (Some(scheme), Some(token)) if scheme.eq_ignore_ascii_case("bearer") => {
+                Self::bearer(token.trim())
+            }
+            _ => Self::default(),
+        }
+    }
+}
+
+/// The methods and types a BRP client is allowed to access.
+///
+/// The default permissions allow everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BrpPermissions {
+    /// The methods the client can call, or `None` to allow all of them.
+    methods: Option<HashSet<String>>,
+    /// The component, resource and event types the client can access, or `None` to allow all of them.
+    types: Option<HashSet<String>>,
+    /// The names of the systems the client can run, or `None` to allow all of them.
+    systems: Option<HashSet<String>>,
+}
+
+impl BrpPermissions {
+    /// Permissions allowing every method and type.
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// Permissions only allowing the built-in methods that don't modify the world.
+    pub fn read_only() -> Self {
+        Self::default().with_methods(READ_ONLY_METHODS.iter().copied())
+    }
+
+    /// Adds `methods` to the methods the client can call.
+    ///
+    /// Once a method is added, all other methods are denied.
+    #[must_use]
+    pub fn with_methods(mut self, methods: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.methods
+            .get_or_insert_default()
+            .extend(methods.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds the component, resource or event types with the given [type paths] to the types the client
+    /// can access.
+    ///
+    /// Once a type is added, all other types are denied: they are left out of the requests
+    /// accessing all the components of entities, like a `bevy/query` with `"option": "all"`, and
+    /// the methods exporting or spawning whole scenes are denied.
+    ///
+    /// [type paths]: bevy_reflect::TypePath::type_path
+    #[must_use]
+    pub fn with_types(mut self, types: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.types
+            .get_or_insert_default()
+            .extend(types.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds the systems registered with the given names to the systems the client can run with
+    /// `bevy/run_system`.
+    ///
+    /// Once a system is added, all other systems are denied. Otherwise, allowing `bevy/run_system`
+    /// allows running every system registered with
+    /// [`RemoteApp::register_remote_system`](crate::callable::RemoteApp::register_remote_system).
+    #[must_use]
+    pub fn with_systems(mut self, names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.systems
+            .get_or_insert_default()
+            .extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns `true` if the client can call the `method`.
+    pub fn allows_method(&self, method: &str) -> bool {
+        self.methods
+            .as_ref()
+            .is_none_or(|methods| methods.contains(method))
+    }
+
+    /// Returns `true` if the client can access the component, resource or event type with the given
+    /// [type path].
+    ///
+    /// [type path]: bevy_reflect::TypePath::type_path
+    pub fn allows_type(&self, type_path: &str) -> bool {
+        self.types
+            .as_ref()
+            .is_none_or(|types| types.contains(type_path))
+    }
+
+    /// Returns `true` if the client can run the system registered with the given `name`.
+    pub fn allows_system(&self, name: &str) -> bool {
+        self.systems
+            .as_ref()
+            .is_none_or(|systems| systems.contains(name))
+    }
+
+    /// Returns `true` if the component, resource and event types the client can access are
+    /// restricted.
+    pub fn restricts_types(&self) -> bool {
+        self.types.is_some()
+    }
+
+    /// Checks that the client can call `method`.
+    ///
+    /// The types accessed by the request are checked by its handler, see [`BrpRequestPermissions`].
+    pub fn check_method(&self, method: &str) -> Result<(), BrpError> {
+        if !self.allows_method(method) {
+            return Err(BrpError::access_denied(format!(
+                "Method `{method}` is not allowed"
+            )));
+        }
+        if self.restricts_types() && ANY_TYPE_METHODS.contains(&method) {
+            return Err(BrpError::access_denied(format!(
+                "Method `{method}` is not allowed when types are restricted"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks that the client can access the component, resource or event type with the given
+    /// [type path].
+    ///
+    /// [type path]: bevy_reflect::TypePath::type_path
+    pub fn check_type(&self, type_path: &str) -> Result<(), BrpError> {
+        if self.allows_type(type_path) {
+            Ok(())
+        } else {
+            Err(BrpError::access_denied(format!(
+                "Type `{type_path}` is not allowed"
+            )))
+        }
+    }
+
+    /// Checks that the client can run the system registered with the given `name`.
+    pub fn check_system(&self, name: &str) -> Result<(), BrpError> {
+        if self.allows_system(name) {
+            Ok(())
+        } else {
+            Err(BrpError::access_denied(format!(
+                "System `{name}` is not allowed"
+            )))
+        }
+    }
+
+    /// Checks that the client can access all the types with the given [type paths].
+    ///
+    /// [type paths]: bevy_reflect::TypePath::type_path
+    pub fn check_types<'a>(
+        &self,
+        type_paths: impl IntoIterator<Item = &'a str>,
+    ) -> Result<(), BrpError> {
+        type_paths
+            .into_iter()
+            .try_for_each(|type_path| self.check_type(type_path))
+    }
+}
+
+/// A resource holding the [`BrpPermissions`] of the client whose request is being handled.
+///
+/// It is inserted while the handler of a request runs, and removed afterwards. Handlers accessing
+/// values by type should check that the client is allowed to access them. When the resource is
+/// missing, like when a handler is run directly, every type is allowed.
+#[derive(Resource, Debug, Clone, Default, Deref)]
+pub struct BrpRequestPermissions(pub BrpPermissions);
+
+/// Decides which [`BrpPermissions`] a client gets from its [`BrpCredentials`].
+///
+/// This is implemented for closures taking the credentials and returning the permissions.
+pub trait BrpAuthenticator: Send + Sync + 'static {
+    /// Returns the permissions of a client presenting `credentials`, or `None` if they are
+    /// rejected.
+    fn authenticate(&self, credentials: &BrpCredentials) -> Option<BrpPermissions>;
+}
+
+impl<F> BrpAuthenticator for F
+where
+    F: Fn(&BrpCredentials) -> Option<BrpPermissions> + Send + Sync + 'static,
+{
+    fn authenticate(&self, credentials: &BrpCredentials) -> Option<BrpPermissions> {
+        self(credentials)
+    }
+}
+
+/// A [`BrpAuthenticator`] giving permissions to the clients presenting known bearer tokens.
+///
+/// Clients without a token are rejected, unless anonymous permissions are set with
+/// [`BearerTokenAuth::with_anonymous`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_remote::{auth::{BearerTokenAuth, BrpPermissions}, RemotePlugin};
+/// let plugin = RemotePlugin::default().with_authenticator(
+///     BearerTokenAuth::default()
+///         .with_token("admin-secret", BrpPermissions::all())
+///         .with_token("viewer-secret", BrpPermissions::read_only()),
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BearerTokenAuth {
+    tokens: HashMap<String, BrpPermissions>,
+    anonymous: Option<BrpPermissions>,
+}
+
+impl BearerTokenAuth {
+    /// Gives `permissions` to the clients presenting `token`.
+    #[must_use]
+    pub fn with_token(mut self, token: impl Into<String>, permissions: BrpPermissions) -> Self {
+        self.tokens.insert(token.into(), permissions);
+        self
+    }
+
+    /// Gives `permissions` to the clients without a token.
+    #[must_use]
+    pub fn with_anonymous(mut self, permissions: BrpPermissions) -> Self {
+        self.anonymous = Some(permissions);
+        self
+    }
+}
+
+impl BrpAuthenticator for BearerTokenAuth {
+    fn authenticate(&self, credentials: &BrpCredentials) -> Option<BrpPermissions> {
+        match &credentials.token {
+            Some(token) => self.tokens.get(token).cloned(),
+            None => self.anonymous.clone(),
+        }
+    }
+}
+
+/// A resource controlling the access of BRP clients, configured through the
+/// [`RemotePlugin`](crate::RemotePlugin).
+#[derive(Resource, Clone, Default)]
+pub struct RemoteAccess {
+    /// The authenticator of the clients, or `None` to give the default permissions to every
+    /// client.
+    pub authenticator: Option<Arc<dyn BrpAuthenticator>>,
+    /// The permissions of the clients when there is no authenticator.
+    pub default_permissions: BrpPermissions,
+    /// The failed authentication attempts since the last successful one.
+    failures: AuthenticationFailures,
+}
+
+/// The failed authentication attempts of the clients, throttled by [`RemoteAccess`].
+#[derive(Debug, Clone, Default)]
+struct AuthenticationFailures {
+    /// The number of consecutive failed attempts.
+    count: u32,
+    /// When the credentials presenting a token are checked again, if in a cooldown.
+    cooldown_end: Option<Instant>,
+}
+
+impl RemoteAccess {
+    /// Returns the permissions of a client presenting `credentials`.
+    ///
+    /// Rejected tokens are counted: after a few of them, the credentials presenting a token are
+    /// rejected without being checked, for a cooldown doubling with each new failure.
+    pub fn permissions(
+        &mut self,
+        credentials: &BrpCredentials,
+    ) -> Result<BrpPermissions, BrpError> {
+        let Some(authenticator) = &self.authenticator else {
+            return Ok(self.default_permissions.clone());
+        };
+        if credentials.token.is_none() {
+            return authenticator
+                .authenticate(credentials)
+                .ok_or_else(|| BrpError::access_denied("Authentication required"));
+        }
+
+        let now = Instant::now();
+        if let Some(cooldown_end) = self.failures.cooldown_end.filter(|&end| now < end) {
+            return Err(BrpError::access_denied(format!(
+                "Too many failed authentication attempts, retry in {} seconds",
+                (cooldown_end - now).as_secs() + 1
+            )));
+        }
+
+        match authenticator.authenticate(credentials) {
+            Some(permissions) => {
+                self.failures = AuthenticationFailures::default();
+                Ok(permissions)
+            }
+            None => {
+                self.failures.count += 1;
+                if let Some(excess) = self
+                    .failures
+                    .count
+                    .checked_sub(FREE_AUTHENTICATION_FAILURES + 1)
+                {
+                    let cooldown = Duration::from_secs(1 << excess.min(6));
+                    let cooldown = cooldown.min(MAX_AUTHENTICATION_COOLDOWN);
+                    self.failures.cooldown_end = Some(now + cooldown);
+                }
+                Err(BrpError::access_denied("Invalid credentials"))
+            }
+        }
+    }
+
+    /// Checks that the client sending `message` is allowed to call its method, returning the
+    /// permissions of the client.
+    pub fn check(&mut self, message: &BrpMessage) -> Result<BrpPermissions, BrpError> {
+        if message.method == RPC_AUTHENTICATE_METHOD {
+            return Ok(BrpPermissions::all());
+        }
+        let permissions = self.permissions(&message.credentials)?;
+        permissions.check_method(&message.method)?;
+        Ok(permissions)
+    }
+}
+
+/// Handles a `rpc.authenticate` request coming from a client.
+pub fn process_remote_authenticate_request(
+    In(params): In<Option<Value>>,
+    mut access: ResMut<RemoteAccess>,
+) -> BrpResult {
+    let credentials: BrpCredentials = match params {
+        Some(params) => serde_json::from_value(params).map_err(|err| BrpError {
+            code: error_codes::INVALID_PARAMS,
+            message: err.to_string(),
+            data: None,
+        })?,
+        None => BrpCredentials::default(),
+    };
+    access.permissions(&credentials)?;
+    Ok(Value::Null)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BrpSender, RemotePlugin};
+    use bevy_app::App;
+    use bevy_ecs::{component::Component, reflect::ReflectComponent};
+    use bevy_reflect::{Reflect, TypePath};
+    use serde_json::json;
+
+    /// Sends a request to the app, returning its result.
+    fn request(
+        app: &mut App,
+        method: &str,
+        params: Value,
+        credentials: BrpCredentials,
+    ) -> BrpResult {
+        let (sender, receiver) = async_channel::bounded(1);
+        app.world()
+            .resource::<BrpSender>()
+            .try_send(BrpMessage {
+                method: method.to_string(),
+                params: (!params.is_null()).then_some(params),
+                sender,
+                credentials,
+            })
+            .unwrap();
+        app.update();
+        receiver.try_recv().unwrap()
+    }
+
+    fn error_code(result: BrpResult) -> Option<i16> {
+        result.err().map(|error| error.code)
+    }
+
+    #[test]
+    fn check_permissions() {
+        let read_only = BrpPermissions::read_only();
+        assert!(read_only.check_method("bevy/get").is_ok());
+        assert_eq!(
+            read_only.check_method("bevy/destroy").unwrap_err().code,
+            error_codes::ACCESS_DENIED
+        );
+
+        let transform = "bevy_transform::components::transform::Transform";
+        let name = "bevy_ecs::name::Name";
+        let limited = BrpPermissions::all().with_types([transform]);
+        assert!(limited.check_types([transform]).is_ok());
+        assert_eq!(
+            limited.check_types([transform, name]).unwrap_err().code,
+            error_codes::ACCESS_DENIED
+        );
+        #[cfg(feature = "bevy_scene")]
+        assert!(limited
+            .check_method(builtin_methods::BRP_EXPORT_SCENE_METHOD)
+            .is_err());
+    }
+
+    #[test]
+    fn throttle_authentication() {
+        let mut access = RemoteAccess {
+            authenticator: Some(Arc::new(
+                BearerTokenAuth::default()
+                    .with_token("admin", BrpPermissions::all())
+                    .with_anonymous(BrpPermissions::read_only()),
+            )),
+            ..Default::default()
+        };
+        for _ in 0..FREE_AUTHENTICATION_FAILURES {
+            access
+                .permissions(&BrpCredentials::bearer("other"))
+                .unwrap_err();
+            assert!(access.permissions(&BrpCredentials::bearer("admin")).is_ok());
+        }
+
+        // Only consecutive failures start the cooldown, which also rejects valid tokens.
+        for _ in 0..=FREE_AUTHENTICATION_FAILURES {
+            assert_eq!(
+                access
+                    .permissions(&BrpCredentials::bearer("other"))
+                    .unwrap_err()
+                    .message,
+                "Invalid credentials"
+            );
+        }
+        assert!(access
+            .permissions(&BrpCredentials::bearer("admin"))
+            .unwrap_err()
+            .message
+            .starts_with("Too many failed authentication attempts"));
+        assert_eq!(
+            access.permissions(&BrpCredentials::default()).ok(),
+            Some(BrpPermissions::read_only())
+        );
+    }
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Visible;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Hidden;
+
+    #[test]
+    fn deny_types() {
+        let mut app = App::new();
+        app.register_type::<Visible>()
+            .register_type::<Hidden>()
+            .add_plugins(
+                RemotePlugin::default()
+                    .with_permissions(BrpPermissions::all().with_types([Visible::type_path()])),
+            );
+        app.update();
+        let entity = app.world_mut().spawn((Visible, Hidden)).id();
+        let credentials = BrpCredentials::default();
+
+        // Types are checked wherever the built-in methods accept them.
+        let query = |data: Value, filter: Value| json!({"data": data, "filter": filter});
+        for params in [
+            query(json!({"components": [Hidden::type_path()]}), json!({})),
+            query(json!({}), json!({"without": [Hidden::type_path()]})),
+            query(json!({"has": [Hidden::type_path()]}), json!({})),
+        ] {
+            assert_eq!(
+                error_code(request(
+                    &mut app,
+                    builtin_methods::BRP_QUERY_METHOD,
+                    params,
+                    credentials.clone()
+                )),
+                Some(error_codes::ACCESS_DENIED)
+            );
+        }
+        assert_eq!(
+            error_code(request(
+                &mut app,
+                builtin_methods::BRP_REMOVE_METHOD,
+                json!({"entity": entity, "components": [Hidden::type_path()]}),
+                credentials.clone()
+            )),
+            Some(error_codes::ACCESS_DENIED)
+        );
+        assert!(app.world().entity(entity).contains::<Hidden>());
+
+        // Denied types are left out of the responses listing types.
+        let all = request(
+            &mut app,
+            builtin_methods::BRP_QUERY_METHOD,
+            query(json!({"option": "all"}), json!({})),
+            credentials.clone(),
+        )
+        .unwrap();
+        let row = all
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|row| row["entity"] == json!(entity))
+            .unwrap();
+        assert_eq!(
+            row["components"]
+                .as_object()
+                .unwrap()
+                .keys()
+                .collect::<Vec<_>>(),
+            [Visible::type_path()]
+        );
+        for params in [Value::Null, json!({"entity": entity})] {
+            let list = request(
+                &mut app,
+                builtin_methods::BRP_LIST_METHOD,
+                params,
+                credentials.clone(),
+            )
+            .unwrap();
+            assert_eq!(list, json!([Visible::type_path()]));
+        }
+        let schema = request(
+            &mut app,
+            builtin_methods::BRP_REGISTRY_SCHEMA_METHOD,
+            Value::Null,
+            credentials,
+        )
+        .unwrap();
+        assert!(schema.get(Visible::type_path()).is_some());
+        assert!(schema.get(Hidden::type_path()).is_none());
+    }
+
+    #[test]
+    fn bearer_tokens() {
+        let auth = BearerTokenAuth::default()
+            .with_token("admin", BrpPermissions::all())
+            .with_token("viewer", BrpPermissions::read_only());
+        assert_eq!(
+            auth.authenticate(&BrpCredentials::bearer("viewer")),
+            Some(BrpPermissions::read_only())
+        );
+        assert_eq!(auth.authenticate(&BrpCredentials::bearer("other")), None);
+        assert_eq!(auth.authenticate(&BrpCredentials::default()), None);
+        assert_eq!(
+            auth.with_anonymous(BrpPermissions::read_only())
+                .authenticate(&BrpCredentials::default()),
+            Some(BrpPermissions::read_only())
+        );
+
+        assert_eq!(
+            BrpCredentials::from_authorization_header("Bearer  secret "),
+            BrpCredentials::bearer("secret")
+        );
+        assert_eq!(
+            BrpCredentials::from_authorization_header("Basic dXNlcg=="),
+            BrpCredentials::default()
+        );
+    }
+
+    #[test]
+    fn deny_requests() {
+        let mut app = App::new();
+        app.add_plugins(
+            RemotePlugin::default().with_authenticator(
+                BearerTokenAuth::default()
+                    .with_token("admin", BrpPermissions::all())
+                    .with_token("viewer", BrpPermissions::read_only()),
+            ),
+        );
+        app.update();
+
+        let destroy = json!({"entity": app.world_mut().spawn_empty().id()});
+        let viewer = BrpCredentials::bearer("viewer");
+        assert_eq!(
+            error_code(request(
+                &mut app,
+                "bevy/destroy",
+                destroy.clone(),
+                BrpCredentials::default()
+            )),
+            Some(error_codes::ACCESS_DENIED)
+        );
+        assert_eq!(
+            error_code(request(
+                &mut app,
+                "bevy/destroy",
+                destroy.clone(),
+                viewer.clone()
+            )),
+            Some(error_codes::ACCESS_DENIED)
+        );
+        assert_eq!(
+            error_code(request(
+                &mut app,
+                "bevy/destroy",
+                destroy,
+                BrpCredentials::bearer("admin")
+            )),
+            None
+        );
+
+        // Authenticating is always allowed, but fails for rejected credentials.
+        assert_eq!(
+            error_code(request(
+                &mut app,
+                RPC_AUTHENTICATE_METHOD,
+                json!({"token": "viewer"}),
+                BrpCredentials::default()
+            )),
+            None
+        );
+        assert_eq!(
+            error_code(request(
+                &mut app,
+                RPC_AUTHENTICATE_METHOD,
+                json!({"token": "other"}),
+                viewer
+            )),
+            Some(error_codes::ACCESS_DENIED)
+        );
+    }
+}
@@ -24,6 +24,8 @@ use serde::{de::DeserializeSeed as _, Deserialize, Serialize};
 use serde_json::{Map, Value};
 
 use crate::{
+    auth::{BrpPermissions, BrpRequestPermissions},
+    callable::{RemoteCallableSystem, RemoteCallableSystems, RemoteEvents},
     error_codes,
     schemas::{
         json_schema::{export_type, JsonSchemaBevyType},
@@ -35,6 +37,24 @@ use crate::{
 #[cfg(all(feature = "http", not(target_family = "wasm")))]
 use {crate::schemas::open_rpc::ServerObject, bevy_utils::default};
 
+#[cfg(feature = "bevy_asset")]
+use {
+    bevy_asset::{
+        AssetServer, DependencyLoadState, LoadState, RecursiveDependencyLoadState, UntypedAssetId,
+    },
+    bevy_platform::collections::HashSet,
+};
+
+#[cfg(feature = "bevy_scene")]
+use {
+    bevy_asset::Assets,
+    bevy_ecs::{observer::Observer, system::SystemIdMarker},
+    bevy_scene::{
+        ron, serde::SceneDeserializer, DynamicScene, DynamicSceneBuilder, DynamicSceneRoot,
+        SceneFilter,
+    },
+};
+
 /// The method path for a `bevy/get` request.
 pub const BRP_GET_METHOD: &str = "bevy/get";
 
@@ -86,9 +106,40 @@ pub const BRP_LIST_RESOURCES_METHOD: &str = "bevy/list_resources";
 /// The method path for a `bevy/registry/schema` request.
 pub const BRP_REGISTRY_SCHEMA_METHOD: &str = "bevy/registry/schema";
 
+/// The method path for a `bevy/send_event` request.
+pub const BRP_SEND_EVENT_METHOD: &str = "bevy/send_event";
+
+/// The method path for a `bevy/trigger_event` request.
+pub const BRP_TRIGGER_EVENT_METHOD: &str = "bevy/trigger_event";
+
+/// The method path for a `bevy/run_system` request.
+pub const BRP_RUN_SYSTEM_METHOD: &str = "bevy/run_system";
+
+/// The method path for a `bevy/asset/dependency_graph` request.
+#[cfg(feature = "bevy_asset")]
+pub const BRP_ASSET_DEPENDENCY_GRAPH_METHOD: &str = "bevy/asset/dependency_graph";
+
+/// The method path for a `bevy/asset/load_state` request.
+#[cfg(feature = "bevy_asset")]
+pub const BRP_ASSET_LOAD_STATE_METHOD: &str = "bevy/asset/load_state";
+
+/// The method path for a `bevy/scene/export` request.
+#[cfg(feature = "bevy_scene")]
+pub const BRP_EXPORT_SCENE_METHOD: &str = "bevy/scene/export";
+
+/// The method path for a `bevy/scene/spawn` request.
+#[cfg(feature = "bevy_scene")]
+pub const BRP_SPAWN_SCENE_METHOD: &str = "bevy/scene/spawn";
+
 /// The method path for a `rpc.discover` request.
 pub const RPC_DISCOVER_METHOD: &str = "rpc.discover";
 
+/// The method path for a `rpc.unwatch` request.
+///
+/// This method is handled by the transports keeping a connection open with the client, like the
+/// WebSocket and stdio transports, rather than by the [`RemotePlugin`](crate::RemotePlugin).
+pub const RPC_UNWATCH_METHOD: &str = "rpc.unwatch";
+
 /// `bevy/get`: Retrieves one or more components from the entity with the given
 /// ID.
 ///
@@ -299,6 +350,144 @@ pub struct BrpMutateResourceParams {
     pub value: Value,
 }
 
+/// `bevy/send_event`: Sends a buffered event registered with
+/// [`RemoteApp::register_remote_event`](crate::callable::RemoteApp::register_remote_event).
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSendEventParams {
+    /// The [full path] of the event type.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+
+    /// The serialized value of the event. It may be omitted for unit structs.
+    #[serde(default)]
+    pub value: Value,
+}
+
+/// `bevy/trigger_event`: Triggers an event registered with
+/// [`RemoteApp::register_remote_trigger`](crate::callable::RemoteApp::register_remote_trigger) or
+/// [`RemoteApp::register_remote_entity_trigger`](crate::callable::RemoteApp::register_remote_entity_trigger),
+/// running its observers.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpTriggerEventParams {
+    /// The [full path] of the event type.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+
+    /// The serialized value of the event. It may be omitted for unit structs.
+    #[serde(default)]
+    pub value: Value,
+
+    /// The entities targeted by the event. Only entity events can have targets.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<Entity>,
+}
+
+/// `bevy/run_system`: Runs a system registered with
+/// [`RemoteApp::register_remote_system`](crate::callable::RemoteApp::register_remote_system).
+///
+/// The server responds with the serialized output of the system, or a null for systems without
+/// output.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpRunSystemParams {
+    /// The name the system was registered with.
+    pub name: String,
+
+    /// The serialized input of the system. It may be omitted for systems without input.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input: Option<Value>,
+}
+
+/// `bevy/asset/dependency_graph`: Retrieves the assets tracked by the `AssetServer`, together
+/// with their load states, load errors, loaders, dependencies and dependants.
+///
+/// The params may be omitted, in which case every tracked asset is returned.
+///
+/// The server responds with a [`BrpAssetDependencyGraphResponse`].
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BrpAssetDependencyGraphParams {
+    /// The asset paths to start from. If this is non-empty, only these assets and their
+    /// recursive dependencies are returned.
+    #[serde(default)]
+    pub roots: Vec<String>,
+}
+
+/// `bevy/asset/load_state`: Retrieves the load states of assets by path.
+///
+/// The server responds with a [`BrpAssetLoadStateResponse`].
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BrpAssetLoadStateParams {
+    /// The paths of the assets.
+    pub paths: Vec<String>,
+}
+
+/// `bevy/scene/export`: Serializes entities, and optionally resources, into the RON format of a
+/// `DynamicScene`.
+///
+/// The params may be omitted, in which case every entity is exported with all its components.
+///
+/// The server responds with a [`BrpExportSceneResponse`].
+#[cfg(feature = "bevy_scene")]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BrpExportSceneParams {
+    /// The entities to export. If omitted, every entity of the world is exported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<Entity>>,
+
+    /// The component types to export. If omitted, all components are exported.
+    #[serde(default)]
+    pub component_filter: BrpSceneFilter,
+
+    /// The resource types to export. If omitted, no resources are exported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_filter: Option<BrpSceneFilter>,
+}
+
+/// The types allowed and denied when exporting a scene, converted to a `SceneFilter`.
+///
+/// All types are allowed if both lists are empty.
+#[cfg(feature = "bevy_scene")]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BrpSceneFilter {
+    /// The [full paths] of the only types to export. If empty, all types are exported except the
+    /// denied ones.
+    ///
+    /// [full paths]: bevy_reflect::TypePath::type_path
+    #[serde(default)]
+    pub allow: Vec<String>,
+
+    /// The [full paths] of the types to leave out.
+    ///
+    /// [full paths]: bevy_reflect::TypePath::type_path
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+/// `bevy/scene/spawn`: Spawns a `DynamicSceneRoot` entity, from a scene in RON format or the path
+/// of a scene asset. Exactly one of `scene` and `path` must be provided.
+///
+/// The scene is instantiated as children of the root entity once it's loaded.
+///
+/// The server responds with a [`BrpSpawnResponse`] containing the root entity.
+#[cfg(feature = "bevy_scene")]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BrpSpawnSceneParams {
+    /// The scene in the RON format of a `DynamicScene`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scene: Option<String>,
+
+    /// The asset path of the scene to load.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
 /// Describes the data that is to be fetched in a query.
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct BrpQuery {
@@ -377,7 +566,7 @@ pub struct JsonSchemaTypeLimit {
 
 /// A response from the world to the client that specifies a single entity.
 ///
-/// This is sent in response to `bevy/spawn`.
+/// This is sent in response to `bevy/spawn` and `bevy/scene/spawn`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct BrpSpawnResponse {
     /// The ID of the entity in question.
@@ -441,8 +630,133 @@ pub type BrpListResourcesResponse = Vec<String>;
 /// A single response from a `bevy/list+watch` request.
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct BrpListWatchingResponse {
-    added: Vec<String>,
-    removed: Vec<String>,
+    /// The components that were added in the last tick.
+    pub added: Vec<String>,
+    /// The components that were removed in the last tick.
+    pub removed: Vec<String>,
+}
+
+/// The response to a `bevy/asset/dependency_graph` request.
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpAssetDependencyGraphResponse {
+    /// The assets in the graph, sorted by path.
+    pub nodes: Vec<BrpAssetGraphNode>,
+}
+
+/// A single asset in a [`BrpAssetDependencyGraphResponse`].
+///
+/// Assets are identified by the string representation of their `UntypedAssetId`.
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpAssetGraphNode {
+    /// The id of the asset.
+    pub id: String,
+    /// The path of the asset, if it has one.
+    pub path: Option<String>,
+    /// The load state of the asset itself.
+    pub load_state: BrpAssetLoadState,
+    /// The combined load state of the asset's direct dependencies.
+    pub dependency_load_state: BrpAssetLoadState,
+    /// The combined load state of the asset's recursive dependencies.
+    pub recursive_dependency_load_state: BrpAssetLoadState,
+    /// The error the asset failed to load with, if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub error: Option<String>,
+    /// The type name of the loader used to load the asset, if known.
+    pub loader: Option<String>,
+    /// The ids of the assets this asset depends on.
+    pub dependencies: Vec<String>,
+    /// The ids of the assets that depend on this asset.
+    pub dependants: Vec<String>,
+    /// The paths read by the asset's loader while loading it.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub loader_dependencies: Vec<String>,
+}
+
+/// The response to a `bevy/asset/load_state` request.
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpAssetLoadStateResponse {
+    /// The load states of the assets, in the order of the requested paths.
+    pub assets: Vec<BrpAssetLoadStates>,
+}
+
+/// The load states of a single asset in a [`BrpAssetLoadStateResponse`].
+///
+/// Assets that the `AssetServer` doesn't track are reported as [`BrpAssetLoadState::NotLoaded`].
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpAssetLoadStates {
+    /// The path of the asset.
+    pub path: String,
+    /// The load state of the asset itself.
+    pub load_state: BrpAssetLoadState,
+    /// The combined load state of the asset's direct dependencies.
+    pub dependency_load_state: BrpAssetLoadState,
+    /// The combined load state of the asset's recursive dependencies.
+    pub recursive_dependency_load_state: BrpAssetLoadState,
+    /// The error the asset failed to load with, if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub error: Option<String>,
+}
+
+/// The response to a `bevy/scene/export` request.
+#[cfg(feature = "bevy_scene")]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpExportSceneResponse {
+    /// The exported scene, in the RON format of a `DynamicScene`.
+    pub scene: String,
+}
+
+/// The load state of an asset, or of its dependencies, as reported over BRP.
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BrpAssetLoadState {
+    /// Loading has not started yet.
+    NotLoaded,
+    /// Loading is in progress.
+    Loading,
+    /// Loading has finished successfully.
+    Loaded,
+    /// Loading has failed.
+    Failed,
+}
+
+#[cfg(feature = "bevy_asset")]
+impl From<&LoadState> for BrpAssetLoadState {
+    fn from(state: &LoadState) -> Self {
+        match state {
+            LoadState::NotLoaded => Self::NotLoaded,
+            LoadState::Loading => Self::Loading,
+            LoadState::Loaded => Self::Loaded,
+            LoadState::Failed(_) => Self::Failed,
+        }
+    }
+}
+
+#[cfg(feature = "bevy_asset")]
+impl From<&DependencyLoadState> for BrpAssetLoadState {
+    fn from(state: &DependencyLoadState) -> Self {
+        match state {
+            DependencyLoadState::NotLoaded => Self::NotLoaded,
+            DependencyLoadState::Loading => Self::Loading,
+            DependencyLoadState::Loaded => Self::Loaded,
+            DependencyLoadState::Failed(_) => Self::Failed,
+        }
+    }
+}
+
+#[cfg(feature = "bevy_asset")]
+impl From<&RecursiveDependencyLoadState> for BrpAssetLoadState {
+    fn from(state: &RecursiveDependencyLoadState) -> Self {
+        match state {
+            RecursiveDependencyLoadState::NotLoaded => Self::NotLoaded,
+            RecursiveDependencyLoadState::Loading => Self::Loading,
+            RecursiveDependencyLoadState::Loaded => Self::Loaded,
+            RecursiveDependencyLoadState::Failed(_) => Self::Failed,
+        }
+    }
 }
 
 /// The response to a `bevy/query` request.
@@ -490,6 +804,7 @@ pub fn process_remote_get_request(In(params): In<Option<Value>>, world: &World)
         components,
         strict,
     } = parse_some(params)?;
+    request_permissions(world).check_types(components.iter().map(String::as_str))?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
@@ -508,6 +823,7 @@ pub fn process_remote_get_resource_request(
     let BrpGetResourceParams {
         resource: resource_path,
     } = parse_some(params)?;
+    request_permissions(world).check_type(&resource_path)?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
@@ -550,6 +866,7 @@ pub fn process_remote_get_watching_request(
         components,
         strict,
     } = parse_some(params)?;
+    request_permissions(world).check_types(components.iter().map(String::as_str))?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
@@ -757,6 +1074,25 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
         },
     };
 
+    // With `"option": "all"`, the denied components are left out of the response.
+    let permissions = request_permissions(world);
+    let option_paths = match &option {
+        ComponentSelector::Paths(paths) => paths.as_slice(),
+        ComponentSelector::All => &[],
+    };
+    permissions.check_types(
+        [
+            components.as_slice(),
+            option_paths,
+            has.as_slice(),
+            filter.with.as_slice(),
+            filter.without.as_slice(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(String::as_str),
+    )?;
+
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
 
@@ -854,6 +1190,10 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
                             if required.iter().any(|(_, cid)| cid == &component_id) {
                                 return None;
                             }
+                            let type_path = type_registry.get(type_id)?.type_info().type_path();
+                            if !permissions.allows_type(type_path) {
+                                return None;
+                            }
                             Some((type_id, Some(component_id)))
                         });
                 components_map.extend(serialize_components(
@@ -937,6 +1277,7 @@ fn serialize_components(
 /// Handles a `bevy/spawn` request coming from a client.
 pub fn process_remote_spawn_request(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
     let BrpSpawnParams { components } = parse_some(params)?;
+    request_permissions(world).check_types(components.keys().map(String::as_str))?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
@@ -997,6 +1338,7 @@ pub fn process_remote_insert_request(
     world: &mut World,
 ) -> BrpResult {
     let BrpInsertParams { entity, components } = parse_some(params)?;
+    request_permissions(world).check_types(components.keys().map(String::as_str))?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
@@ -1023,6 +1365,7 @@ pub fn process_remote_insert_resource_request(
         resource: resource_path,
         value,
     } = parse_some(params)?;
+    request_permissions(world).check_type(&resource_path)?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
@@ -1051,6 +1394,7 @@ pub fn process_remote_mutate_component_request(
         path,
         value,
     } = parse_some(params)?;
+    request_permissions(world).check_type(&component)?;
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
 
@@ -1111,6 +1455,7 @@ pub fn process_remote_mutate_resource_request(
         path: field_path,
         value,
     } = parse_some(params)?;
+    request_permissions(world).check_type(&resource_path)?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
@@ -1158,6 +1503,7 @@ pub fn process_remote_remove_request(
     world: &mut World,
 ) -> BrpResult {
     let BrpRemoveParams { entity, components } = parse_some(params)?;
+    request_permissions(world).check_types(components.iter().map(String::as_str))?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
@@ -1189,6 +1535,7 @@ pub fn process_remote_remove_resource_request(
     let BrpRemoveResourceParams {
         resource: resource_path,
     } = parse_some(params)?;
+    request_permissions(world).check_type(&resource_path)?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
@@ -1212,6 +1559,65 @@ pub fn process_remote_destroy_request(
     Ok(Value::Null)
 }
 
+/// Handles a `bevy/send_event` request coming from a client.
+pub fn process_remote_send_event_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSendEventParams { event, value } = parse_some(params)?;
+    request_permissions(world).check_type(&event)?;
+
+    let send = world
+        .get_resource::<RemoteEvents>()
+        .and_then(|events| events.get(&event))
+        .and_then(|fns| fns.send)
+        .ok_or_else(|| BrpError::event_error(anyhow!("Event `{event}` can't be sent remotely")))?;
+    send(world, value)?;
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/trigger_event` request coming from a client.
+pub fn process_remote_trigger_event_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpTriggerEventParams {
+        event,
+        value,
+        targets,
+    } = parse_some(params)?;
+    request_permissions(world).check_type(&event)?;
+
+    let trigger = world
+        .get_resource::<RemoteEvents>()
+        .and_then(|events| events.get(&event))
+        .and_then(|fns| fns.trigger)
+        .ok_or_else(|| {
+            BrpError::event_error(anyhow!("Event `{event}` can't be triggered remotely"))
+        })?;
+    trigger(world, value, targets)?;
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/run_system` request coming from a client.
+pub fn process_remote_run_system_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpRunSystemParams { name, input } = parse_some(params)?;
+    request_permissions(world).check_system(&name)?;
+
+    // The system is run outside of the resources, so that it can access them.
+    let RemoteCallableSystem { system, run } = world
+        .get_resource::<RemoteCallableSystems>()
+        .and_then(|systems| systems.get(&name))
+        .copied()
+        .ok_or_else(|| BrpError::system_error(anyhow!("System `{name}` can't be run remotely")))?;
+    run(world, system, input)
+}
+
 /// Handles a `bevy/reparent` request coming from a client.
 pub fn process_remote_reparent_request(
     In(params): In<Option<Value>>,
@@ -1247,6 +1653,7 @@ pub fn process_remote_reparent_request(
 pub fn process_remote_list_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
     let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
+    let permissions = request_permissions(world);
 
     let mut response = BrpListResponse::default();
 
@@ -1257,14 +1664,20 @@ pub fn process_remote_list_request(In(params): In<Option<Value>>, world: &World)
             let Some(component_info) = world.components().get_info(component_id) else {
                 continue;
             };
-            response.push(component_info.name().to_string());
+            let name = component_info.name().to_string();
+            if permissions.allows_type(&name) {
+                response.push(name);
+            }
         }
     }
     // If `None`, list all registered components.
     else {
         for registered_type in type_registry.iter() {
-            if registered_type.data::<ReflectComponent>().is_some() {
-                response.push(registered_type.type_info().type_path().to_owned());
+            let type_path = registered_type.type_info().type_path();
+            if registered_type.data::<ReflectComponent>().is_some()
+                && permissions.allows_type(type_path)
+            {
+                response.push(type_path.to_owned());
             }
         }
     }
@@ -1285,10 +1698,13 @@ pub fn process_remote_list_resources_request(
 
     let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
+    let permissions = request_permissions(world);
 
     for registered_type in type_registry.iter() {
-        if registered_type.data::<ReflectResource>().is_some() {
-            response.push(registered_type.type_info().type_path().to_owned());
+        let type_path = registered_type.type_info().type_path();
+        if registered_type.data::<ReflectResource>().is_some() && permissions.allows_type(type_path)
+        {
+            response.push(type_path.to_owned());
         }
     }
 
@@ -1305,6 +1721,7 @@ pub fn process_remote_list_watching_request(
 ) -> BrpResult<Option<Value>> {
     let BrpListParams { entity } = parse_some(params)?;
     let entity_ref = get_entity(world, entity)?;
+    let permissions = request_permissions(world);
     let mut response = BrpListWatchingResponse::default();
 
     for component_id in entity_ref.archetype().components() {
@@ -1316,7 +1733,10 @@ pub fn process_remote_list_watching_request(
             let Some(component_info) = world.components().get_info(component_id) else {
                 continue;
             };
-            response.added.push(component_info.name().to_string());
+            let name = component_info.name().to_string();
+            if permissions.allows_type(&name) {
+                response.added.push(name);
+            }
         }
     }
 
@@ -1329,7 +1749,10 @@ pub fn process_remote_list_watching_request(
                 let Some(component_info) = world.components().get_info(*component_id) else {
                     continue;
                 };
-                response.removed.push(component_info.name().to_string());
+                let name = component_info.name().to_string();
+                if permissions.allows_type(&name) {
+                    response.removed.push(name);
+                }
             }
         }
     }
@@ -1353,8 +1776,10 @@ pub fn export_registry_types(In(params): In<Option<Value>>, world: &World) -> Br
     let extra_info = world.resource::<crate::schemas::SchemaTypesMetadata>();
     let types = world.resource::<AppTypeRegistry>();
     let types = types.read();
+    let permissions = request_permissions(world);
     let schemas = types
         .iter()
+        .filter(|type_reg| is_type_visible(world, &permissions, type_reg))
         .filter_map(|type_reg| {
             let path_table = type_reg.type_info().type_path_table();
             if let Some(crate_name) = &path_table.crate_name() {
@@ -1396,6 +1821,271 @@ pub fn export_registry_types(In(params): In<Option<Value>>, world: &World) -> Br
     serde_json::to_value(schemas).map_err(BrpError::internal)
 }
 
+/// Handles a `bevy/asset/dependency_graph` request coming from a client.
+#[cfg(feature = "bevy_asset")]
+pub fn process_remote_asset_dependency_graph_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpAssetDependencyGraphParams { roots } = match params {
+        None => Default::default(),
+        Some(params) => parse(params)?,
+    };
+
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .ok_or_else(|| BrpError::resource_not_present(core::any::type_name::<AssetServer>()))?;
+    let mut graph = asset_server.dependency_graph();
+
+    if !roots.is_empty() {
+        let root_ids = roots
+            .iter()
+            .map(|path| {
+                graph
+                    .get_by_path(path.as_str())
+                    .map(|node| node.id)
+                    .ok_or_else(|| BrpError::asset_not_found(path))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        graph = graph.subgraph(root_ids);
+    }
+
+    let ids_to_strings = |ids: &HashSet<UntypedAssetId>| {
+        let mut ids: Vec<String> = ids.iter().map(ToString::to_string).collect();
+        ids.sort();
+        ids
+    };
+    let mut nodes: Vec<BrpAssetGraphNode> = graph
+        .nodes()
+        .map(|node| BrpAssetGraphNode {
+            id: node.id.to_string(),
+            path: node.path.as_ref().map(ToString::to_string),
+            load_state: (&node.load_state).into(),
+            dependency_load_state: (&node.dependency_load_state).into(),
+            recursive_dependency_load_state: (&node.recursive_dependency_load_state).into(),
+            error: match &node.load_state {
+                LoadState::Failed(error) => Some(error.to_string()),
+                _ => None,
+            },
+            loader: node.loader.map(ToOwned::to_owned),
+            dependencies: ids_to_strings(&node.dependencies),
+            dependants: ids_to_strings(&node.dependants),
+            loader_dependencies: node
+                .loader_dependencies
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        })
+        .collect();
+    nodes.sort_by(|a, b| (&a.path, &a.id).cmp(&(&b.path, &b.id)));
+
+    serde_json::to_value(BrpAssetDependencyGraphResponse { nodes }).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/asset/load_state` request coming from a client.
+#[cfg(feature = "bevy_asset")]
+pub fn process_remote_asset_load_state_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpAssetLoadStateParams { paths } = parse_some(params)?;
+
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .ok_or_else(|| BrpError::resource_not_present(core::any::type_name::<AssetServer>()))?;
+
+    let assets = paths
+        .into_iter()
+        .map(|path| {
+            let states = asset_server
+                .get_path_id(path.as_str())
+                .and_then(|id| asset_server.get_load_states(id));
+            match states {
+                Some((load_state, dependency_load_state, recursive_dependency_load_state)) => {
+                    BrpAssetLoadStates {
+                        path,
+                        load_state: (&load_state).into(),
+                        dependency_load_state: (&dependency_load_state).into(),
+                        recursive_dependency_load_state: (&recursive_dependency_load_state).into(),
+                        error: match load_state {
+                            LoadState::Failed(error) => Some(error.to_string()),
+                            _ => None,
+                        },
+                    }
+                }
+                None => BrpAssetLoadStates {
+                    path,
+                    load_state: BrpAssetLoadState::NotLoaded,
+                    dependency_load_state: BrpAssetLoadState::NotLoaded,
+                    recursive_dependency_load_state: BrpAssetLoadState::NotLoaded,
+                    error: None,
+                },
+            }
+        })
+        .collect();
+
+    serde_json::to_value(BrpAssetLoadStateResponse { assets }).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/scene/export` request coming from a client.
+#[cfg(feature = "bevy_scene")]
+pub fn process_remote_export_scene_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpExportSceneParams {
+        entities,
+        component_filter,
+        resource_filter,
+    } = match params {
+        None => Default::default(),
+        Some(params) => parse(params)?,
+    };
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    let entities = match entities {
+        Some(entities) => {
+            for &entity in &entities {
+                get_entity(world, entity)?;
+            }
+            entities
+        }
+        None => exported_entities(world)?,
+    };
+    let mut builder = DynamicSceneBuilder::from_world(world)
+        .with_component_filter(scene_filter(&type_registry, &component_filter)?)
+        .extract_entities(entities.into_iter());
+    if let Some(resource_filter) = resource_filter {
+        builder = builder
+            .with_resource_filter(scene_filter(&type_registry, &resource_filter)?)
+            .extract_resources();
+    }
+
+    let scene = builder
+        .build()
+        .serialize(&type_registry)
+        .map_err(BrpError::scene_error)?;
+    serde_json::to_value(BrpExportSceneResponse { scene }).map_err(BrpError::internal)
+}
+
+/// Returns the entities exported by a `bevy/scene/export` request that doesn't list them.
+///
+/// Disabled entities are skipped, as well as the entities of observers and one-shot systems, which
+/// are internal to the app.
+#[cfg(feature = "bevy_scene")]
+fn exported_entities(world: &World) -> Result<Vec<Entity>, BrpError> {
+    // The query skips the entities disabled by the default query filters, unlike queries accessing
+    // all the components of the entities.
+    let mut query = world
+        .try_query::<Entity>()
+        .ok_or_else(|| BrpError::internal("the entities of the world can't be queried"))?;
+    Ok(query
+        .iter(world)
+        .filter(|&entity| {
+            let entity = world.entity(entity);
+            !entity.contains::<Observer>() && !entity.contains::<SystemIdMarker>()
+        })
+        .collect())
+}
+
+/// Handles a `bevy/scene/spawn` request coming from a client.
+#[cfg(feature = "bevy_scene")]
+pub fn process_remote_spawn_scene_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSpawnSceneParams { scene, path } = parse_some(params)?;
+
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .ok_or_else(|| BrpError::resource_not_present(core::any::type_name::<AssetServer>()))?
+        .clone();
+    let handle = match (scene, path) {
+        (Some(scene), None) => {
+            let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+            // Parents are written as full asset paths, see `DynamicScene::parent`.
+            let mut load_parent = |path: &str| asset_server.load(path.to_owned());
+            let scene_deserializer = SceneDeserializer {
+                type_registry: &app_type_registry.read(),
+                load_parent: Some(&mut load_parent),
+            };
+            let mut deserializer =
+                ron::de::Deserializer::from_str(&scene).map_err(BrpError::scene_error)?;
+            let scene = scene_deserializer
+                .deserialize(&mut deserializer)
+                .map_err(|err| BrpError::scene_error(deserializer.span_error(err)))?;
+            world
+                .get_resource_mut::<Assets<DynamicScene>>()
+                .ok_or_else(|| {
+                    BrpError::resource_not_present(core::any::type_name::<Assets<DynamicScene>>())
+                })?
+                .add(scene)
+        }
+        (None, Some(path)) => asset_server.load(path),
+        _ => {
+            return Err(BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: "Exactly one of `scene` and `path` must be provided".to_string(),
+                data: None,
+            })
+        }
+    };
+
+    let entity = world.spawn(DynamicSceneRoot(handle)).id();
+    serde_json::to_value(BrpSpawnResponse { entity }).map_err(BrpError::internal)
+}
+
+/// Converts the type paths of a [`BrpSceneFilter`] to a [`SceneFilter`].
+#[cfg(feature = "bevy_scene")]
+fn scene_filter(
+    type_registry: &TypeRegistry,
+    filter: &BrpSceneFilter,
+) -> Result<SceneFilter, BrpError> {
+    let type_id = |type_path: &String| {
+        type_registry
+            .get_with_type_path(type_path)
+            .map(TypeRegistration::type_id)
+            .ok_or_else(|| BrpError::scene_error(format!("Unknown type: `{type_path}`")))
+    };
+    let mut scene_filter = SceneFilter::Unset;
+    for type_path in &filter.allow {
+        scene_filter = scene_filter.allow_by_id(type_id(type_path)?);
+    }
+    for type_path in &filter.deny {
+        scene_filter = scene_filter.deny_by_id(type_id(type_path)?);
+    }
+    Ok(scene_filter)
+}
+
+/// Returns the permissions of the client whose request is being handled, allowing every type if
+/// the handler isn't run for a request.
+fn request_permissions(world: &World) -> BrpPermissions {
+    world
+        .get_resource::<BrpRequestPermissions>()
+        .map(|permissions| permissions.0.clone())
+        .unwrap_or_default()
+}
+
+/// Returns `true` if the client with the given `permissions` can see the registered type.
+///
+/// [`BrpPermissions`] only restrict component, resource and event types: other types, like the
+/// types of their fields, are always visible.
+fn is_type_visible(
+    world: &World,
+    permissions: &BrpPermissions,
+    registration: &TypeRegistration,
+) -> bool {
+    let type_path = registration.type_info().type_path();
+    permissions.allows_type(type_path)
+        || (registration.data::<ReflectComponent>().is_none()
+            && registration.data::<ReflectResource>().is_none()
+            && world
+                .get_resource::<RemoteEvents>()
+                .is_none_or(|events| events.get(type_path).is_none()))
+}
+
 /// Immutably retrieves an entity from the [`World`], returning an error if the
 /// entity isn't present.
 fn get_entity(world: &World, entity: Entity) -> Result<EntityRef<'_>, BrpError> {
@@ -1648,4 +2338,149 @@ mod tests {
             entity: Entity::from_raw_u32(0).unwrap(),
         });
     }
+
+    #[cfg(feature = "bevy_asset")]
+    #[test]
+    fn asset_dependency_graph_serialization_tests() {
+        test_serialize_deserialize(BrpAssetDependencyGraphParams::default());
+        test_serialize_deserialize(BrpAssetDependencyGraphResponse {
+            nodes: vec![BrpAssetGraphNode {
+                id: "0".to_owned(),
+                path: Some("scene.scn.ron".to_owned()),
+                load_state: BrpAssetLoadState::Loaded,
+                dependency_load_state: BrpAssetLoadState::Loading,
+                recursive_dependency_load_state: BrpAssetLoadState::Failed,
+                error: None,
+                loader: Some("bevy_scene::SceneLoader".to_owned()),
+                dependencies: vec!["1".to_owned()],
+                dependants: vec![],
+                loader_dependencies: vec![],
+            }],
+        });
+    }
+
+    #[cfg(feature = "bevy_scene")]
+    #[test]
+    fn export_and_spawn_scene() {
+        use bevy_app::{App, TaskPoolPlugin};
+        use bevy_asset::AssetPlugin;
+        use bevy_ecs::{component::Component, reflect::ReflectComponent};
+        use bevy_reflect::{Reflect, TypePath};
+        use bevy_scene::ScenePlugin;
+        use serde_json::json;
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component)]
+        struct Marker(u32);
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component)]
+        struct Ignored;
+
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .register_type::<Marker>()
+        .register_type::<Ignored>();
+        let world = app.world_mut();
+        let entity = world.spawn((Marker(7), Ignored)).id();
+        world.spawn(Marker(8));
+
+        let params = json!({
+            "entities": [entity],
+            "component_filter": {"deny": [Ignored::type_path()]},
+        });
+        let response = world
+            .run_system_cached_with(process_remote_export_scene_request, Some(params))
+            .unwrap()
+            .unwrap();
+        let BrpExportSceneResponse { scene } = serde_json::from_value(response).unwrap();
+        assert!(scene.contains("Marker"));
+        assert!(!scene.contains("Ignored"));
+
+        let response = world
+            .run_system_cached_with(
+                process_remote_spawn_scene_request,
+                Some(json!({"scene": scene})),
+            )
+            .unwrap()
+            .unwrap();
+        let BrpSpawnResponse { entity: root } = serde_json::from_value(response).unwrap();
+        app.update();
+        app.update();
+
+        let world = app.world_mut();
+        let mut query = world.query::<(&Marker, &ChildOf)>();
+        let spawned: Vec<_> = query
+            .iter(world)
+            .map(|(marker, child_of)| (marker.0, child_of.parent()))
+            .collect();
+        assert_eq!(spawned, [(7, root)]);
+
+        let error = world
+            .run_system_cached_with(process_remote_spawn_scene_request, Some(json!({})))
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error.code, error_codes::INVALID_PARAMS);
+
+        let response = world
+            .run_system_cached_with(
+                process_remote_asset_load_state_request,
+                Some(json!({"paths": ["missing.scn.ron"]})),
+            )
+            .unwrap()
+            .unwrap();
+        let BrpAssetLoadStateResponse { assets } = serde_json::from_value(response).unwrap();
+        assert_eq!(assets[0].load_state, BrpAssetLoadState::NotLoaded);
+    }
+
+    #[cfg(feature = "bevy_scene")]
+    #[test]
+    fn export_whole_world() {
+        use bevy_ecs::{
+            component::Component, entity_disabling::Disabled, observer::On,
+            reflect::ReflectComponent, system::Commands,
+        };
+        use bevy_reflect::Reflect;
+        use bevy_scene::{serde::SceneDeserializer, DynamicScene};
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component)]
+        struct Marker(u32);
+
+        #[derive(bevy_ecs::event::Event)]
+        struct Ping;
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Marker>();
+        let exported = world.spawn(Marker(1)).id();
+        world.spawn((Marker(2), Disabled));
+        world.add_observer(|_: On<Ping>, mut commands: Commands| {
+            commands.spawn(Marker(3));
+        });
+        world.register_system(|| {});
+
+        let response = world
+            .run_system_cached_with(process_remote_export_scene_request, None)
+            .unwrap()
+            .unwrap();
+        let BrpExportSceneResponse { scene } = serde_json::from_value(response).unwrap();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let mut deserializer = ron::de::Deserializer::from_str(&scene).unwrap();
+        let scene: DynamicScene = SceneDeserializer {
+            type_registry: &registry,
+            load_parent: None,
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+        let entities: Vec<Entity> = scene.entities.iter().map(|entity| entity.entity).collect();
+        assert_eq!(entities, [exported]);
+    }
 }
@@ -0,0 +1,465 @@
+//! Registration of the events and systems that BRP clients can send, trigger and run.
+//!
+//! Unlike components and resources, which are accessible as soon as they are reflected, events and
+//! systems have to be registered with the [`RemoteApp`] methods before clients can use them with
+//! the `bevy/send_event`, `bevy/trigger_event` and `bevy/run_system` methods:
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_reflect::Reflect;
+//! # use bevy_remote::{callable::RemoteApp, RemotePlugin};
+//! #[derive(Event, BufferedEvent, Reflect)]
+//! struct Explosion {
+//!     power: f32,
+//! }
+//!
+//! fn count_entities(In(limit): In<usize>, query: Query<Entity>) -> usize {
+//!     query.iter().count().min(limit)
+//! }
+//!
+//! App::new()
+//!     .add_plugins(RemotePlugin::default())
+//!     .add_event::<Explosion>()
+//!     .register_remote_event::<Explosion>()
+//!     .register_remote_system("count_entities", count_entities);
+//! ```
+
+use crate::{BrpError, BrpResult};
+use bevy_app::App;
+use bevy_ecs::{
+    entity::Entity,
+    event::{BufferedEvent, EntityEvent, Event},
+    reflect::AppTypeRegistry,
+    resource::Resource,
+    system::{In, IntoSystem, SystemId},
+    world::World,
+};
+use bevy_platform::collections::HashMap;
+use bevy_reflect::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    FromReflect, GetTypeRegistration, PartialReflect, TypeInfo, TypePath,
+};
+use core::any::TypeId;
+use serde::de::DeserializeSeed as _;
+use serde_json::{Map, Value};
+
+/// Sends a buffered event deserialized from a JSON value.
+pub type RemoteSendEventFn = fn(&mut World, Value) -> BrpResult<()>;
+
+/// Triggers an event deserialized from a JSON value, for the given target entities.
+pub type RemoteTriggerEventFn = fn(&mut World, Value, Vec<Entity>) -> BrpResult<()>;
+
+/// Runs the one-shot system with the given entity, with an input deserialized from a JSON value,
+/// returning its serialized output.
+pub type RemoteRunSystemFn = fn(&mut World, Entity, Option<Value>) -> BrpResult;
+
+/// A system registered with [`RemoteApp::register_remote_system`].
+#[derive(Clone, Copy)]
+pub struct RemoteCallableSystem {
+    /// The entity of the one-shot system, see [`SystemId::entity`].
+    pub system: Entity,
+    /// Runs the system.
+    pub run: RemoteRunSystemFn,
+}
+
+/// The functions used to send or trigger an event registered with [`RemoteApp`].
+#[derive(Clone, Copy, Default)]
+pub struct RemoteEventFns {
+    /// Sends the event, if it was registered with [`RemoteApp::register_remote_event`].
+    pub send: Option<RemoteSendEventFn>,
+    /// Triggers the event, if it was registered with [`RemoteApp::register_remote_trigger`] or
+    /// [`RemoteApp::register_remote_entity_trigger`].
+    pub trigger: Option<RemoteTriggerEventFn>,
+}
+
+/// A resource holding the events that BRP clients can send and trigger, by [type path].
+///
+/// [type path]: bevy_reflect::TypePath::type_path
+#[derive(Resource, Default)]
+pub struct RemoteEvents(HashMap<String, RemoteEventFns>);
+
+impl RemoteEvents {
+    /// Returns the functions sending and triggering the event with the given [type path].
+    ///
+    /// [type path]: bevy_reflect::TypePath::type_path
+    pub fn get(&self, type_path: &str) -> Option<&RemoteEventFns> {
+        self.0.get(type_path)
+    }
+
+    /// Returns the [type paths] of the registered events.
+    ///
+    /// [type paths]: bevy_reflect::TypePath::type_path
+    pub fn type_paths(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+}
+
+/// A resource holding the systems that BRP clients can run, by name.
+#[derive(Resource, Default)]
+pub struct RemoteCallableSystems(HashMap<String, RemoteCallableSystem>);
+
+impl RemoteCallableSystems {
+    /// Returns the system registered with the given `name`.
+    pub fn get(&self, name: &str) -> Option<&RemoteCallableSystem> {
+        self.0.get(name)
+    }
+
+    /// Returns the names of the registered systems.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+}
+
+/// Methods to register the events and systems that BRP clients can use.
+pub trait RemoteApp {
+    /// Allows BRP clients to send the [`BufferedEvent`] `E` with `bevy/send_event`.
+    ///
+    /// The event must also be added to the app with `add_event`.
+    fn register_remote_event<E>(&mut self) -> &mut Self
+    where
+        E: BufferedEvent + FromReflect + TypePath + GetTypeRegistration;
+
+    /// Allows BRP clients to trigger the [`Event`] `E` with `bevy/trigger_event`, running its
+    /// global observers.
+    fn register_remote_trigger<E>(&mut self) -> &mut Self
+    where
+        E: Event + FromReflect + TypePath + GetTypeRegistration;
+
+    /// Allows BRP clients to trigger the [`EntityEvent`] `E` with `bevy/trigger_event`, optionally
+    /// targeting entities.
+    fn register_remote_entity_trigger<E>(&mut self) -> &mut Self
+    where
+        E: EntityEvent + FromReflect + TypePath + GetTypeRegistration;
+
+    /// Registers `system` as a one-shot system that BRP clients can run by `name` with
+    /// `bevy/run_system`.
+    ///
+    /// The input of the system is deserialized from the request and its output is serialized in
+    /// the response, using reflection. Systems without input or output use `()`.
+    fn register_remote_system<I, O, M>(
+        &mut self,
+        name: impl Into<String>,
+        system: impl IntoSystem<In<I>, O, M> + 'static,
+    ) -> &mut Self
+    where
+        I: FromReflect + TypePath + GetTypeRegistration,
+        O: PartialReflect + TypePath + GetTypeRegistration;
+}
+
+impl RemoteApp for App {
+    fn register_remote_event<E>(&mut self) -> &mut Self
+    where
+        E: BufferedEvent + FromReflect + TypePath + GetTypeRegistration,
+    {
+        self.register_type::<E>();
+        self.world_mut()
+            .get_resource_or_init::<RemoteEvents>()
+            .0
+            .entry(E::type_path().to_string())
+            .or_default()
+            .send = Some(send_event::<E>);
+        self
+    }
+
+    fn register_remote_trigger<E>(&mut self) -> &mut Self
+    where
+        E: Event + FromReflect + TypePath + GetTypeRegistration,
+    {
+        self.register_type::<E>();
+        self.world_mut()
+            .get_resource_or_init::<RemoteEvents>()
+            .0
+            .entry(E::type_path().to_string())
+            .or_default()
+            .trigger = Some(trigger_event::<E>);
+        self
+    }
+
+    fn register_remote_entity_trigger<E>(&mut self) -> &mut Self
+    where
+        E: EntityEvent + FromReflect + TypePath + GetTypeRegistration,
+    {
+        self.register_type::<E>();
+        self.world_mut()
+            .get_resource_or_init::<RemoteEvents>()
+            .0
+            .entry(E::type_path().to_string())
+            .or_default()
+            .trigger = Some(trigger_entity_event::<E>);
+        self
+    }
+
+    fn register_remote_system<I, O, M>(
+        &mut self,
+        name: impl Into<String>,
+        system: impl IntoSystem<In<I>, O, M> + 'static,
+    ) -> &mut Self
+    where
+        I: FromReflect + TypePath + GetTypeRegistration,
+        O: PartialReflect + TypePath + GetTypeRegistration,
+    {
+        self.register_type::<I>().register_type::<O>();
+        let name = name.into();
+        let system = self.world_mut().register_system(system).entity();
+        self.world_mut()
+            .get_resource_or_init::<RemoteCallableSystems>()
+            .0
+            .insert(
+                name,
+                RemoteCallableSystem {
+                    system,
+                    run: run_system::<I, O>,
+                },
+            );
+        self
+    }
+}
+
+fn run_system<I, O>(world: &mut World, system: Entity, input: Option<Value>) -> BrpResult
+where
+    I: FromReflect + TypePath,
+    O: PartialReflect + TypePath,
+{
+    let input =
+        deserialize::<I>(world, input.unwrap_or_default()).map_err(BrpError::system_error)?;
+    let output = world
+        .run_system_with(SystemId::<In<I>, O>::from_entity(system), input)
+        .map_err(BrpError::system_error)?;
+    if TypeId::of::<O>() == TypeId::of::<()>() {
+        return Ok(Value::Null);
+    }
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    serde_json::to_value(TypedReflectSerializer::new(&output, &type_registry))
+        .map_err(BrpError::system_error)
+}
+
+fn send_event<E: BufferedEvent + FromReflect + TypePath>(
+    world: &mut World,
+    value: Value,
+) -> BrpResult<()> {
+    let event = deserialize::<E>(world, value).map_err(BrpError::event_error)?;
+    world.send_event(event).map(|_| ()).ok_or_else(|| {
+        BrpError::event_error(format!(
+            "Event `{}` was not added to the app",
+            E::type_path()
+        ))
+    })
+}
+
+fn trigger_event<E: Event + FromReflect + TypePath>(
+    world: &mut World,
+    value: Value,
+    targets: Vec<Entity>,
+) -> BrpResult<()> {
+    if !targets.is_empty() {
+        return Err(BrpError::event_error(format!(
+            "Event `{}` can't target entities",
+            E::type_path()
+        )));
+    }
+    let event = deserialize::<E>(world, value).map_err(BrpError::event_error)?;
+    world.trigger(event);
+    Ok(())
+}
+
+fn trigger_entity_event<E: EntityEvent + FromReflect + TypePath>(
+    world: &mut World,
+    value: Value,
+    targets: Vec<Entity>,
+) -> BrpResult<()> {
+    if let Some(&entity) = targets
+        .iter()
+        .find(|&&entity| !world.entities().contains(entity))
+    {
+        return Err(BrpError::entity_not_found(entity));
+    }
+    let event = deserialize::<E>(world, value).map_err(BrpError::event_error)?;
+    if targets.is_empty() {
+        world.trigger(event);
+    } else {
+        world.trigger_targets(event, targets);
+    }
+    Ok(())
+}
+
+/// Deserializes a value of type `T` from JSON using its reflected type registration.
+fn deserialize<T: FromReflect + TypePath>(world: &World, value: Value) -> Result<T, String> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let registration = type_registry
+        .get(TypeId::of::<T>())
+        .ok_or_else(|| format!("Type `{}` is not registered", T::type_path()))?;
+    // Unit types may be omitted, which is represented by a null.
+    let value = match (value, registration.type_info()) {
+        (Value::Null, TypeInfo::Struct(info)) if info.field_len() == 0 => Value::Object(Map::new()),
+        (Value::Null, TypeInfo::TupleStruct(info)) if info.field_len() == 0 => {
+            Value::Array(Vec::new())
+        }
+        (Value::Null, TypeInfo::Tuple(info)) if info.field_len() == 0 => Value::Array(Vec::new()),
+        (value, _) => value,
+    };
+    let reflected = TypedReflectDeserializer::new(registration, &type_registry)
+        .deserialize(&value)
+        .map_err(|err| format!("{} is invalid: {err}", T::type_path()))?;
+    T::from_reflect(&*reflected)
+        .ok_or_else(|| format!("{} could not be built from its value", T::type_path()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        auth::{BrpPermissions, BrpRequestPermissions},
+        builtin_methods::*,
+        error_codes, RemotePlugin,
+    };
+    use bevy_ecs::{
+        event::Events,
+        observer::On,
+        system::{Query, Res, ResMut},
+    };
+    use bevy_reflect::Reflect;
+    use serde_json::json;
+
+    #[derive(Event, BufferedEvent, Reflect, Debug, PartialEq)]
+    struct Explosion {
+        power: f32,
+    }
+
+    #[derive(Event, Reflect)]
+    struct Reset;
+
+    #[derive(Event, EntityEvent, Reflect)]
+    struct Hit(u32);
+
+    #[derive(Resource, Default)]
+    struct Log(Vec<String>);
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(RemotePlugin::default())
+            .init_resource::<Log>()
+            .add_event::<Explosion>()
+            .register_remote_event::<Explosion>()
+            .register_remote_trigger::<Reset>()
+            .register_remote_entity_trigger::<Hit>()
+            .add_observer(|_: On<Reset>, mut log: ResMut<Log>| log.0.push("reset".into()))
+            .add_observer(|hit: On<Hit>, mut log: ResMut<Log>| {
+                log.0
+                    .push(format!("hit {} {}", hit.event().0, hit.target()));
+            });
+        app
+    }
+
+    fn error_code(result: BrpResult) -> Option<i16> {
+        result.err().map(|error| error.code)
+    }
+
+    #[test]
+    fn send_event() {
+        let mut app = app();
+        let world = app.world_mut();
+        let result = world.run_system_cached_with(
+            process_remote_send_event_request,
+            Some(json!({"event": Explosion::type_path(), "value": {"power": 2.0}})),
+        );
+        assert_eq!(result.unwrap().unwrap(), Value::Null);
+        let events = world.resource::<Events<Explosion>>();
+        assert_eq!(
+            events.iter_current_update_events().collect::<Vec<_>>(),
+            [&Explosion { power: 2.0 }]
+        );
+
+        // Events that are only triggered can't be sent.
+        let result = world.run_system_cached_with(
+            process_remote_send_event_request,
+            Some(json!({"event": Reset::type_path()})),
+        );
+        assert_eq!(error_code(result.unwrap()), Some(error_codes::EVENT_ERROR));
+    }
+
+    #[test]
+    fn trigger_event() {
+        let mut app = app();
+        let world = app.world_mut();
+        let entity = world.spawn_empty().id();
+        let mut trigger = |params: Value| {
+            world
+                .run_system_cached_with(process_remote_trigger_event_request, Some(params))
+                .unwrap()
+        };
+
+        assert!(trigger(json!({"event": Reset::type_path()})).is_ok());
+        assert!(
+            trigger(json!({"event": Hit::type_path(), "value": 3, "targets": [entity]})).is_ok()
+        );
+        assert_eq!(
+            error_code(trigger(
+                json!({"event": Reset::type_path(), "targets": [entity]})
+            )),
+            Some(error_codes::EVENT_ERROR)
+        );
+        assert_eq!(
+            error_code(trigger(
+                json!({"event": Explosion::type_path(), "value": {"power": 1.0}})
+            )),
+            Some(error_codes::EVENT_ERROR)
+        );
+        assert_eq!(
+            world.resource::<Log>().0,
+            ["reset".to_string(), format!("hit 3 {entity}")]
+        );
+    }
+
+    #[test]
+    fn run_system() {
+        let mut app = app();
+        app.register_remote_system("count", |In(limit): In<usize>, query: Query<Entity>| {
+            query.iter().count().min(limit)
+        })
+        .register_remote_system("clear", |In(()): In<()>, mut log: ResMut<Log>| {
+            log.0.clear();
+        })
+        // Systems can access the registered systems while they run.
+        .register_remote_system(
+            "count_systems",
+            |In(()): In<()>, systems: Res<RemoteCallableSystems>| systems.names().count(),
+        );
+        let world = app.world_mut();
+        world.spawn_batch([(), (), ()]);
+        world.resource_mut::<Log>().0.push("entry".into());
+        let mut run = |params: Value| {
+            world
+                .run_system_cached_with(process_remote_run_system_request, Some(params))
+                .unwrap()
+        };
+
+        assert_eq!(run(json!({"name": "count", "input": 2})).unwrap(), json!(2));
+        assert_eq!(
+            error_code(run(json!({"name": "count"}))),
+            Some(error_codes::SYSTEM_ERROR)
+        );
+        assert_eq!(run(json!({"name": "clear"})).unwrap(), Value::Null);
+        assert_eq!(run(json!({"name": "count_systems"})).unwrap(), json!(3));
+        assert_eq!(
+            error_code(run(json!({"name": "unknown"}))),
+            Some(error_codes::SYSTEM_ERROR)
+        );
+        assert!(world.resource::<Log>().0.is_empty());
+
+        // Clients can be restricted to some of the systems.
+        world.insert_resource(BrpRequestPermissions(
+            BrpPermissions::all().with_systems(["count"]),
+        ));
+        let mut run = |params: Value| {
+            world
+                .run_system_cached_with(process_remote_run_system_request, Some(params))
+                .unwrap()
+        };
+        assert_eq!(run(json!({"name": "count", "input": 2})).unwrap(), json!(2));
+        assert_eq!(
+            error_code(run(json!({"name": "clear"}))),
+            Some(error_codes::ACCESS_DENIED)
+        );
+    }
+}
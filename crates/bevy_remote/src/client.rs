@@ -0,0 +1,774 @@
+//! A typed Rust client for the Bevy Remote Protocol.
+//!
+//! [`BrpClient`] wraps each built-in method in a function taking and returning the types of the
+//! [`builtin_methods`] module, instead of hand-built JSON. Requests are sent through a
+//! [`BrpTransport`], which is [`HttpTransport`] by default but can be replaced to talk to the app
+//! in other ways.
+//!
+//! ```no_run
+//! # use bevy_remote::client::BrpClient;
+//! # fn main() -> Result<(), bevy_remote::client::BrpClientError> {
+//! let mut client = BrpClient::http("127.0.0.1:15702");
+//! let resources = client.list_resources()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    builtin_methods::*,
+    schemas::{json_schema::JsonSchemaBevyType, open_rpc::OpenRpcDocument},
+    BrpError, BrpRequest,
+};
+use alloc::boxed::Box;
+use bevy_ecs::entity::Entity;
+use bevy_platform::collections::HashMap;
+use core::marker::PhantomData;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+#[cfg(not(target_family = "wasm"))]
+pub use http::HttpTransport;
+
+/// The responses of a watching request, as they are received.
+pub type BrpResponseStream = Box<dyn Iterator<Item = Result<Value, BrpClientError>> + Send>;
+
+/// An error that occurs when making a request with a [`BrpClient`].
+#[derive(Error, Debug)]
+pub enum BrpClientError {
+    /// The transport failed to communicate with the app.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// A request or response could not be serialized or deserialized.
+    #[error("invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The HTTP connection to the app failed.
+    #[error("HTTP error: {0}")]
+    Http(#[from] hyper::Error),
+    /// A header of the requests is invalid.
+    #[error("invalid header: {0}")]
+    InvalidHeader(String),
+    /// The app answered with an unexpected message.
+    #[error("unexpected response: {0}")]
+    Protocol(String),
+    /// The app answered the request with an error.
+    #[error("the request failed with code {}: {}", .0.code, .0.message)]
+    Remote(BrpError),
+}
+
+/// Sends requests to a Bevy app running the [`RemotePlugin`](crate::RemotePlugin).
+pub trait BrpTransport {
+    /// Sends a request and waits for its response.
+    fn send(&mut self, request: &BrpRequest) -> Result<Value, BrpClientError>;
+
+    /// Sends a watching request, returning the stream of its responses.
+    ///
+    /// The watching request stops when the stream is dropped.
+    fn watch(&mut self, request: &BrpRequest) -> Result<BrpResponseStream, BrpClientError>;
+}
+
+/// The results of a watching request made with a [`BrpClient`].
+///
+/// Iterating blocks until the next result is received. Dropping this stops the request.
+pub struct BrpWatch<R> {
+    responses: BrpResponseStream,
+    _marker: PhantomData<fn() -> R>,
+}
+
+impl<R: DeserializeOwned> Iterator for BrpWatch<R> {
+    type Item = Result<R, BrpClientError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let response = self.responses.next()?;
+        Some(
+            response
+                .and_then(into_result)
+                .and_then(|result| serde_json::from_value(result).map_err(BrpClientError::from)),
+        )
+    }
+}
+
+/// A client calling the methods of a Bevy app through a [`BrpTransport`].
+pub struct BrpClient<T> {
+    transport: T,
+    next_id: u64,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl BrpClient<HttpTransport> {
+    /// Creates a client sending its requests over HTTP to the given address, like
+    /// `"127.0.0.1:15702"`.
+    pub fn http(address: impl Into<String>) -> Self {
+        Self::new(HttpTransport::new(address))
+    }
+}
+
+impl<T: BrpTransport> BrpClient<T> {
+    /// Creates a client sending its requests through `transport`.
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            next_id: 0,
+        }
+    }
+
+    /// Returns the transport of the client.
+    pub fn transport_mut(&mut self) -> &mut T {
+        &mut self.transport
+    }
+
+    /// Calls `method` with `params`, returning its deserialized result.
+    ///
+    /// This can be used for custom methods.
+    pub fn call<R: DeserializeOwned>(
+        &mut self,
+        method: &str,
+        params: Option<impl Serialize>,
+    ) -> Result<R, BrpClientError> {
+        let request = self.request(method, params)?;
+        let response = self.transport.send(&request)?;
+        Ok(serde_json::from_value(into_result(response)?)?)
+    }
+
+    /// Calls the watching `method` with `params`, returning its deserialized results.
+    ///
+    /// This can be used for custom watching methods.
+    pub fn call_watching<R: DeserializeOwned>(
+        &mut self,
+        method: &str,
+        params: Option<impl Serialize>,
+    ) -> Result<BrpWatch<R>, BrpClientError> {
+        let request = self.request(method, params)?;
+        Ok(BrpWatch {
+            responses: self.transport.watch(&request)?,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Retrieves the `components` of `entity`, with `bevy/get`.
+    ///
+    /// When `strict` is `false`, the components that can't be retrieved are reported in the
+    /// response instead of failing the request.
+    pub fn get(
+        &mut self,
+        entity: Entity,
+        components: &[&str],
+        strict: bool,
+    ) -> Result<BrpGetResponse, BrpClientError> {
+        self.call(BRP_GET_METHOD, Some(get_params(entity, components, strict)))
+    }
+
+    /// Retrieves and deserializes the value of a single component of `entity`, with `bevy/get`.
+    pub fn get_component<C: DeserializeOwned>(
+        &mut self,
+        entity: Entity,
+        component: &str,
+    ) -> Result<C, BrpClientError> {
+        match self.get(entity, &[component], true)? {
+            BrpGetResponse::Strict(mut components) => {
+                let value = components.remove(component).ok_or_else(|| {
+                    BrpClientError::Protocol(format!("`{component}` missing from the response"))
+                })?;
+                Ok(serde_json::from_value(value)?)
+            }
+            BrpGetResponse::Lenient { .. } => Err(BrpClientError::Protocol(
+                "unexpected lenient response to a strict request".into(),
+            )),
+        }
+    }
+
+    /// Queries the entities matching `filter`, returning the components selected by `data`, with
+    /// `bevy/query`.
+    ///
+    /// When `strict` is `false`, the components that can't be retrieved are skipped instead of
+    /// failing the request.
+    pub fn query(
+        &mut self,
+        data: BrpQuery,
+        filter: BrpQueryFilter,
+        strict: bool,
+    ) -> Result<BrpQueryResponse, BrpClientError> {
+        self.call(
+            BRP_QUERY_METHOD,
+            Some(BrpQueryParams {
+                data,
+                filter,
+                strict,
+            }),
+        )
+    }
+
+    /// Spawns an entity with the given serialized `components`, with `bevy/spawn`.
+    pub fn spawn(&mut self, components: HashMap<String, Value>) -> Result<Entity, BrpClientError> {
+        let response: BrpSpawnResponse =
+            self.call(BRP_SPAWN_METHOD, Some(BrpSpawnParams { components }))?;
+        Ok(response.entity)
+    }
+
+    /// Inserts the serialized `components` into `entity`, with `bevy/insert`.
+    pub fn insert(
+        &mut self,
+        entity: Entity,
+        components: HashMap<String, Value>,
+    ) -> Result<(), BrpClientError> {
+        self.call_unit(BRP_INSERT_METHOD, BrpInsertParams { entity, components })
+    }
+
+    /// Removes the `components` from `entity`, with `bevy/remove`.
+    pub fn remove(&mut self, entity: Entity, components: &[&str]) -> Result<(), BrpClientError> {
+        self.call_unit(
+            BRP_REMOVE_METHOD,
+            BrpRemoveParams {
+                entity,
+                components: to_strings(components),
+            },
+        )
+    }
+
+    /// Despawns `entity`, with `bevy/destroy`.
+    pub fn destroy(&mut self, entity: Entity) -> Result<(), BrpClientError> {
+        self.call_unit(BRP_DESTROY_METHOD, BrpDestroyParams { entity })
+    }
+
+    /// Makes `entities` children of `parent`, or removes their parent if `None`, with
+    /// `bevy/reparent`.
+    pub fn reparent(
+        &mut self,
+        entities: &[Entity],
+        parent: Option<Entity>,
+    ) -> Result<(), BrpClientError> {
+        self.call_unit(
+            BRP_REPARENT_METHOD,
+            BrpReparentParams {
+                entities: entities.to_vec(),
+                parent,
+            },
+        )
+    }
+
+    /// Lists the components of `entity`, or all the registered components if `None`, with
+    /// `bevy/list`.
+    pub fn list(&mut self, entity: Option<Entity>) -> Result<BrpListResponse, BrpClientError> {
+        self.call(
+            BRP_LIST_METHOD,
+            entity.map(|entity| BrpListParams { entity }),
+        )
+    }
+
+    /// Sets the field at `path` in the `component` of `entity` to `value`, with
+    /// `bevy/mutate_component`.
+    pub fn mutate_component(
+        &mut self,
+        entity: Entity,
+        component: &str,
+        path: &str,
+        value: Value,
+    ) -> Result<(), BrpClientError> {
+        self.call_unit(
+            BRP_MUTATE_COMPONENT_METHOD,
+            BrpMutateComponentParams {
+                entity,
+                component: component.to_string(),
+                path: path.to_string(),
+                value,
+            },
+        )
+    }
+
+    /// Watches the changes of the `components` of `entity`, with `bevy/get+watch`.
+    pub fn get_watch(
+        &mut self,
+        entity: Entity,
+        components: &[&str],
+        strict: bool,
+    ) -> Result<BrpWatch<BrpGetWatchingResponse>, BrpClientError> {
+        self.call_watching(
+            BRP_GET_AND_WATCH_METHOD,
+            Some(get_params(entity, components, strict)),
+        )
+    }
+
+    /// Watches the components added to and removed from `entity`, with `bevy/list+watch`.
+    pub fn list_watch(
+        &mut self,
+        entity: Entity,
+    ) -> Result<BrpWatch<BrpListWatchingResponse>, BrpClientError> {
+        self.call_watching(BRP_LIST_AND_WATCH_METHOD, Some(BrpListParams { entity }))
+    }
+
+    /// Retrieves the serialized value of a resource, with `bevy/get_resource`.
+    pub fn get_resource(&mut self, resource: &str) -> Result<Value, BrpClientError> {
+        let response: BrpGetResourceResponse = self.call(
+            BRP_GET_RESOURCE_METHOD,
+            Some(BrpGetResourceParams {
+                resource: resource.to_string(),
+            }),
+        )?;
+        Ok(response.value)
+    }
+
+    /// Inserts a resource from its serialized `value`, with `bevy/insert_resource`.
+    pub fn insert_resource(&mut self, resource: &str, value: Value) -> Result<(), BrpClientError> {
+        self.call_unit(
+            BRP_INSERT_RESOURCE_METHOD,
+            BrpInsertResourceParams {
+                resource: resource.to_string(),
+                value,
+            },
+        )
+    }
+
+    /// Removes a resource, with `bevy/remove_resource`.
+    pub fn remove_resource(&mut self, resource: &str) -> Result<(), BrpClientError> {
+        self.call_unit(
+            BRP_REMOVE_RESOURCE_METHOD,
+            BrpRemoveResourceParams {
+                resource: resource.to_string(),
+            },
+        )
+    }
+
+    /// Sets the field at `path` in a resource to `value`, with `bevy/mutate_resource`.
+    pub fn mutate_resource(
+        &mut self,
+        resource: &str,
+        path: &str,
+        value: Value,
+    ) -> Result<(), BrpClientError> {
+        self.call_unit(
+            BRP_MUTATE_RESOURCE_METHOD,
+            BrpMutateResourceParams {
+                resource: resource.to_string(),
+                path: path.to_string(),
+                value,
+            },
+        )
+    }
+
+    /// Lists the registered resources, with `bevy/list_resources`.
+    pub fn list_resources(&mut self) -> Result<BrpListResourcesResponse, BrpClientError> {
+        self.call(BRP_LIST_RESOURCES_METHOD, None::<()>)
+    }
+
+    /// Retrieves the JSON schemas of the registered types matching `filter`, by type path, with
+    /// `bevy/registry/schema`.
+    pub fn registry_schema(
+        &mut self,
+        filter: BrpJsonSchemaQueryFilter,
+    ) -> Result<HashMap<String, JsonSchemaBevyType>, BrpClientError> {
+        self.call(BRP_REGISTRY_SCHEMA_METHOD, Some(filter))
+    }
+
+    /// Retrieves the `OpenRPC` document describing the methods of the app, with `rpc.discover`.
+    pub fn discover(&mut self) -> Result<OpenRpcDocument, BrpClientError> {
+        self.call(RPC_DISCOVER_METHOD, None::<()>)
+    }
+
+    /// Sends a buffered event from its serialized `value`, with `bevy/send_event`.
+    pub fn send_event(&mut self, event: &str, value: Value) -> Result<(), BrpClientError> {
+        self.call_unit(
+            BRP_SEND_EVENT_METHOD,
+            BrpSendEventParams {
+                event: event.to_string(),
+                value,
+            },
+        )
+    }
+
+    /// Triggers an event from its serialized `value`, optionally targeting entities, with
+    /// `bevy/trigger_event`.
+    pub fn trigger_event(
+        &mut self,
+        event: &str,
+        value: Value,
+        targets: &[Entity],
+    ) -> Result<(), BrpClientError> {
+        self.call_unit(
+            BRP_TRIGGER_EVENT_METHOD,
+            BrpTriggerEventParams {
+                event: event.to_string(),
+                value,
+                targets: targets.to_vec(),
+            },
+        )
+    }
+
+    /// Runs the system registered with `name`, returning its deserialized output, with
+    /// `bevy/run_system`.
+    pub fn run_system<R: DeserializeOwned>(
+        &mut self,
+        name: &str,
+        input: Option<Value>,
+    ) -> Result<R, BrpClientError> {
+        self.call(
+            BRP_RUN_SYSTEM_METHOD,
+            Some(BrpRunSystemParams {
+                name: name.to_string(),
+                input,
+            }),
+        )
+    }
+
+    /// Retrieves the assets reachable from `roots`, or all of them if empty, with
+    /// `bevy/asset/dependency_graph`.
+    #[cfg(feature = "bevy_asset")]
+    pub fn asset_dependency_graph(
+        &mut self,
+        roots: &[&str],
+    ) -> Result<BrpAssetDependencyGraphResponse, BrpClientError> {
+        self.call(
+            BRP_ASSET_DEPENDENCY_GRAPH_METHOD,
+            Some(BrpAssetDependencyGraphParams {
+                roots: to_strings(roots),
+            }),
+        )
+    }
+
+    /// Retrieves the load states of the assets at `paths`, with `bevy/asset/load_state`.
+    #[cfg(feature = "bevy_asset")]
+    pub fn asset_load_state(
+        &mut self,
+        paths: &[&str],
+    ) -> Result<BrpAssetLoadStateResponse, BrpClientError> {
+        self.call(
+            BRP_ASSET_LOAD_STATE_METHOD,
+            Some(BrpAssetLoadStateParams {
+                paths: to_strings(paths),
+            }),
+        )
+    }
+
+    /// Exports entities and resources as a scene in RON format, with `bevy/scene/export`.
+    #[cfg(feature = "bevy_scene")]
+    pub fn export_scene(&mut self, params: BrpExportSceneParams) -> Result<String, BrpClientError> {
+        let response: BrpExportSceneResponse = self.call(BRP_EXPORT_SCENE_METHOD, Some(params))?;
+        Ok(response.scene)
+    }
+
+    /// Spawns a scene given in RON format, returning its root entity, with `bevy/scene/spawn`.
+    #[cfg(feature = "bevy_scene")]
+    pub fn spawn_scene(&mut self, scene: &str) -> Result<Entity, BrpClientError> {
+        self.call_spawn_scene(BrpSpawnSceneParams {
+            scene: Some(scene.to_string()),
+            path: None,
+        })
+    }
+
+    /// Loads and spawns the scene asset at `path`, returning its root entity, with
+    /// `bevy/scene/spawn`.
+    #[cfg(feature = "bevy_scene")]
+    pub fn spawn_scene_asset(&mut self, path: &str) -> Result<Entity, BrpClientError> {
+        self.call_spawn_scene(BrpSpawnSceneParams {
+            scene: None,
+            path: Some(path.to_string()),
+        })
+    }
+
+    #[cfg(feature = "bevy_scene")]
+    fn call_spawn_scene(&mut self, params: BrpSpawnSceneParams) -> Result<Entity, BrpClientError> {
+        let response: BrpSpawnResponse = self.call(BRP_SPAWN_SCENE_METHOD, Some(params))?;
+        Ok(response.entity)
+    }
+
+    /// Calls a method responding with a null.
+    fn call_unit(&mut self, method: &str, params: impl Serialize) -> Result<(), BrpClientError> {
+        self.call::<Value>(method, Some(params)).map(|_| ())
+    }
+
+    fn request(
+        &mut self,
+        method: &str,
+        params: Option<impl Serialize>,
+    ) -> Result<BrpRequest, BrpClientError> {
+        self.next_id += 1;
+        Ok(BrpRequest {
+            jsonrpc: String::from("2.0"),
+            method: method.to_string(),
+            id: Some(Value::from(self.next_id)),
+            params: params.map(serde_json::to_value).transpose()?,
+        })
+    }
+}
+
+fn get_params(entity: Entity, components: &[&str], strict: bool) -> BrpGetParams {
+    BrpGetParams {
+        entity,
+        components: to_strings(components),
+        strict,
+    }
+}
+
+fn to_strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(ToString::to_string).collect()
+}
+
+/// Returns the `result` of a response, or its `error`.
+fn into_result(mut response: Value) -> Result<Value, BrpClientError> {
+    if let Some(error) = response.get_mut("error") {
+        return Err(BrpClientError::Remote(serde_json::from_value(
+            error.take(),
+        )?));
+    }
+    match response.get_mut("result") {
+        Some(result) => Ok(result.take()),
+        None => Err(BrpClientError::Protocol(format!(
+            "response without a result: {response}"
+        ))),
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+mod http {
+    use super::{BrpClientError, BrpResponseStream, BrpTransport};
+    use crate::BrpRequest;
+    use alloc::boxed::Box;
+    use async_io::Async;
+    use bevy_tasks::{block_on, futures_lite::future};
+    use core::{future::Future, pin::Pin};
+    use http_body_util::{BodyExt, Full};
+    use hyper::{
+        body::{Bytes, Incoming},
+        client::conn::http1::{self, Connection},
+        header::{HeaderName, HeaderValue, AUTHORIZATION, CONTENT_TYPE, HOST},
+        Request, StatusCode,
+    };
+    use serde_json::Value;
+    use smol_hyper::rt::FuturesIo;
+    use std::net::TcpStream;
+
+    type ClientConnection = Connection<FuturesIo<Async<TcpStream>>, Full<Bytes>>;
+
+    /// A [`BrpTransport`] sending requests over HTTP, to the
+    /// [`RemoteHttpPlugin`](crate::http::RemoteHttpPlugin).
+    ///
+    /// Each request uses a new connection. Watching requests keep their connection open until
+    /// their stream is dropped.
+    #[derive(Debug, Clone)]
+    pub struct HttpTransport {
+        address: String,
+        headers: Vec<(HeaderName, HeaderValue)>,
+    }
+
+    impl HttpTransport {
+        /// Creates a transport connecting to the given address, like `"127.0.0.1:15702"`.
+        pub fn new(address: impl Into<String>) -> Self {
+            Self {
+                address: address.into(),
+                headers: Vec::new(),
+            }
+        }
+
+        /// Adds a header to the requests.
+        ///
+        /// Fails if `name` or `value` isn't a valid header, for example if they contain line
+        /// breaks.
+        pub fn with_header(mut self, name: &str, value: &str) -> Result<Self, BrpClientError> {
+            let name = HeaderName::try_from(name)
+                .map_err(|err| BrpClientError::InvalidHeader(err.to_string()))?;
+            let value = HeaderValue::try_from(value)
+                .map_err(|err| BrpClientError::InvalidHeader(err.to_string()))?;
+            self.headers.push((name, value));
+            Ok(self)
+        }
+
+        /// Authenticates the requests with a bearer token.
+        ///
+        /// Fails if `token` isn't a valid header value.
+        pub fn with_bearer_token(self, token: &str) -> Result<Self, BrpClientError> {
+            self.with_header(AUTHORIZATION.as_str(), &format!("Bearer {token}"))
+        }
+
+        /// Posts `request`, returning the body of the response once its headers are received.
+        fn post(&self, request: &BrpRequest) -> Result<ResponseBody, BrpClientError> {
+            let mut builder = Request::post("/")
+                .header(HOST, self.address.as_str())
+                .header(CONTENT_TYPE, "application/json");
+            for (name, value) in &self.headers {
+                builder = builder.header(name, value);
+            }
+            let request = builder
+                .body(Full::new(Bytes::from(serde_json::to_vec(request)?)))
+                .map_err(|err| BrpClientError::InvalidHeader(err.to_string()))?;
+
+            let stream = Async::new(TcpStream::connect(&self.address)?)?;
+            let (mut sender, connection) = block_on(http1::handshake(FuturesIo::new(stream)))?;
+            let mut connection = Some(Box::pin(connection));
+            let response = drive(&mut connection, sender.send_request(request))??;
+            if response.status() != StatusCode::OK {
+                return Err(BrpClientError::Protocol(format!(
+                    "unexpected HTTP status: {}",
+                    response.status()
+                )));
+            }
+            Ok(ResponseBody {
+                connection,
+                body: response.into_body(),
+            })
+        }
+    }
+
+    impl BrpTransport for HttpTransport {
+        fn send(&mut self, request: &BrpRequest) -> Result<Value, BrpClientError> {
+            let mut response = self.post(request)?;
+            let mut body = Vec::new();
+            while let Some(chunk) = response.next_chunk()? {
+                body.extend_from_slice(&chunk);
+            }
+            Ok(serde_json::from_slice(&body)?)
+        }
+
+        fn watch(&mut self, request: &BrpRequest) -> Result<BrpResponseStream, BrpClientError> {
+            Ok(Box::new(Events {
+                response: self.post(request)?,
+                buffer: Vec::new(),
+            }))
+        }
+    }
+
+    /// The body of an HTTP response, along with the connection receiving it.
+    struct ResponseBody {
+        /// The connection, until it is closed.
+        connection: Option<Pin<Box<ClientConnection>>>,
+        body: Incoming,
+    }
+
+    impl ResponseBody {
+        /// Waits for the next chunk of the body, returning `None` once it is complete.
+        fn next_chunk(&mut self) -> Result<Option<Bytes>, BrpClientError> {
+            loop {
+                let Some(frame) = drive(&mut self.connection, self.body.frame())? else {
+                    return Ok(None);
+                };
+                // Trailers are ignored.
+                if let Ok(data) = frame?.into_data() {
+                    return Ok(Some(data));
+                }
+            }
+        }
+    }
+
+    /// The server-sent events of a watching request, one `data:` line per response.
+    struct Events {
+        response: ResponseBody,
+        buffer: Vec<u8>,
+    }
+
+    impl Iterator for Events {
+        type Item = Result<Value, BrpClientError>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                if let Some(end) = self.buffer.iter().position(|&byte| byte == b'\n') {
+                    let line: Vec<u8> = self.buffer.drain(..=end).collect();
+                    if let Some(data) = line.strip_prefix(b"data:") {
+                        return Some(serde_json::from_slice(data).map_err(BrpClientError::from));
+                    }
+                    continue;
+                }
+                match self.response.next_chunk() {
+                    Ok(Some(chunk)) => self.buffer.extend_from_slice(&chunk),
+                    Ok(None) => return None,
+                    Err(err) => return Some(Err(err)),
+                }
+            }
+        }
+    }
+
+    /// Blocks on `future` while driving `connection`, which is cleared once it is closed.
+    fn drive<F: Future>(
+        connection: &mut Option<Pin<Box<ClientConnection>>>,
+        future: F,
+    ) -> Result<F::Output, BrpClientError> {
+        let mut future = core::pin::pin!(future);
+        if let Some(open) = connection {
+            let output = block_on(future::or(
+                async { Ok(Some(future.as_mut().await)) },
+                async { open.as_mut().await.map(|()| None) },
+            ))?;
+            if let Some(output) = output {
+                return Ok(output);
+            }
+            *connection = None;
+        }
+        Ok(block_on(future))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error_codes;
+    use serde_json::json;
+
+    /// A transport answering each request with the next of `responses`.
+    #[derive(Default)]
+    struct MockTransport {
+        requests: Vec<BrpRequest>,
+        responses: Vec<Value>,
+    }
+
+    impl BrpTransport for MockTransport {
+        fn send(&mut self, request: &BrpRequest) -> Result<Value, BrpClientError> {
+            self.requests.push(request.clone());
+            Ok(self.responses.remove(0))
+        }
+
+        fn watch(&mut self, request: &BrpRequest) -> Result<BrpResponseStream, BrpClientError> {
+            self.requests.push(request.clone());
+            Ok(Box::new(
+                core::mem::take(&mut self.responses).into_iter().map(Ok),
+            ))
+        }
+    }
+
+    #[test]
+    fn custom_transport() {
+        let entity = Entity::from_raw_u32(3).unwrap();
+        let mut client = BrpClient::new(MockTransport {
+            responses: vec![
+                json!({"jsonrpc": "2.0", "id": 1, "result": {"entity": entity}}),
+                json!({"jsonrpc": "2.0", "id": 2, "error": {"code": error_codes::ENTITY_NOT_FOUND, "message": "not found"}}),
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(client.spawn(HashMap::default()).unwrap(), entity);
+        let Err(BrpClientError::Remote(error)) = client.destroy(entity) else {
+            panic!("expected a remote error");
+        };
+        assert_eq!(error.code, error_codes::ENTITY_NOT_FOUND);
+
+        let requests = &client.transport_mut().requests;
+        assert_eq!(requests[0].method, BRP_SPAWN_METHOD);
+        assert_eq!(requests[0].id, Some(json!(1)));
+        assert_eq!(requests[1].params, Some(json!({ "entity": entity })));
+
+        client.transport_mut().responses = vec![
+            json!({"jsonrpc": "2.0", "id": 3, "result": {"added": ["a"], "removed": []}}),
+            json!({"jsonrpc": "2.0", "id": 3, "result": {"added": [], "removed": ["a"]}}),
+        ];
+        let changes = client
+            .list_watch(entity)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(changes[1].removed, vec!["a".to_string()]);
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    #[test]
+    fn invalid_headers() {
+        let transport = HttpTransport::new("127.0.0.1:15702");
+        assert!(transport.clone().with_header("X-Custom", "value").is_ok());
+        assert!(matches!(
+            transport
+                .clone()
+                .with_header("X-Custom", "value\r\nX-Injected: 1"),
+            Err(BrpClientError::InvalidHeader(_))
+        ));
+        assert!(matches!(
+            transport.with_bearer_token("token\n"),
+            Err(BrpClientError::InvalidHeader(_))
+        ));
+    }
+}
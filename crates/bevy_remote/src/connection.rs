@@ -0,0 +1,414 @@
+//! Request processing shared by the BRP transports keeping a connection open with each client.
+//!
+//! Clients send requests, or batches of requests, as JSON messages, and receive responses as JSON
+//! messages. Requests are processed concurrently, so responses may arrive in a different order
+//! than the requests: clients should match them using the request `id`.
+//!
+//! Any number of watching requests (`+watch` methods) can run concurrently on a connection. Each
+//! result of a watching request is pushed to the client as a response with the `id` of the request,
+//! until the client stops it with a [`RPC_UNWATCH_METHOD`] request or closes the connection.
+//!
+//! A successful [`RPC_AUTHENTICATE_METHOD`] request replaces the credentials used for the following
+//! requests of the connection.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{
+    auth::{BrpCredentials, RPC_AUTHENTICATE_METHOD},
+    builtin_methods::RPC_UNWATCH_METHOD,
+    error_codes, BrpBatch, BrpError, BrpMessage, BrpPayload, BrpRequest, BrpResponse, BrpResult,
+};
+use async_channel::{Receiver, Sender};
+use bevy_platform::collections::HashMap;
+use bevy_tasks::{IoTaskPool, Task};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// The number of results of a watching request that can be waiting to be sent to the client.
+const WATCH_CHANNEL_SIZE: usize = 8;
+
+/// `rpc.unwatch`: Stops a watching request running on the same connection.
+#[derive(Debug, Deserialize)]
+struct UnwatchParams {
+    /// The `id` of the watching request to stop.
+    id: Value,
+}
+
+/// Processes the messages received on a connection until `incoming` is closed, sending the
+/// responses to `outgoing`.
+///
+/// Requests are sent to the app with `credentials`, until the client authenticates again.
+/// The watching requests of the connection are stopped when this returns.
+pub(crate) async fn serve_connection(
+    incoming: Receiver<String>,
+    outgoing: Sender<String>,
+    request_sender: Sender<BrpMessage>,
+    mut credentials: BrpCredentials,
+) {
+    // Dropping a task cancels it, which closes the channel of its watching request.
+    let mut watches: HashMap<String, Task<()>> = HashMap::default();
+
+    while let Ok(message) = incoming.recv().await {
+        watches.retain(|_, task| !task.is_finished());
+
+        let batch = match serde_json::from_str::<BrpBatch>(&message) {
+            Ok(batch) => batch,
+            Err(err) => {
+                send_response(
+                    &outgoing,
+                    &BrpResponse::new(
+                        None,
+                        Err(BrpError {
+                            code: error_codes::PARSE_ERROR,
+                            message: err.to_string(),
+                            data: None,
+                        }),
+                    ),
+                )
+                .await;
+                continue;
+            }
+        };
+
+        let request = match batch {
+            BrpBatch::Single(request) => request,
+            BrpBatch::Batch(requests) => {
+                IoTaskPool::get()
+                    .spawn(process_batch(
+                        requests,
+                        outgoing.clone(),
+                        request_sender.clone(),
+                        credentials.clone(),
+                    ))
+                    .detach();
+                continue;
+            }
+        };
+
+        let request = match BrpRequest::from_json(request) {
+            Ok(request) => request,
+            Err(response) => {
+                send_response(&outgoing, &response).await;
+                continue;
+            }
+        };
+
+        if request.method == RPC_AUTHENTICATE_METHOD {
+            // Processed before the next messages, so that they use the new credentials.
+            let new_credentials = request
+                .params
+                .clone()
+                .and_then(|params| serde_json::from_value(params).ok());
+            let response = process_request(request, &request_sender, credentials.clone()).await;
+            if let (BrpPayload::Result(_), Some(new_credentials)) =
+                (&response.payload, new_credentials)
+            {
+                credentials = new_credentials;
+            }
+            send_response(&outgoing, &response).await;
+        } else if request.method == RPC_UNWATCH_METHOD {
+            let result = unwatch(&mut watches, request.params);
+            send_response(&outgoing, &BrpResponse::new(request.id, result)).await;
+        } else if request.is_watching() {
+            let key = watch_key(request.id.as_ref());
+            if watches.contains_key(&key) {
+                let error = BrpError {
+                    code: error_codes::INVALID_REQUEST,
+                    message: format!("A watching request with the id {key} is already running"),
+                    data: None,
+                };
+                send_response(&outgoing, &BrpResponse::new(request.id, Err(error))).await;
+                continue;
+            }
+
+            let (result_sender, result_receiver) = async_channel::bounded(WATCH_CHANNEL_SIZE);
+            let _ = request_sender
+                .send(BrpMessage {
+                    method: request.method,
+                    params: request.params,
+                    sender: result_sender,
+                    credentials: credentials.clone(),
+                })
+                .await;
+            let task = IoTaskPool::get().spawn(forward_watch_results(
+                request.id,
+                result_receiver,
+                outgoing.clone(),
+            ));
+            watches.insert(key, task);
+        } else {
+            IoTaskPool::get()
+                .spawn({
+                    let outgoing = outgoing.clone();
+                    let request_sender = request_sender.clone();
+                    let credentials = credentials.clone();
+                    async move {
+                        let response = process_request(request, &request_sender, credentials).await;
+                        send_response(&outgoing, &response).await;
+                    }
+                })
+                .detach();
+        }
+    }
+}
+
+/// Processes a batch of requests, sending all the responses in a single message.
+async fn process_batch(
+    requests: Vec<Value>,
+    outgoing: Sender<String>,
+    request_sender: Sender<BrpMessage>,
+    credentials: BrpCredentials,
+) {
+    let mut responses = Vec::new();
+    for request in requests {
+        let response = match BrpRequest::from_json(request) {
+            Ok(request) if request.is_watching() || request.method == RPC_UNWATCH_METHOD => {
+                BrpResponse::new(
+                    request.id,
+                    Err(BrpError {
+                        code: error_codes::INVALID_REQUEST,
+                        message: "Watching can not be used in batch requests".to_string(),
+                        data: None,
+                    }),
+                )
+            }
+            Ok(request) => process_request(request, &request_sender, credentials.clone()).await,
+            Err(response) => response,
+        };
+        responses.push(response);
+    }
+    if let Ok(serialized) = serde_json::to_string(&responses) {
+        let _ = outgoing.send(serialized).await;
+    }
+}
+
+/// Sends a non-watching request to the app and waits for its result.
+async fn process_request(
+    request: BrpRequest,
+    request_sender: &Sender<BrpMessage>,
+    credentials: BrpCredentials,
+) -> BrpResponse {
+    let (result_sender, result_receiver) = async_channel::bounded(1);
+    let _ = request_sender
+        .send(BrpMessage {
+            method: request.method,
+            params: request.params,
+            sender: result_sender,
+            credentials,
+        })
+        .await;
+    let result = result_receiver
+        .recv()
+        .await
+        .map_err(|_| BrpError::internal("The app stopped processing the request"));
+    BrpResponse::new(request.id, result.and_then(|result| result))
+}
+
+/// Pushes the results of a watching request to the client until the request stops.
+async fn forward_watch_results(
+    id: Option<Value>,
+    results: Receiver<BrpResult>,
+    outgoing: Sender<String>,
+) {
+    while let Ok(result) = results.recv().await {
+        let Ok(serialized) = serde_json::to_string(&BrpResponse::new(id.clone(), result)) else {
+            continue;
+        };
+        if outgoing.send(serialized).await.is_err() {
+            break;
+        }
+    }
+}
+
+/// Stops the watching request identified by the `id` in the `params` of a `rpc.unwatch` request.
+fn unwatch(watches: &mut HashMap<String, Task<()>>, params: Option<Value>) -> BrpResult {
+    let params: UnwatchParams = params
+        .ok_or_else(|| BrpError {
+            code: error_codes::INVALID_PARAMS,
+            message: String::from("Params not provided"),
+            data: None,
+        })
+        .and_then(|params| {
+            serde_json::from_value(params).map_err(|err| BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: err.to_string(),
+                data: None,
+            })
+        })?;
+
+    let key = watch_key(Some(&params.id));
+    match watches.remove(&key) {
+        Some(_) => Ok(Value::Null),
+        None => Err(BrpError {
+            code: error_codes::INVALID_PARAMS,
+            message: format!("No watching request with the id {key} is running"),
+            data: None,
+        }),
+    }
+}
+
+/// Returns the key identifying a watching request on its connection.
+fn watch_key(id: Option<&Value>) -> String {
+    id.map(Value::to_string)
+        .unwrap_or_else(|| String::from("null"))
+}
+
+async fn send_response(outgoing: &Sender<String>, response: &BrpResponse) {
+    if let Ok(serialized) = serde_json::to_string(response) {
+        let _ = outgoing.send(serialized).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_tasks::{
+        tick_global_task_pools_on_main_thread, AsyncComputeTaskPool, ComputeTaskPool, TaskPool,
+    };
+    use serde_json::json;
+
+    /// A connection served on the task pools, which are ticked on the test thread.
+    struct TestConnection {
+        incoming: Sender<String>,
+        outgoing: Receiver<String>,
+        requests: Receiver<BrpMessage>,
+        _task: Task<()>,
+    }
+
+    impl TestConnection {
+        fn new() -> Self {
+            ComputeTaskPool::get_or_init(TaskPool::new);
+            AsyncComputeTaskPool::get_or_init(TaskPool::new);
+            IoTaskPool::get_or_init(TaskPool::new);
+            let (incoming_sender, incoming) = async_channel::unbounded();
+            let (outgoing, outgoing_receiver) = async_channel::unbounded();
+            let (request_sender, requests) = async_channel::unbounded();
+            Self {
+                incoming: incoming_sender,
+                outgoing: outgoing_receiver,
+                requests,
+                _task: IoTaskPool::get().spawn(serve_connection(
+                    incoming,
+                    outgoing,
+                    request_sender,
+                    BrpCredentials::default(),
+                )),
+            }
+        }
+
+        fn send(&self, message: Value) {
+            self.incoming.try_send(message.to_string()).unwrap();
+        }
+
+        fn request(&self) -> BrpMessage {
+            wait(|| self.requests.try_recv().ok())
+        }
+
+        fn response(&self) -> Value {
+            serde_json::from_str(&wait(|| self.outgoing.try_recv().ok())).unwrap()
+        }
+    }
+
+    /// Ticks the task pools until `poll` returns a value.
+    fn wait<T>(mut poll: impl FnMut() -> Option<T>) -> T {
+        loop {
+            tick_global_task_pools_on_main_thread();
+            if let Some(value) = poll() {
+                return value;
+            }
+        }
+    }
+
+    #[test]
+    fn concurrent_watches() {
+        let connection = TestConnection::new();
+
+        let watch = |id: u32| json!({"jsonrpc": "2.0", "id": id, "method": "bevy/get+watch"});
+        connection.send(watch(1));
+        let first = connection.request();
+        connection.send(watch(2));
+        let second = connection.request();
+
+        // Both watches push their results on the same connection.
+        second.sender.try_send(Ok(json!("b"))).unwrap();
+        assert_eq!(
+            connection.response(),
+            json!({"jsonrpc": "2.0", "id": 2, "result": "b"})
+        );
+        first.sender.try_send(Ok(json!("a"))).unwrap();
+        assert_eq!(
+            connection.response(),
+            json!({"jsonrpc": "2.0", "id": 1, "result": "a"})
+        );
+
+        // Stopping a watch closes its channel, which stops the watching request in the app.
+        connection
+            .send(json!({"jsonrpc": "2.0", "id": 3, "method": "rpc.unwatch", "params": {"id": 1}}));
+        assert_eq!(
+            connection.response(),
+            json!({"jsonrpc": "2.0", "id": 3, "result": null})
+        );
+        wait(|| first.sender.is_closed().then_some(()));
+        assert!(!second.sender.is_closed());
+
+        // Closing the connection stops the remaining watches.
+        connection.incoming.close();
+        wait(|| second.sender.is_closed().then_some(()));
+    }
+
+    #[test]
+    fn authenticate() {
+        let connection = TestConnection::new();
+        let get = json!({"jsonrpc": "2.0", "id": 1, "method": "bevy/get"});
+
+        // Rejected credentials are not used.
+        connection.send(json!({"jsonrpc": "2.0", "id": 2, "method": "rpc.authenticate", "params": {"token": "wrong"}}));
+        let request = connection.request();
+        assert_eq!(request.credentials, BrpCredentials::default());
+        request
+            .sender
+            .try_send(Err(BrpError::access_denied("Invalid credentials")))
+            .unwrap();
+        connection.response();
+        connection.send(get.clone());
+        assert_eq!(connection.request().credentials, BrpCredentials::default());
+
+        // Accepted credentials are used for the following requests.
+        connection.send(json!({"jsonrpc": "2.0", "id": 3, "method": "rpc.authenticate", "params": {"token": "secret"}}));
+        connection
+            .request()
+            .sender
+            .try_send(Ok(Value::Null))
+            .unwrap();
+        connection.response();
+        connection.send(get);
+        assert_eq!(
+            connection.request().credentials,
+            BrpCredentials::bearer("secret")
+        );
+    }
+
+    #[test]
+    fn invalid_messages() {
+        let connection = TestConnection::new();
+
+        connection.incoming.try_send("{".to_string()).unwrap();
+        assert_eq!(
+            connection.response()["error"]["code"],
+            json!(error_codes::PARSE_ERROR)
+        );
+
+        connection
+            .send(json!({"jsonrpc": "2.0", "id": 1, "method": "rpc.unwatch", "params": {"id": 7}}));
+        assert_eq!(
+            connection.response()["error"]["code"],
+            json!(error_codes::INVALID_PARAMS)
+        );
+
+        connection.send(json!([{"jsonrpc": "2.0", "id": 1, "method": "bevy/list+watch"}]));
+        assert_eq!(
+            connection.response()[0]["error"]["code"],
+            json!(error_codes::INVALID_REQUEST)
+        );
+    }
+}
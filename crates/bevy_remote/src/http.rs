@@ -5,11 +5,15 @@
 //!
 //! Clients are expected to `POST` JSON requests to the root URL; see the `client`
 //! example for a trivial example of use.
+//!
+//! Clients can present their credentials with an `Authorization: Bearer <token>` header in each
+//! request. See the [`auth`](crate::auth) module for details.
 
 #![cfg(not(target_family = "wasm"))]
 
 use crate::{
-    error_codes, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpResult, BrpSender,
+    auth::BrpCredentials, error_codes, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse,
+    BrpResult, BrpSender,
 };
 use anyhow::Result as AnyhowResult;
 use async_channel::{Receiver, Sender};
@@ -269,12 +273,18 @@ async fn process_request_batch(
     request_sender: &Sender<BrpMessage>,
     headers: &Headers,
 ) -> AnyhowResult<Response<BrpHttpBody>> {
+    let credentials = request
+        .headers()
+        .get(hyper::header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .map(BrpCredentials::from_authorization_header)
+        .unwrap_or_default();
     let batch_bytes = request.into_body().collect().await?.to_bytes();
     let batch: Result<BrpBatch, _> = serde_json::from_slice(&batch_bytes);
 
     let result = match batch {
         Ok(BrpBatch::Single(request)) => {
-            let response = process_single_request(request, request_sender).await?;
+            let response = process_single_request(request, request_sender, credentials).await?;
             match response {
                 BrpHttpResponse::Complete(res) => {
                     BrpHttpResponse::Complete(serde_json::to_string(&res)?)
@@ -286,7 +296,8 @@ async fn process_request_batch(
             let mut responses = Vec::new();
 
             for request in requests {
-                let response = process_single_request(request, request_sender).await?;
+                let response =
+                    process_single_request(request, request_sender, credentials.clone()).await?;
                 match response {
                     BrpHttpResponse::Complete(res) => responses.push(res),
                     BrpHttpResponse::Stream(BrpStream { id, .. }) => {
@@ -349,36 +360,14 @@ async fn process_request_batch(
 async fn process_single_request(
     request: Value,
     request_sender: &Sender<BrpMessage>,
+    credentials: BrpCredentials,
 ) -> AnyhowResult<BrpHttpResponse<BrpResponse, BrpStream>> {
-    // Reach in and get the request ID early so that we can report it even when parsing fails.
-    let id = request.as_object().and_then(|map| map.get("id")).cloned();
-
-    let request: BrpRequest = match serde_json::from_value(request) {
-        Ok(v) => v,
-        Err(err) => {
-            return Ok(BrpHttpResponse::Complete(BrpResponse::new(
-                id,
-                Err(BrpError {
-                    code: error_codes::INVALID_REQUEST,
-                    message: err.to_string(),
-                    data: None,
-                }),
-            )));
-        }
+    let request = match BrpRequest::from_json(request) {
+        Ok(request) => request,
+        Err(response) => return Ok(BrpHttpResponse::Complete(response)),
     };
 
-    if request.jsonrpc != "2.0" {
-        return Ok(BrpHttpResponse::Complete(BrpResponse::new(
-            id,
-            Err(BrpError {
-                code: error_codes::INVALID_REQUEST,
-                message: String::from("JSON-RPC request requires `\"jsonrpc\": \"2.0\"`"),
-                data: None,
-            }),
-        )));
-    }
-
-    let watch = request.method.contains("+watch");
+    let watch = request.is_watching();
     let size = if watch { 8 } else { 1 };
     let (result_sender, result_receiver) = async_channel::bounded(size);
 
@@ -387,6 +376,7 @@ async fn process_single_request(
             method: request.method,
             params: request.params,
             sender: result_sender,
+            credentials,
         })
         .await;
 
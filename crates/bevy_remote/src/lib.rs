@@ -6,6 +6,16 @@
 //! over HTTP. These *remote clients* can inspect and alter the state of the
 //! entity-component system.
 //!
+//! The available transports are:
+//! - HTTP, with the `RemoteHttpPlugin` (`http` feature).
+//! - WebSocket, with the `RemoteWebSocketPlugin` (`websocket` feature).
+//! - The standard input and output of the process, with the `RemoteStdioPlugin` (`stdio` feature).
+//!
+//! Custom transports can send requests to the app through the [`BrpSender`] resource.
+//!
+//! With the `client` feature, Rust programs can call the methods of an app with the typed
+//! `BrpClient` of the `client` module.
+//!
 //! The Bevy Remote Protocol is based on the JSON-RPC 2.0 protocol.
 //!
 //! ## Request objects
@@ -96,6 +106,23 @@
 //!
 //! * `data` is an optional field of arbitrary type containing additional information about the error.
 //!
+//! ## Access control
+//!
+//! By default, every client can call every method. Clients can be authenticated, for example with
+//! a bearer token, and restricted to some methods and component, resource or event types using
+//! [`RemotePlugin::with_authenticator`] and [`RemotePlugin::with_permissions`]. Denied requests
+//! fail with the [`ACCESS_DENIED`](error_codes::ACCESS_DENIED) error code, and denied types are
+//! hidden from the listings of the built-in methods. Custom methods accessing values by type should
+//! check the [`BrpRequestPermissions`](auth::BrpRequestPermissions) of the client.
+//!
+//! ```
+//! # use bevy_remote::{auth::BrpPermissions, RemotePlugin};
+//! // Only allow the methods that don't modify the world.
+//! let plugin = RemotePlugin::default().with_permissions(BrpPermissions::read_only());
+//! ```
+//!
+//! See the [`auth`] module for details.
+//!
 //! ## Built-in methods
 //!
 //! The Bevy Remote Protocol includes a number of built-in methods for accessing and modifying data
@@ -456,6 +483,113 @@
 //!
 //! `result`: An array of [fully-qualified type names] of registered resource types.
 //!
+//! ### `bevy/send_event`
+//!
+//! Send a buffered event, to be read by the `EventReader`s of the app. The event must be registered
+//! with [`RemoteApp::register_remote_event`](callable::RemoteApp::register_remote_event).
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to send.
+//! - `value` (optional): The serialized value of the event. It may be omitted for unit structs.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/trigger_event`
+//!
+//! Trigger an event, running its observers. The event must be registered with
+//! [`RemoteApp::register_remote_trigger`](callable::RemoteApp::register_remote_trigger), or
+//! [`RemoteApp::register_remote_entity_trigger`](callable::RemoteApp::register_remote_entity_trigger)
+//! to target entities.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to trigger.
+//! - `value` (optional): The serialized value of the event. It may be omitted for unit structs.
+//! - `targets` (optional): An array of [entity ID]s targeted by the event.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/run_system`
+//!
+//! Run a one-shot system registered with
+//! [`RemoteApp::register_remote_system`](callable::RemoteApp::register_remote_system).
+//!
+//! `params`:
+//! - `name`: The name the system was registered with.
+//! - `input` (optional): The serialized input of the system. It may be omitted for systems
+//!   without input.
+//!
+//! Clients allowed to call this method can run every registered system, unless their permissions
+//! name the systems they can run with
+//! [`BrpPermissions::with_systems`](auth::BrpPermissions::with_systems).
+//!
+//! `result`: The serialized output of the system, or null for systems without output.
+//!
+//! ### `bevy/asset/dependency_graph`
+//!
+//! Inspect the assets tracked by the `AssetServer` and the dependencies between them. This is
+//! only available when the `bevy_asset` feature is enabled.
+//!
+//! `params` (optional):
+//! - `roots` (optional): An array of asset paths. If provided, only these assets and their
+//!   recursive dependencies are returned.
+//!
+//! `result`:
+//! - `nodes`: An array of assets, sorted by path, each containing:
+//!   - `id`: The ID of the asset.
+//!   - `path`: The path of the asset, or null.
+//!   - `load_state`, `dependency_load_state`, `recursive_dependency_load_state`: One of
+//!     `NotLoaded`, `Loading`, `Loaded` or `Failed`.
+//!   - `error` (optional): The error the asset failed to load with.
+//!   - `loader`: The type name of the loader used to load the asset, or null.
+//!   - `dependencies`: The IDs of the assets this asset depends on.
+//!   - `dependants`: The IDs of the assets that depend on this asset.
+//!   - `loader_dependencies` (optional): The paths read by the loader while loading the asset.
+//!
+//! ### `bevy/asset/load_state`
+//!
+//! Get the load states of assets tracked by the `AssetServer`. This is only available when the
+//! `bevy_asset` feature is enabled.
+//!
+//! `params`:
+//! - `paths`: An array of asset paths.
+//!
+//! `result`:
+//! - `assets`: An array with the load states of each asset, in the order of `paths`, containing:
+//!   - `path`: The path of the asset.
+//!   - `load_state`, `dependency_load_state`, `recursive_dependency_load_state`: One of
+//!     `NotLoaded`, `Loading`, `Loaded` or `Failed`. Assets that aren't tracked are `NotLoaded`.
+//!   - `error` (optional): The error the asset failed to load with.
+//!
+//! ### `bevy/scene/export`
+//!
+//! Serialize entities, and optionally resources, into the RON format of a `DynamicScene`. This is
+//! only available when the `bevy_scene` feature is enabled.
+//!
+//! `params` (optional):
+//! - `entities` (optional): An array of [entity ID]s to export. If omitted, every entity of the
+//!   world is exported.
+//! - `component_filter` (optional): The components to export, as an object with the arrays of
+//!   [fully-qualified type names] `allow` and `deny`. If omitted, all components are exported.
+//! - `resource_filter` (optional): The resources to export, in the same format as
+//!   `component_filter`. If omitted, no resources are exported.
+//!
+//! `result`:
+//! - `scene`: The exported scene.
+//!
+//! ### `bevy/scene/spawn`
+//!
+//! Spawn a scene as the children of a new `DynamicSceneRoot` entity, which happens once the scene
+//! is loaded. This is only available when the `bevy_scene` feature is enabled.
+//!
+//! `params`:
+//! - `scene` (optional): A scene in the RON format of a `DynamicScene`.
+//! - `path` (optional): The asset path of a scene to load.
+//!
+//! Exactly one of `scene` and `path` must be provided.
+//!
+//! `result`:
+//! - `entity`: The ID of the root entity of the scene.
+//!
 //! ## Custom methods
 //!
 //! In addition to the provided methods, the Bevy Remote Protocol can be extended to include custom
@@ -501,7 +635,9 @@
 
 extern crate alloc;
 
+use alloc::sync::Arc;
 use async_channel::{Receiver, Sender};
+use auth::{BrpAuthenticator, BrpCredentials, BrpPermissions, BrpRequestPermissions, RemoteAccess};
 use bevy_app::{prelude::*, MainScheduleOrder};
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
@@ -517,10 +653,20 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::RwLock;
 
+pub mod auth;
 pub mod builtin_methods;
+pub mod callable;
+#[cfg(feature = "client")]
+pub mod client;
+#[cfg(any(feature = "websocket", feature = "stdio"))]
+mod connection;
 #[cfg(feature = "http")]
 pub mod http;
 pub mod schemas;
+#[cfg(feature = "stdio")]
+pub mod stdio;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 const CHANNEL_SIZE: usize = 16;
 
@@ -533,6 +679,8 @@ const CHANNEL_SIZE: usize = 16;
 pub struct RemotePlugin {
     /// The verbs that the server will recognize and respond to.
     methods: RwLock<Vec<(String, RemoteMethodHandler)>>,
+    /// The authentication and permissions of the clients.
+    access: RemoteAccess,
 }
 
 impl RemotePlugin {
@@ -541,6 +689,7 @@ impl RemotePlugin {
     fn empty() -> Self {
         Self {
             methods: RwLock::new(vec![]),
+            access: RemoteAccess::default(),
         }
     }
 
@@ -571,11 +720,31 @@ impl RemotePlugin {
         ));
         self
     }
+
+    /// Authenticate the clients with the given `authenticator`, which decides the permissions of
+    /// each client from its credentials.
+    ///
+    /// Clients whose credentials are rejected can't make any request.
+    #[must_use]
+    pub fn with_authenticator(mut self, authenticator: impl BrpAuthenticator) -> Self {
+        self.access.authenticator = Some(Arc::new(authenticator));
+        self
+    }
+
+    /// Set the permissions of the clients when no authenticator is set.
+    ///
+    /// For example, [`BrpPermissions::read_only`] only allows the methods that don't modify the
+    /// world.
+    #[must_use]
+    pub fn with_permissions(mut self, permissions: BrpPermissions) -> Self {
+        self.access.default_permissions = permissions;
+        self
+    }
 }
 
 impl Default for RemotePlugin {
     fn default() -> Self {
-        Self::empty()
+        let plugin = Self::empty()
             .with_method(
                 builtin_methods::BRP_GET_METHOD,
                 builtin_methods::process_remote_get_request,
@@ -616,6 +785,10 @@ impl Default for RemotePlugin {
                 builtin_methods::RPC_DISCOVER_METHOD,
                 builtin_methods::process_remote_list_methods_request,
             )
+            .with_method(
+                auth::RPC_AUTHENTICATE_METHOD,
+                auth::process_remote_authenticate_request,
+            )
             .with_watching_method(
                 builtin_methods::BRP_GET_AND_WATCH_METHOD,
                 builtin_methods::process_remote_get_watching_request,
@@ -648,6 +821,39 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_REGISTRY_SCHEMA_METHOD,
                 builtin_methods::export_registry_types,
             )
+            .with_method(
+                builtin_methods::BRP_SEND_EVENT_METHOD,
+                builtin_methods::process_remote_send_event_request,
+            )
+            .with_method(
+                builtin_methods::BRP_TRIGGER_EVENT_METHOD,
+                builtin_methods::process_remote_trigger_event_request,
+            )
+            .with_method(
+                builtin_methods::BRP_RUN_SYSTEM_METHOD,
+                builtin_methods::process_remote_run_system_request,
+            );
+        #[cfg(feature = "bevy_asset")]
+        let plugin = plugin
+            .with_method(
+                builtin_methods::BRP_ASSET_DEPENDENCY_GRAPH_METHOD,
+                builtin_methods::process_remote_asset_dependency_graph_request,
+            )
+            .with_method(
+                builtin_methods::BRP_ASSET_LOAD_STATE_METHOD,
+                builtin_methods::process_remote_asset_load_state_request,
+            );
+        #[cfg(feature = "bevy_scene")]
+        let plugin = plugin
+            .with_method(
+                builtin_methods::BRP_EXPORT_SCENE_METHOD,
+                builtin_methods::process_remote_export_scene_request,
+            )
+            .with_method(
+                builtin_methods::BRP_SPAWN_SCENE_METHOD,
+                builtin_methods::process_remote_spawn_scene_request,
+            );
+        plugin
     }
 }
 
@@ -676,8 +882,11 @@ impl Plugin for RemotePlugin {
             .insert_after(Last, RemoteLast);
 
         app.insert_resource(remote_methods)
+            .insert_resource(self.access.clone())
             .init_resource::<schemas::SchemaTypesMetadata>()
             .init_resource::<RemoteWatchingRequests>()
+            .init_resource::<callable::RemoteEvents>()
+            .init_resource::<callable::RemoteCallableSystems>()
             .add_systems(PreStartup, setup_mailbox_channel)
             .configure_sets(
                 RemoteLast,
@@ -785,9 +994,10 @@ impl RemoteMethods {
     }
 }
 
-/// Holds the [`BrpMessage`]'s of all ongoing watching requests along with their handlers.
+/// Holds the [`BrpMessage`]'s of all ongoing watching requests along with their handlers and the
+/// permissions of their clients.
 #[derive(Debug, Resource, Default)]
-pub struct RemoteWatchingRequests(Vec<(BrpMessage, RemoteWatchingMethodSystemId)>);
+pub struct RemoteWatchingRequests(Vec<(BrpMessage, RemoteWatchingMethodSystemId, BrpPermissions)>);
 
 /// A single request from a Bevy Remote Protocol client to the server,
 /// serialized in JSON.
@@ -848,6 +1058,46 @@ pub struct BrpRequest {
     pub params: Option<Value>,
 }
 
+impl BrpRequest {
+    /// Parses a request sent by a client.
+    ///
+    /// If the request is invalid, returns the error response to send back to the client.
+    pub fn from_json(request: Value) -> Result<Self, BrpResponse> {
+        // Reach in and get the request ID early so that we can report it even when parsing fails.
+        let id = request.as_object().and_then(|map| map.get("id")).cloned();
+
+        let request: BrpRequest = serde_json::from_value(request).map_err(|err| {
+            BrpResponse::new(
+                id.clone(),
+                Err(BrpError {
+                    code: error_codes::INVALID_REQUEST,
+                    message: err.to_string(),
+                    data: None,
+                }),
+            )
+        })?;
+
+        if request.jsonrpc != "2.0" {
+            return Err(BrpResponse::new(
+                id,
+                Err(BrpError {
+                    code: error_codes::INVALID_REQUEST,
+                    message: String::from("JSON-RPC request requires `\"jsonrpc\": \"2.0\"`"),
+                    data: None,
+                }),
+            ));
+        }
+
+        Ok(request)
+    }
+
+    /// Returns whether the request is for a watching method, whose handler keeps sending results
+    /// until the client disconnects.
+    pub fn is_watching(&self) -> bool {
+        self.method.contains("+watch")
+    }
+}
+
 /// A response according to BRP.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BrpResponse {
@@ -966,6 +1216,16 @@ impl BrpError {
         }
     }
 
+    /// No asset with the given path is tracked by the asset server.
+    #[must_use]
+    pub fn asset_not_found(path: &str) -> Self {
+        Self {
+            code: error_codes::ASSET_NOT_FOUND,
+            message: format!("Asset `{path}` not found"),
+            data: None,
+        }
+    }
+
     /// Attempt to reparent an entity to itself.
     #[must_use]
     pub fn self_reparent(entity: Entity) -> Self {
@@ -975,6 +1235,46 @@ impl BrpError {
             data: None,
         }
     }
+
+    /// An arbitrary scene error. Possibly related to reflection or serialization.
+    #[must_use]
+    pub fn scene_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::SCENE_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// An arbitrary event error. Possibly related to reflection.
+    #[must_use]
+    pub fn event_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::EVENT_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// An arbitrary system error. Possibly related to reflection.
+    #[must_use]
+    pub fn system_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::SYSTEM_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// The client is not allowed to make the request.
+    #[must_use]
+    pub fn access_denied(message: impl Into<String>) -> Self {
+        Self {
+            code: error_codes::ACCESS_DENIED,
+            message: message.into(),
+            data: None,
+        }
+    }
 }
 
 /// Error codes used by BRP.
@@ -1016,6 +1316,21 @@ pub mod error_codes {
 
     /// Could not find resource in the world.
     pub const RESOURCE_NOT_PRESENT: i16 = -23502;
+
+    /// Could not find the asset.
+    pub const ASSET_NOT_FOUND: i16 = -23601;
+
+    /// Could not serialize, deserialize or spawn the scene.
+    pub const SCENE_ERROR: i16 = -23602;
+
+    /// The client is not allowed to make the request.
+    pub const ACCESS_DENIED: i16 = -23701;
+
+    /// Could not reflect, find, send or trigger the event.
+    pub const EVENT_ERROR: i16 = -23801;
+
+    /// Could not find or run the system.
+    pub const SYSTEM_ERROR: i16 = -23901;
 }
 
 /// The result of a request.
@@ -1048,6 +1363,9 @@ pub struct BrpMessage {
     ///
     /// The value sent here is serialized and sent back to the client.
     pub sender: Sender<BrpResult>,
+
+    /// The credentials presented by the client, used to check its permissions.
+    pub credentials: BrpCredentials,
 }
 
 /// A resource holding the matching sender for the [`BrpReceiver`]'s receiver.
@@ -1079,6 +1397,16 @@ fn process_remote_requests(world: &mut World) {
     }
 
     while let Ok(message) = world.resource_mut::<BrpReceiver>().try_recv() {
+        // Check that the client is allowed to call this method. The types are checked by the
+        // handler, using the permissions of the client.
+        let permissions = match world.resource_mut::<RemoteAccess>().check(&message) {
+            Ok(permissions) => permissions,
+            Err(error) => {
+                let _ = message.sender.force_send(Err(error));
+                continue;
+            }
+        };
+
         // Fetch the handler for the method. If there's no such handler
         // registered, return an error.
         let Some(&handler) = world.resource::<RemoteMethods>().get(&message.method) else {
@@ -1092,7 +1420,9 @@ fn process_remote_requests(world: &mut World) {
 
         match handler {
             RemoteMethodSystemId::Instant(id) => {
-                let result = match world.run_system_with(id, message.params) {
+                let result = match run_with_permissions(world, &permissions, |world| {
+                    world.run_system_with(id, message.params)
+                }) {
                     Ok(result) => result,
                     Err(error) => {
                         let _ = message.sender.force_send(Err(BrpError {
@@ -1110,7 +1440,7 @@ fn process_remote_requests(world: &mut World) {
                 world
                     .resource_mut::<RemoteWatchingRequests>()
                     .0
-                    .push((message, id));
+                    .push((message, id, permissions));
             }
         }
     }
@@ -1120,8 +1450,10 @@ fn process_remote_requests(world: &mut World) {
 /// and handles it if so.
 fn process_ongoing_watching_requests(world: &mut World) {
     world.resource_scope::<RemoteWatchingRequests, ()>(|world, requests| {
-        for (message, system_id) in requests.0.iter() {
-            let handler_result = process_single_ongoing_watching_request(world, message, system_id);
+        for (message, system_id, permissions) in requests.0.iter() {
+            let handler_result = run_with_permissions(world, permissions, |world| {
+                process_single_ongoing_watching_request(world, message, system_id)
+            });
             let sender_result = match handler_result {
                 Ok(Some(value)) => message.sender.try_send(Ok(value)),
                 Err(err) => message.sender.try_send(Err(err)),
@@ -1150,9 +1482,22 @@ fn process_single_ongoing_watching_request(
         })?
 }
 
+/// Runs the handler of a request, with the permissions of its client in the
+/// [`BrpRequestPermissions`] resource.
+fn run_with_permissions<T>(
+    world: &mut World,
+    permissions: &BrpPermissions,
+    run: impl FnOnce(&mut World) -> T,
+) -> T {
+    world.insert_resource(BrpRequestPermissions(permissions.clone()));
+    let output = run(world);
+    world.remove_resource::<BrpRequestPermissions>();
+    output
+}
+
 fn remove_closed_watching_requests(mut requests: ResMut<RemoteWatchingRequests>) {
     for i in (0..requests.0.len()).rev() {
-        let Some((message, _)) = requests.0.get(i) else {
+        let Some((message, ..)) = requests.0.get(i) else {
             unreachable!()
         };
 
@@ -0,0 +1,91 @@
+//! The BRP transport using JSON-RPC over the standard input and output of the process.
+//!
+//! Adding the [`RemoteStdioPlugin`] to your [`App`] makes Bevy read requests from its standard
+//! input and write the responses to its standard output, one JSON message per line. This is
+//! convenient for tools launching the app as a child process, like editors, which don't need to
+//! open a port to talk to it.
+//!
+//! Requests are processed like with the [WebSocket transport](crate::websocket): any number of
+//! watching requests can run concurrently, and responses may arrive in a different order than the
+//! requests.
+//!
+//! Nothing else may be written to the standard output while this transport is in use. Bevy's
+//! `LogPlugin` writes to the standard error, so logs don't interfere with it.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{auth::BrpCredentials, connection::serve_connection, BrpSender};
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::system::Res;
+use bevy_log::warn;
+use bevy_tasks::IoTaskPool;
+use std::{
+    io::{stdin, stdout, BufRead, Write},
+    thread,
+};
+
+/// The number of messages that can be waiting to be written or processed.
+const CHANNEL_SIZE: usize = 16;
+
+/// Add this plugin to your [`App`] to allow the process that launched the app to inspect and modify
+/// entities through the standard input and output. It requires the
+/// [`RemotePlugin`](super::RemotePlugin).
+///
+/// This BRP transport cannot be used when targeting WASM.
+#[derive(Default)]
+pub struct RemoteStdioPlugin;
+
+impl Plugin for RemoteStdioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, start_stdio_transport);
+    }
+}
+
+/// A system that starts reading requests from the standard input.
+fn start_stdio_transport(request_sender: Res<BrpSender>) {
+    let (incoming_sender, incoming) = async_channel::bounded::<String>(CHANNEL_SIZE);
+    let (outgoing, outgoing_receiver) = async_channel::bounded::<String>(CHANNEL_SIZE);
+
+    // The standard input and output are blocking, so they are handled on dedicated threads.
+    let reader = thread::Builder::new()
+        .name("BRP stdin".to_string())
+        .spawn(move || {
+            for line in stdin().lock().lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if line.trim().is_empty() {
+                    continue;
+                }
+                if incoming_sender.send_blocking(line).is_err() {
+                    break;
+                }
+            }
+        });
+    let writer = thread::Builder::new()
+        .name("BRP stdout".to_string())
+        .spawn(move || {
+            while let Ok(message) = outgoing_receiver.recv_blocking() {
+                let mut stdout = stdout().lock();
+                if writeln!(stdout, "{message}")
+                    .and_then(|()| stdout.flush())
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        });
+    if let Err(err) = reader.and(writer) {
+        warn!("Failed to start the BRP stdio transport: {err}");
+        return;
+    }
+
+    IoTaskPool::get()
+        .spawn(serve_connection(
+            incoming,
+            outgoing,
+            request_sender.clone(),
+            BrpCredentials::default(),
+        ))
+        .detach();
+}
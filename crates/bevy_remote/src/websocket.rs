@@ -0,0 +1,580 @@
+//! The BRP transport using JSON-RPC over WebSocket.
+//!
+//! Adding the [`RemoteWebSocketPlugin`] to your [`App`] causes Bevy to accept WebSocket
+//! connections (by default, on port 15703) while your app is running.
+//!
+//! Clients send requests, or batches of requests, as text messages, and receive the responses as
+//! text messages. Binary messages are rejected, closing the connection.
+//!
+//! Unlike with HTTP, a single connection can run any number of watching requests (`+watch`
+//! methods) concurrently: each result is pushed to the client as a response with the `id` of the
+//! watching request, until the client stops it with a
+//! [`rpc.unwatch`](crate::builtin_methods::RPC_UNWATCH_METHOD) request or closes the connection.
+//!
+//! For example, the following request stops the watching request with the `id` 3:
+//!
+//! ```json
+//! {
+//!     "jsonrpc": "2.0",
+//!     "method": "rpc.unwatch",
+//!     "id": 4,
+//!     "params": { "id": 3 }
+//! }
+//! ```
+//!
+//! Clients can present their credentials in the `Authorization` header of the handshake request,
+//! or with a [`rpc.authenticate`](crate::auth::RPC_AUTHENTICATE_METHOD) request once connected.
+//! See the [`auth`](crate::auth) module for details.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{auth::BrpCredentials, connection::serve_connection, BrpMessage, BrpSender};
+use anyhow::{bail, Result as AnyhowResult};
+use async_channel::{Receiver, Sender};
+use async_io::Async;
+use async_tungstenite::{
+    tungstenite::{
+        self,
+        handshake::derive_accept_key,
+        protocol::{frame::coding::CloseCode, CloseFrame, Role, WebSocketConfig},
+        Message,
+    },
+    WebSocketReceiver, WebSocketSender, WebSocketStream,
+};
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::{resource::Resource, system::Res};
+use bevy_log::{debug, error};
+use bevy_tasks::{
+    futures_lite::{future, AsyncRead, AsyncWrite, StreamExt},
+    IoTaskPool,
+};
+use core::{
+    convert::Infallible,
+    net::{IpAddr, Ipv4Addr},
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use http_body_util::Full;
+use hyper::{
+    body::{Bytes, Incoming},
+    header::{self, HeaderValue},
+    rt::ReadBuf,
+    server::conn::http1,
+    service,
+    upgrade::Upgraded,
+    Method, Request, Response, StatusCode,
+};
+use smol_hyper::rt::{FuturesIo, SmolTimer};
+use std::net::{TcpListener, TcpStream};
+
+/// The default port that Bevy will listen on for WebSocket connections.
+pub const DEFAULT_WEBSOCKET_PORT: u16 = 15703;
+
+/// The default host address that Bevy will use for its WebSocket server.
+pub const DEFAULT_WEBSOCKET_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+
+/// The largest message accepted from a client, in bytes.
+const MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+/// The size of the buffer of the handshake request, in bytes. Larger requests are refused.
+const MAX_HANDSHAKE_SIZE: usize = 16 * 1024;
+
+/// The number of messages that can be waiting to be sent to or processed for a client.
+const CHANNEL_SIZE: usize = 16;
+
+/// The only version of the WebSocket protocol supported by the server, from RFC 6455.
+const WEBSOCKET_VERSION: &str = "13";
+
+/// Add this plugin to your [`App`] to allow remote connections over WebSocket to inspect and modify
+/// entities. It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// This BRP transport cannot be used when targeting WASM.
+///
+/// The defaults are:
+/// - [`DEFAULT_WEBSOCKET_ADDR`] : 127.0.0.1.
+/// - [`DEFAULT_WEBSOCKET_PORT`] : 15703.
+pub struct RemoteWebSocketPlugin {
+    /// The address that Bevy will bind to.
+    address: IpAddr,
+    /// The port that Bevy will listen on.
+    port: u16,
+}
+
+impl Default for RemoteWebSocketPlugin {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_WEBSOCKET_ADDR,
+            port: DEFAULT_WEBSOCKET_PORT,
+        }
+    }
+}
+
+impl RemoteWebSocketPlugin {
+    /// Set the IP address that the server will use.
+    #[must_use]
+    pub fn with_address(mut self, address: impl Into<IpAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Set the remote port that the server will listen on.
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+}
+
+impl Plugin for RemoteWebSocketPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(WebSocketHost {
+            address: self.address,
+            port: self.port,
+        })
+        .add_systems(Startup, start_websocket_server);
+    }
+}
+
+/// A resource containing the address and port that the WebSocket server listens on.
+///
+/// Currently, changing this while the application is running has no effect; this merely
+/// reflects the values set during the setup of the [`RemoteWebSocketPlugin`].
+#[derive(Debug, Resource)]
+pub struct WebSocketHost {
+    /// The address that Bevy is bound to.
+    pub address: IpAddr,
+    /// The port that Bevy listens on.
+    pub port: u16,
+}
+
+/// A system that starts up the Bevy Remote Protocol WebSocket server.
+fn start_websocket_server(request_sender: Res<BrpSender>, host: Res<WebSocketHost>) {
+    let (address, port) = (host.address, host.port);
+    let request_sender = request_sender.clone();
+    IoTaskPool::get()
+        .spawn(async move {
+            if let Err(err) = server_main(address, port, request_sender).await {
+                error!("The BRP WebSocket server on {address}:{port} stopped: {err}");
+            }
+        })
+        .detach();
+}
+
+/// The Bevy Remote Protocol WebSocket server main loop.
+async fn server_main(
+    address: IpAddr,
+    port: u16,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    let listener = Async::<TcpListener>::bind((address, port))?;
+    loop {
+        let (client, _) = listener.accept().await?;
+
+        let request_sender = request_sender.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                if let Err(err) = handle_client(client, request_sender).await {
+                    debug!("Closed a BRP WebSocket connection: {err}");
+                }
+            })
+            .detach();
+    }
+}
+
+/// Serves the handshake of a client over HTTP, then upgrades the connection to WebSocket.
+async fn handle_client(
+    client: Async<TcpStream>,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    http1::Builder::new()
+        .timer(SmolTimer::new())
+        .max_buf_size(MAX_HANDSHAKE_SIZE)
+        .serve_connection(
+            FuturesIo::new(client),
+            service::service_fn(|request| {
+                let response = accept_handshake(request, request_sender.clone());
+                async move { Ok::<_, Infallible>(response) }
+            }),
+        )
+        .with_upgrades()
+        .await?;
+
+    Ok(())
+}
+
+/// Answers the handshake `request` of a client, and serves the WebSocket connection once the
+/// response is sent.
+fn accept_handshake(
+    mut request: Request<Incoming>,
+    request_sender: Sender<BrpMessage>,
+) -> Response<Full<Bytes>> {
+    let response = match handshake_response(&request) {
+        Ok(response) => response,
+        Err(err) => {
+            debug!("Refused a BRP WebSocket connection: {err}");
+            return err.response();
+        }
+    };
+    let credentials = request_credentials(&request);
+    let upgrade = hyper::upgrade::on(&mut request);
+    IoTaskPool::get()
+        .spawn(async move {
+            let result = match upgrade.await {
+                Ok(upgraded) => {
+                    serve_websocket(UpgradedIo(upgraded), request_sender, credentials).await
+                }
+                Err(err) => Err(err.into()),
+            };
+            if let Err(err) = result {
+                debug!("Closed a BRP WebSocket connection: {err}");
+            }
+        })
+        .detach();
+    response
+}
+
+/// Serves the BRP over the upgraded connection `io` of a client, until it is closed.
+async fn serve_websocket<S>(
+    io: S,
+    request_sender: Sender<BrpMessage>,
+    credentials: BrpCredentials,
+) -> AnyhowResult<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let config = WebSocketConfig::default()
+        .max_message_size(Some(MAX_MESSAGE_SIZE))
+        .max_frame_size(Some(MAX_MESSAGE_SIZE));
+    let (sender, receiver) = WebSocketStream::from_raw_socket(io, Role::Server, Some(config))
+        .await
+        .split();
+
+    let (incoming_sender, incoming) = async_channel::bounded(CHANNEL_SIZE);
+    let (outgoing, outgoing_receiver) = async_channel::bounded(CHANNEL_SIZE);
+    let (control_sender, control_receiver) = async_channel::bounded(1);
+
+    let writer_task =
+        IoTaskPool::get().spawn(write_messages(sender, outgoing_receiver, control_receiver));
+    let connection = IoTaskPool::get().spawn(serve_connection(
+        incoming,
+        outgoing,
+        request_sender,
+        credentials,
+    ));
+
+    let result = read_messages(receiver, &incoming_sender, &control_sender).await;
+    drop(incoming_sender);
+    drop(control_sender);
+    connection.await;
+    let _ = writer_task.await;
+    result
+}
+
+/// Reads the text messages of a client and sends them to `incoming`, until the connection is closed.
+///
+/// Pings and close requests are answered by `tungstenite`. Binary messages, invalid messages and
+/// messages larger than [`MAX_MESSAGE_SIZE`] are refused by sending a close frame to `control`.
+async fn read_messages<S: AsyncRead + AsyncWrite + Unpin>(
+    mut receiver: WebSocketReceiver<S>,
+    incoming: &Sender<String>,
+    control: &Sender<CloseFrame>,
+) -> AnyhowResult<()> {
+    while let Some(message) = receiver.next().await {
+        let message = match message {
+            Ok(message) => message,
+            Err(err) => {
+                let code = match err {
+                    tungstenite::Error::Utf8(_) => CloseCode::Invalid,
+                    tungstenite::Error::Capacity(_) => CloseCode::Size,
+                    _ => CloseCode::Protocol,
+                };
+                let _ = control.send(close_frame(code)).await;
+                return Err(err.into());
+            }
+        };
+        match message {
+            Message::Text(text) => incoming.send(text.as_str().to_owned()).await?,
+            Message::Binary(_) => {
+                control.send(close_frame(CloseCode::Unsupported)).await?;
+                bail!("binary messages are not supported");
+            }
+            Message::Close(_) => return Ok(()),
+            Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {}
+        }
+    }
+    Ok(())
+}
+
+/// Writes the responses sent to `outgoing` as text messages, until a close frame is sent to
+/// `control` or a channel is closed.
+async fn write_messages<S: AsyncRead + AsyncWrite + Unpin>(
+    mut sender: WebSocketSender<S>,
+    outgoing: Receiver<String>,
+    control: Receiver<CloseFrame>,
+) -> AnyhowResult<()> {
+    loop {
+        let message = future::or(
+            async {
+                control
+                    .recv()
+                    .await
+                    .ok()
+                    .map(|frame| Message::Close(Some(frame)))
+            },
+            async { outgoing.recv().await.ok().map(Message::text) },
+        )
+        .await;
+        match message {
+            Some(Message::Close(frame)) => {
+                sender.close(frame).await?;
+                return Ok(());
+            }
+            Some(message) => sender.send(message).await?,
+            None => return Ok(()),
+        }
+    }
+}
+
+/// Returns a close frame with the given status code.
+fn close_frame(code: CloseCode) -> CloseFrame {
+    CloseFrame {
+        code,
+        reason: "".into(),
+    }
+}
+
+/// An error refusing a WebSocket handshake.
+#[derive(Debug, thiserror::Error)]
+enum HandshakeError {
+    /// The request isn't a valid WebSocket handshake.
+    #[error("invalid WebSocket handshake: {0}")]
+    BadRequest(&'static str),
+    /// The client requested a version of the protocol other than [`WEBSOCKET_VERSION`].
+    #[error("unsupported WebSocket version")]
+    UnsupportedVersion,
+}
+
+impl HandshakeError {
+    /// Returns the HTTP response sent to the client.
+    fn response(&self) -> Response<Full<Bytes>> {
+        let mut response = Response::new(Full::default());
+        match self {
+            HandshakeError::BadRequest(_) => *response.status_mut() = StatusCode::BAD_REQUEST,
+            HandshakeError::UnsupportedVersion => {
+                *response.status_mut() = StatusCode::UPGRADE_REQUIRED;
+                response.headers_mut().insert(
+                    header::SEC_WEBSOCKET_VERSION,
+                    HeaderValue::from_static(WEBSOCKET_VERSION),
+                );
+            }
+        }
+        response
+    }
+}
+
+/// Returns the response accepting the WebSocket connection requested by `request`.
+fn handshake_response<B>(request: &Request<B>) -> Result<Response<Full<Bytes>>, HandshakeError> {
+    let header = |name| {
+        request
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("")
+    };
+    if request.method() != Method::GET {
+        return Err(HandshakeError::BadRequest(
+            "WebSocket handshakes must be GET requests",
+        ));
+    }
+    if !header(header::UPGRADE).eq_ignore_ascii_case("websocket") {
+        return Err(HandshakeError::BadRequest(
+            "missing `Upgrade: websocket` header",
+        ));
+    }
+    let connection_upgrade = header(header::CONNECTION)
+        .split(',')
+        .any(|token| token.trim().eq_ignore_ascii_case("upgrade"));
+    if !connection_upgrade {
+        return Err(HandshakeError::BadRequest(
+            "missing `Connection: Upgrade` header",
+        ));
+    }
+    if header(header::SEC_WEBSOCKET_VERSION) != WEBSOCKET_VERSION {
+        return Err(HandshakeError::UnsupportedVersion);
+    }
+    let Some(key) = request.headers().get(header::SEC_WEBSOCKET_KEY) else {
+        return Err(HandshakeError::BadRequest(
+            "missing Sec-WebSocket-Key header",
+        ));
+    };
+    let accept = HeaderValue::from_str(&derive_accept_key(key.as_bytes()))
+        .map_err(|_| HandshakeError::BadRequest("invalid Sec-WebSocket-Key header"))?;
+
+    let mut response = Response::new(Full::default());
+    *response.status_mut() = StatusCode::SWITCHING_PROTOCOLS;
+    let headers = response.headers_mut();
+    headers.insert(header::UPGRADE, HeaderValue::from_static("websocket"));
+    headers.insert(header::CONNECTION, HeaderValue::from_static("Upgrade"));
+    headers.insert(header::SEC_WEBSOCKET_ACCEPT, accept);
+    Ok(response)
+}
+
+/// Returns the credentials sent in the `Authorization` header of the handshake `request`.
+fn request_credentials<B>(request: &Request<B>) -> BrpCredentials {
+    request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .map(BrpCredentials::from_authorization_header)
+        .unwrap_or_default()
+}
+
+/// Exposes an [`Upgraded`] connection through the I/O traits of `futures`, used by
+/// `async-tungstenite`.
+struct UpgradedIo(Upgraded);
+
+impl AsyncRead for UpgradedIo {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let mut read_buf = ReadBuf::new(buf);
+        ready!(hyper::rt::Read::poll_read(
+            Pin::new(&mut self.0),
+            cx,
+            read_buf.unfilled()
+        ))?;
+        Poll::Ready(Ok(read_buf.filled().len()))
+    }
+}
+
+impl AsyncWrite for UpgradedIo {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        hyper::rt::Write::poll_write(Pin::new(&mut self.0), cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        hyper::rt::Write::poll_flush(Pin::new(&mut self.0), cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        hyper::rt::Write::poll_shutdown(Pin::new(&mut self.0), cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_tasks::block_on;
+
+    #[test]
+    fn handshake() {
+        // The example of RFC 6455.
+        let request = || {
+            Request::get("/chat")
+                .header(header::HOST, "server.example.com")
+                .header(header::UPGRADE, "websocket")
+                .header(header::CONNECTION, "Upgrade")
+                .header(header::SEC_WEBSOCKET_KEY, "dGhlIHNhbXBsZSBub25jZQ==")
+                .header(header::SEC_WEBSOCKET_VERSION, "13")
+        };
+        let response = handshake_response(&request().body(()).unwrap()).unwrap();
+        assert_eq!(response.status(), StatusCode::SWITCHING_PROTOCOLS);
+        assert_eq!(
+            response.headers()[header::SEC_WEBSOCKET_ACCEPT],
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+
+        let plain = Request::get("/").body(()).unwrap();
+        assert!(handshake_response(&plain).is_err());
+
+        let without_connection = Request::get("/chat")
+            .header(header::UPGRADE, "websocket")
+            .header(header::SEC_WEBSOCKET_KEY, "dGhlIHNhbXBsZSBub25jZQ==")
+            .header(header::SEC_WEBSOCKET_VERSION, "13")
+            .body(())
+            .unwrap();
+        assert!(matches!(
+            handshake_response(&without_connection),
+            Err(HandshakeError::BadRequest(_))
+        ));
+        let mut keep_alive = request().body(()).unwrap();
+        keep_alive.headers_mut().insert(
+            header::CONNECTION,
+            HeaderValue::from_static("keep-alive, Upgrade"),
+        );
+        assert!(handshake_response(&keep_alive).is_ok());
+
+        let mut old_version = request().body(()).unwrap();
+        old_version
+            .headers_mut()
+            .insert(header::SEC_WEBSOCKET_VERSION, HeaderValue::from_static("8"));
+        let err = handshake_response(&old_version).unwrap_err();
+        assert!(matches!(err, HandshakeError::UnsupportedVersion));
+        let response = err.response();
+        assert_eq!(response.status(), StatusCode::UPGRADE_REQUIRED);
+        assert_eq!(response.headers()[header::SEC_WEBSOCKET_VERSION], "13");
+    }
+
+    /// Returns a server and a client WebSocket connected to each other.
+    fn connected_sockets() -> (
+        WebSocketStream<Async<TcpStream>>,
+        WebSocketStream<Async<TcpStream>>,
+    ) {
+        let listener = TcpListener::bind((DEFAULT_WEBSOCKET_ADDR, 0)).unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (server, _) = listener.accept().unwrap();
+        block_on(future::zip(
+            WebSocketStream::from_raw_socket(Async::new(server).unwrap(), Role::Server, None),
+            WebSocketStream::from_raw_socket(Async::new(client).unwrap(), Role::Client, None),
+        ))
+    }
+
+    /// Sends `messages` from a client, and returns the result of reading them on the server,
+    /// with the text messages and the close frames sent by the server.
+    fn read_all(messages: Vec<Message>) -> (AnyhowResult<()>, Vec<String>, Vec<CloseFrame>) {
+        let (server, mut client) = connected_sockets();
+        let (incoming, incoming_receiver) = async_channel::unbounded();
+        let (control, control_receiver) = async_channel::unbounded();
+        let (_, receiver) = server.split();
+        let ((), result) = block_on(future::zip(
+            async {
+                for message in messages {
+                    client.send(message).await.unwrap();
+                }
+            },
+            read_messages(receiver, &incoming, &control),
+        ));
+        drop((incoming, control));
+        (
+            result,
+            block_on(incoming_receiver.collect()),
+            block_on(control_receiver.collect()),
+        )
+    }
+
+    #[test]
+    fn messages() {
+        // Pings are answered by `tungstenite`.
+        let (result, messages, control) = read_all(vec![
+            Message::text("Hello"),
+            Message::Ping(Bytes::from_static(b"ping")),
+            Message::text("World"),
+            Message::Close(None),
+        ]);
+        assert!(result.is_ok());
+        assert_eq!(messages, vec!["Hello".to_string(), "World".to_string()]);
+        assert!(control.is_empty());
+
+        // Binary messages are refused.
+        let (result, messages, control) = read_all(vec![Message::binary(b"{}".to_vec())]);
+        assert!(result.is_err());
+        assert!(messages.is_empty());
+        assert_eq!(control, vec![close_frame(CloseCode::Unsupported)]);
+    }
+}
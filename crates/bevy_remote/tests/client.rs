@@ -0,0 +1,214 @@
+//! Tests the [`BrpClient`] against a headless app serving the Bevy Remote Protocol over HTTP.
+
+#![cfg(feature = "client")]
+
+use bevy_app::{App, TaskPoolPlugin};
+use bevy_ecs::prelude::*;
+use bevy_platform::collections::HashMap;
+use bevy_reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
+use bevy_remote::{
+    builtin_methods::{
+        BrpGetResponse, BrpGetWatchingResponse, BrpJsonSchemaQueryFilter, BrpQuery, BrpQueryFilter,
+    },
+    client::{BrpClient, BrpClientError, HttpTransport},
+    error_codes,
+    http::RemoteHttpPlugin,
+    RemotePlugin,
+};
+use core::time::Duration;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::{
+    net::{TcpListener, TcpStream},
+    thread,
+    time::Instant,
+};
+
+#[derive(Component, Reflect, Serialize, Deserialize, Debug, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Serialize, Deserialize)]
+struct Marker;
+
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+struct Score {
+    value: u32,
+}
+
+const POSITION: &str = "client::Position";
+const MARKER: &str = "client::Marker";
+const SCORE: &str = "client::Score";
+
+/// Runs `client` on another thread, against an app listening on a free port, updating the app
+/// until the client is done.
+fn run(client: impl FnOnce(BrpClient<HttpTransport>) + Send + 'static) {
+    // Let the OS pick a free port, which the app then listens on.
+    let port = TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .unwrap()
+        .port();
+    let mut app = App::new();
+    app.add_plugins((
+        TaskPoolPlugin::default(),
+        RemotePlugin::default(),
+        RemoteHttpPlugin::default().with_port(port),
+    ))
+    .register_type::<Position>()
+    .register_type::<Marker>()
+    .register_type::<Score>();
+
+    let address = format!("127.0.0.1:{port}");
+    let handle = thread::spawn(move || {
+        // The server starts listening once the app has been updated.
+        while TcpStream::connect(&address).is_err() {
+            thread::sleep(Duration::from_millis(10));
+        }
+        client(BrpClient::http(address));
+    });
+
+    let start = Instant::now();
+    while !handle.is_finished() {
+        assert!(
+            start.elapsed() < Duration::from_secs(60),
+            "client timed out"
+        );
+        app.update();
+        thread::sleep(Duration::from_millis(1));
+    }
+    if let Err(panic) = handle.join() {
+        std::panic::resume_unwind(panic);
+    }
+}
+
+fn values(values: &[(&str, Value)]) -> HashMap<String, Value> {
+    values
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.clone()))
+        .collect()
+}
+
+#[test]
+fn entities() {
+    run(|mut client| {
+        let entity = client
+            .spawn(values(&[(POSITION, json!({ "x": 1.0, "y": 2.0 }))]))
+            .unwrap();
+        assert_eq!(
+            client.get_component::<Position>(entity, POSITION).unwrap(),
+            Position { x: 1.0, y: 2.0 }
+        );
+
+        client
+            .mutate_component(entity, POSITION, ".y", json!(5.0))
+            .unwrap();
+        client
+            .insert(entity, values(&[(MARKER, json!(null))]))
+            .unwrap();
+        let other = client
+            .spawn(values(&[(POSITION, json!({ "x": 0.0, "y": 0.0 }))]))
+            .unwrap();
+
+        let rows = client
+            .query(
+                BrpQuery {
+                    components: vec![POSITION.to_string()],
+                    ..Default::default()
+                },
+                BrpQueryFilter {
+                    with: vec![MARKER.to_string()],
+                    ..Default::default()
+                },
+                true,
+            )
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].entity, entity);
+        assert_eq!(rows[0].components[POSITION], json!({ "x": 1.0, "y": 5.0 }));
+
+        let listed = client.list(Some(entity)).unwrap();
+        assert!(listed.iter().any(|name| name == MARKER));
+        client.remove(entity, &[MARKER]).unwrap();
+        let response = client.get(entity, &[POSITION, MARKER], false).unwrap();
+        let BrpGetResponse::Lenient { components, errors } = response else {
+            panic!("expected a lenient response");
+        };
+        assert!(components.contains_key(POSITION));
+        assert!(errors.contains_key(MARKER));
+
+        client.reparent(&[other], Some(entity)).unwrap();
+        client.destroy(entity).unwrap();
+        let error = client.get(other, &[POSITION], true).unwrap_err();
+        assert!(
+            matches!(error, BrpClientError::Remote(ref error) if error.code == error_codes::ENTITY_NOT_FOUND),
+            "{error}"
+        );
+    });
+}
+
+#[test]
+fn resources_and_schema() {
+    run(|mut client| {
+        client
+            .insert_resource(SCORE, json!({ "value": 1 }))
+            .unwrap();
+        client.mutate_resource(SCORE, ".value", json!(3)).unwrap();
+        assert_eq!(client.get_resource(SCORE).unwrap(), json!({ "value": 3 }));
+        assert!(client
+            .list_resources()
+            .unwrap()
+            .iter()
+            .any(|name| name == SCORE));
+        client.remove_resource(SCORE).unwrap();
+        assert!(matches!(
+            client.get_resource(SCORE),
+            Err(BrpClientError::Remote(_))
+        ));
+
+        let schemas = client
+            .registry_schema(BrpJsonSchemaQueryFilter::default())
+            .unwrap();
+        assert!(schemas.contains_key(POSITION));
+        let document = client.discover().unwrap();
+        assert!(document
+            .methods
+            .iter()
+            .any(|method| method.name == "bevy/get"));
+    });
+}
+
+#[test]
+fn watches() {
+    run(|mut client| {
+        let entity = client
+            .spawn(values(&[(POSITION, json!({ "x": 1.0, "y": 2.0 }))]))
+            .unwrap();
+
+        // Watches only report the changes made after they start.
+        let mut positions = client.get_watch(entity, &[POSITION], true).unwrap();
+        let mut lists = client.list_watch(entity).unwrap();
+
+        client
+            .mutate_component(entity, POSITION, ".x", json!(4.0))
+            .unwrap();
+        let BrpGetWatchingResponse::Strict { components, .. } = positions.next().unwrap().unwrap()
+        else {
+            panic!("expected a strict response");
+        };
+        assert_eq!(components[POSITION], json!({ "x": 4.0, "y": 2.0 }));
+
+        client
+            .insert(entity, values(&[(MARKER, json!(null))]))
+            .unwrap();
+        let changes = lists
+            .find(|changes| !changes.as_ref().unwrap().added.is_empty())
+            .unwrap()
+            .unwrap();
+        assert!(changes.added.iter().any(|name| name == MARKER));
+    });
+}
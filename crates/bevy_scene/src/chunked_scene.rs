@@ -0,0 +1,863 @@
+use crate::{
+    dynamic_scene::find_component,
+    reflect_utils::{clone_reflect_value, map_entity_references},
+    ron,
+    serde::{SceneDeserializer, SceneSerializer},
+    serialize_ron, DynamicScene, SceneLoaderError, SceneSpawnError,
+};
+use alloc::collections::BTreeMap;
+use bevy_asset::{io::Reader, Asset, AssetId, AssetLoader, Assets, Handle, LoadContext};
+use bevy_ecs::{
+    component::Component,
+    entity::{Entity, EntityHashMap, EntityHashSet, EntityMapper},
+    hierarchy::ChildOf,
+    lifecycle::HookContext,
+    query::With,
+    reflect::AppTypeRegistry,
+    world::{DeferredWorld, FromWorld, World},
+};
+use bevy_math::{IVec3, Vec3};
+use bevy_platform::collections::HashMap;
+use bevy_reflect::{TypePath, TypeRegistry, TypeRegistryArc};
+use bevy_transform::components::{GlobalTransform, Transform};
+use core::fmt::Formatter;
+use serde::{
+    de::{DeserializeSeed, Error as _, MapAccess, Visitor},
+    ser::{SerializeMap, SerializeStruct},
+    Deserializer, Serialize, Serializer,
+};
+use tracing::error;
+
+/// Name of the serialized chunked scene struct type.
+pub const CHUNKED_SCENE_STRUCT: &str = "ChunkedScene";
+/// Name of the serialized chunk size field in a chunked scene struct.
+pub const CHUNKED_SCENE_CHUNK_SIZE: &str = "chunk_size";
+/// Name of the serialized chunks field in a chunked scene struct.
+pub const CHUNKED_SCENE_CHUNKS: &str = "chunks";
+
+/// A scene split into the cells of a regular 3D grid, so that it can be spawned piece by piece with a
+/// [`SceneStreamer`].
+///
+/// Each chunk is a [`DynamicScene`] stored as a labeled sub-asset of the chunked scene (see
+/// [`ChunkedScene::chunk_label`]), so a single chunk can also be loaded on its own, for example with
+/// `asset_server.load::<DynamicScene>("level.chunks.ron#chunk_0_0_0")`.
+///
+/// Entity identifiers are shared by all the chunks of a chunked scene: components of an entity in
+/// one chunk can reference entities of another chunk.
+#[derive(Asset, TypePath, Debug)]
+pub struct ChunkedScene {
+    /// The size of the chunks along each axis, in world units.
+    pub chunk_size: f32,
+    /// The chunks of the scene, by chunk coordinates.
+    pub chunks: HashMap<IVec3, Handle<DynamicScene>>,
+}
+
+impl ChunkedScene {
+    /// Returns the label of the sub-asset holding the chunk at `coord`.
+    pub fn chunk_label(coord: IVec3) -> String {
+        format!("chunk_{}_{}_{}", coord.x, coord.y, coord.z)
+    }
+
+    /// Returns the coordinates of the chunk containing the given `position`.
+    pub fn chunk_coord(&self, position: Vec3) -> IVec3 {
+        chunk_coord(position, self.chunk_size)
+    }
+}
+
+fn chunk_coord(position: Vec3, chunk_size: f32) -> IVec3 {
+    (position / chunk_size).floor().as_ivec3()
+}
+
+/// Splits `scene` into chunks of the given size, grouping each entity with its root ancestor.
+///
+/// Root entities are placed in the chunk containing the translation of their [`Transform`]. Root
+/// entities without a [`Transform`] are placed in the chunk at the origin, as well as the resources of
+/// the scene.
+///
+/// The chunks can be serialized with [`ChunkedSceneSerializer`].
+pub fn split_scene_into_chunks(
+    scene: DynamicScene,
+    chunk_size: f32,
+) -> HashMap<IVec3, DynamicScene> {
+    let parents: EntityHashMap<Entity> = scene
+        .entities
+        .iter()
+        .filter_map(|entity| {
+            find_component::<ChildOf>(entity).map(|child_of| (entity.entity, child_of.parent()))
+        })
+        .collect();
+    let root_coords: EntityHashMap<IVec3> = scene
+        .entities
+        .iter()
+        .map(|entity| {
+            let translation = find_component::<Transform>(entity)
+                .map(|transform| transform.translation)
+                .unwrap_or_default();
+            (entity.entity, chunk_coord(translation, chunk_size))
+        })
+        .collect();
+
+    let mut chunks: HashMap<IVec3, DynamicScene> = HashMap::default();
+    for entity in scene.entities {
+        let mut root = entity.entity;
+        // Bounded, in case the hierarchy contains a cycle.
+        for _ in 0..parents.len() {
+            match parents.get(&root) {
+                Some(parent) if root_coords.contains_key(parent) => root = *parent,
+                _ => break,
+            }
+        }
+        let coord = root_coords.get(&root).copied().unwrap_or_default();
+        chunks.entry(coord).or_default().entities.push(entity);
+    }
+    if !scene.resources.is_empty() {
+        chunks.entry(IVec3::ZERO).or_default().resources = scene.resources;
+    }
+    chunks
+}
+
+/// Serializes the chunks of a chunked scene, in the format loaded by [`ChunkedSceneLoader`].
+///
+/// ```ron
+/// (
+///   chunk_size: 16.0,
+///   chunks: {
+///     (0, 0, 0): (
+///       resources: {},
+///       entities: {},
+///     ),
+///   },
+/// )
+/// ```
+pub struct ChunkedSceneSerializer<'a> {
+    /// The size of the chunks along each axis, in world units.
+    pub chunk_size: f32,
+    /// The chunks to serialize, by chunk coordinates.
+    pub chunks: &'a HashMap<IVec3, DynamicScene>,
+    /// The type registry containing the types present in the chunks.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> ChunkedSceneSerializer<'a> {
+    /// Serializes the chunks to a RON string.
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        serialize_ron(self)
+    }
+}
+
+impl<'a> Serialize for ChunkedSceneSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(CHUNKED_SCENE_STRUCT, 2)?;
+        state.serialize_field(CHUNKED_SCENE_CHUNK_SIZE, &self.chunk_size)?;
+        state.serialize_field(
+            CHUNKED_SCENE_CHUNKS,
+            &ChunksSerializer {
+                chunks: self.chunks,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+struct ChunksSerializer<'a> {
+    chunks: &'a HashMap<IVec3, DynamicScene>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ChunksSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Sort the chunks so that the output is deterministic.
+        let chunks: BTreeMap<(i32, i32, i32), &DynamicScene> = self
+            .chunks
+            .iter()
+            .map(|(coord, chunk)| ((*coord).into(), chunk))
+            .collect();
+        let mut state = serializer.serialize_map(Some(chunks.len()))?;
+        for (coord, chunk) in chunks {
+            state.serialize_entry(&coord, &SceneSerializer::new(chunk, self.registry))?;
+        }
+        state.end()
+    }
+}
+
+/// The deserialized chunks of a chunked scene, before they are added as sub-assets.
+struct ChunksData {
+    chunk_size: f32,
+    chunks: Vec<(IVec3, DynamicScene)>,
+}
+
+/// Handles chunked scene deserialization.
+struct ChunkedSceneDeserializer<'a> {
+    type_registry: &'a TypeRegistry,
+    load_parent: &'a mut dyn FnMut(&str) -> Handle<DynamicScene>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ChunkedSceneDeserializer<'a> {
+    type Value = ChunksData;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            CHUNKED_SCENE_STRUCT,
+            &[CHUNKED_SCENE_CHUNK_SIZE, CHUNKED_SCENE_CHUNKS],
+            self,
+        )
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ChunkedSceneDeserializer<'a> {
+    type Value = ChunksData;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("chunked scene struct")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut chunk_size = None;
+        let mut chunks = None;
+        while let Some(key) = map.next_key::<&str>()? {
+            match key {
+                CHUNKED_SCENE_CHUNK_SIZE => {
+                    if chunk_size.is_some() {
+                        return Err(A::Error::duplicate_field(CHUNKED_SCENE_CHUNK_SIZE));
+                    }
+                    chunk_size = Some(map.next_value::<f32>()?);
+                }
+                CHUNKED_SCENE_CHUNKS => {
+                    if chunks.is_some() {
+                        return Err(A::Error::duplicate_field(CHUNKED_SCENE_CHUNKS));
+                    }
+                    chunks = Some(map.next_value_seed(ChunksDeserializer {
+                        type_registry: self.type_registry,
+                        load_parent: &mut *self.load_parent,
+                    })?);
+                }
+                _ => {
+                    return Err(A::Error::unknown_field(
+                        key,
+                        &[CHUNKED_SCENE_CHUNK_SIZE, CHUNKED_SCENE_CHUNKS],
+                    ))
+                }
+            }
+        }
+        let chunk_size =
+            chunk_size.ok_or_else(|| A::Error::missing_field(CHUNKED_SCENE_CHUNK_SIZE))?;
+        if chunk_size <= 0.0 {
+            return Err(A::Error::custom(format_args!(
+                "chunk size must be positive, found {chunk_size}"
+            )));
+        }
+        Ok(ChunksData {
+            chunk_size,
+            chunks: chunks.ok_or_else(|| A::Error::missing_field(CHUNKED_SCENE_CHUNKS))?,
+        })
+    }
+}
+
+struct ChunksDeserializer<'a> {
+    type_registry: &'a TypeRegistry,
+    load_parent: &'a mut dyn FnMut(&str) -> Handle<DynamicScene>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ChunksDeserializer<'a> {
+    type Value = Vec<(IVec3, DynamicScene)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ChunksDeserializer<'a> {
+    type Value = Vec<(IVec3, DynamicScene)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of chunks")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut chunks: Vec<(IVec3, DynamicScene)> = Vec::new();
+        while let Some(coord) = map.next_key::<(i32, i32, i32)>()? {
+            let coord = IVec3::from(coord);
+            if chunks.iter().any(|(other, _)| *other == coord) {
+                return Err(A::Error::custom(format_args!("duplicate chunk {coord}")));
+            }
+            let chunk = map.next_value_seed(SceneDeserializer {
+                type_registry: self.type_registry,
+                load_parent: Some(&mut *self.load_parent),
+            })?;
+            chunks.push((coord, chunk));
+        }
+        Ok(chunks)
+    }
+}
+
+/// Asset loader for a [`ChunkedScene`] (`.chunks.ron`).
+///
+/// The loader handles assets serialized with [`ChunkedSceneSerializer`], and adds each chunk as a
+/// labeled sub-asset.
+#[derive(Debug)]
+pub struct ChunkedSceneLoader {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for ChunkedSceneLoader {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        ChunkedSceneLoader {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+impl AssetLoader for ChunkedSceneLoader {
+    type Asset = ChunkedScene;
+    type Settings = ();
+    type Error = SceneLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
+        // Parents are written as full asset paths, see `DynamicScene::parent`.
+        let mut load_parent = |path: &str| load_context.load(path.to_owned());
+        let data = ChunkedSceneDeserializer {
+            type_registry: &self.type_registry.read(),
+            load_parent: &mut load_parent,
+        }
+        .deserialize(&mut deserializer)
+        .map_err(|e| deserializer.span_error(e))?;
+
+        let chunks = data
+            .chunks
+            .into_iter()
+            .map(|(coord, chunk)| {
+                let handle =
+                    load_context.add_labeled_asset(ChunkedScene::chunk_label(coord), chunk);
+                (coord, handle)
+            })
+            .collect();
+        Ok(ChunkedScene {
+            chunk_size: data.chunk_size,
+            chunks,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["chunks.ron"]
+    }
+}
+
+/// Spawns the chunks of a [`ChunkedScene`] around a focus entity, and despawns them when the focus
+/// moves away.
+///
+/// The chunks within [`radius`](Self::radius) chunks of the chunk containing the [`GlobalTransform`]
+/// of the [`focus`](Self::focus) entity are spawned as children of the entity holding the streamer.
+/// Chunks that are further away are despawned.
+///
+/// When a spawned chunk references an entity of a chunk that is not spawned yet, the reference
+/// points to an empty placeholder entity, which receives its components once its chunk is spawned.
+/// Likewise, the entities of despawned chunks that are still referenced by a spawned chunk are
+/// emptied rather than despawned, so that the references stay valid if their chunk is spawned again.
+/// The other entities of despawned chunks are despawned.
+///
+/// All the spawned entities are despawned when the streamer is removed.
+#[derive(Component)]
+#[component(on_remove = on_remove_scene_streamer)]
+pub struct SceneStreamer {
+    /// The streamed scene.
+    pub scene: Handle<ChunkedScene>,
+    /// The entity around which chunks are spawned.
+    pub focus: Entity,
+    /// The distance up to which chunks are spawned, in chunks along each axis.
+    pub radius: u32,
+    state: StreamerState,
+}
+
+impl SceneStreamer {
+    /// Creates a streamer spawning the chunks of `scene` within `radius` chunks of `focus`.
+    pub fn new(scene: Handle<ChunkedScene>, focus: Entity, radius: u32) -> Self {
+        Self {
+            scene,
+            focus,
+            radius,
+            state: StreamerState::default(),
+        }
+    }
+
+    /// Returns the coordinates of the chunks that are currently spawned.
+    pub fn spawned_chunks(&self) -> impl Iterator<Item = IVec3> + '_ {
+        self.state.chunks.keys().copied()
+    }
+
+    /// Returns the world entity of the scene `entity`, if its chunk has been spawned or if it is
+    /// referenced by a spawned chunk.
+    pub fn entity(&self, entity: Entity) -> Option<Entity> {
+        self.state.entity_map.get(&entity).copied()
+    }
+}
+
+#[derive(Default)]
+struct StreamerState {
+    /// The scene the state belongs to, to start over when the streamed scene changes.
+    scene: Option<AssetId<ChunkedScene>>,
+    /// Maps the spawned and referenced entities of the chunks of the scene to world entities.
+    entity_map: EntityHashMap<Entity>,
+    /// The scene entities of each spawned chunk.
+    chunks: HashMap<IVec3, Vec<Entity>>,
+    /// The scene entities referenced by each spawned chunk.
+    references: HashMap<IVec3, EntityHashSet>,
+}
+
+impl StreamerState {
+    /// Forgets the chunk at `coord`, despawning its entities and the placeholders it references unless
+    /// they are still referenced by another spawned chunk, in which case they are only emptied.
+    fn despawn_chunk(&mut self, world: &mut World, coord: IVec3) {
+        let entities = self.chunks.remove(&coord).unwrap_or_default();
+        let references = self.references.remove(&coord).unwrap_or_default();
+        let spawned: EntityHashSet = self.chunks.values().flatten().copied().collect();
+        let referenced: EntityHashSet = self.references.values().flatten().copied().collect();
+
+        let mut despawned = Vec::new();
+        for entity in entities.into_iter().chain(references) {
+            if spawned.contains(&entity) {
+                continue;
+            }
+            let Some(&target) = self.entity_map.get(&entity) else {
+                continue;
+            };
+            if referenced.contains(&entity) {
+                // Emptying the tombstone also detaches it from its parent, so that it isn't despawned
+                // along with it.
+                if let Ok(mut target) = world.get_entity_mut(target) {
+                    target.clear();
+                }
+            } else {
+                self.entity_map.remove(&entity);
+                despawned.push(target);
+            }
+        }
+        for entity in despawned {
+            if let Ok(entity) = world.get_entity_mut(entity) {
+                entity.despawn();
+            }
+        }
+    }
+}
+
+fn on_remove_scene_streamer(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    let Some(streamer) = world.get::<SceneStreamer>(entity) else {
+        return;
+    };
+    let entities: Vec<Entity> = streamer.state.entity_map.values().copied().collect();
+    let mut commands = world.commands();
+    for entity in entities {
+        commands.entity(entity).try_despawn();
+    }
+}
+
+/// System that spawns and despawns the chunks of [`SceneStreamer`]s.
+pub fn scene_streamer_system(world: &mut World) {
+    let streamers: Vec<Entity> = world
+        .query_filtered::<Entity, With<SceneStreamer>>()
+        .iter(world)
+        .collect();
+    for streamer in streamers {
+        let (scene, focus, radius, mut state) = {
+            let mut component = world.get_mut::<SceneStreamer>(streamer).unwrap();
+            let state = core::mem::take(&mut component.state);
+            (
+                component.scene.id(),
+                component.focus,
+                component.radius,
+                state,
+            )
+        };
+        update_streamer(world, streamer, scene, focus, radius, &mut state);
+        if let Some(mut component) = world.get_mut::<SceneStreamer>(streamer) {
+            component.state = state;
+        }
+    }
+}
+
+fn update_streamer(
+    world: &mut World,
+    streamer: Entity,
+    scene: AssetId<ChunkedScene>,
+    focus: Entity,
+    radius: u32,
+    state: &mut StreamerState,
+) {
+    if state.scene != Some(scene) {
+        for (_, entity) in state.entity_map.drain() {
+            if let Ok(entity) = world.get_entity_mut(entity) {
+                entity.despawn();
+            }
+        }
+        state.chunks.clear();
+        state.references.clear();
+        state.scene = Some(scene);
+    }
+
+    let Some(focus) = world
+        .get::<GlobalTransform>(focus)
+        .map(GlobalTransform::translation)
+    else {
+        return;
+    };
+    let Some(chunked_scene) = world.resource::<Assets<ChunkedScene>>().get(scene) else {
+        return;
+    };
+    let center = chunked_scene.chunk_coord(focus);
+    let radius = i32::try_from(radius).unwrap_or(i32::MAX);
+    let in_range = |coord: IVec3| (coord - center).abs().max_element() <= radius;
+    let to_spawn: Vec<(IVec3, AssetId<DynamicScene>)> = chunked_scene
+        .chunks
+        .iter()
+        .filter(|(coord, _)| in_range(**coord) && !state.chunks.contains_key(*coord))
+        .map(|(coord, handle)| (*coord, handle.id()))
+        .collect();
+    let to_despawn: Vec<IVec3> = state
+        .chunks
+        .keys()
+        .copied()
+        .filter(|coord| !in_range(*coord))
+        .collect();
+
+    for coord in to_despawn {
+        state.despawn_chunk(world, coord);
+    }
+
+    for (coord, chunk) in to_spawn {
+        match spawn_chunk(world, chunk, &mut state.entity_map) {
+            Ok((entities, references)) => {
+                for entity in &entities {
+                    let entity = state.entity_map[entity];
+                    if world.get::<ChildOf>(entity).is_none() {
+                        world.entity_mut(streamer).add_child(entity);
+                    }
+                }
+                state.chunks.insert(coord, entities);
+                state.references.insert(coord, references);
+            }
+            // The chunk is still loading.
+            Err(SceneSpawnError::NonExistentScene { .. }) => {}
+            Err(err) => {
+                error!("Failed to spawn chunk {coord} of {scene}: {err}");
+                // Don't try again every frame.
+                state.chunks.insert(coord, Vec::new());
+            }
+        }
+    }
+}
+
+/// Spawns a chunk, returning its scene entities and the scene entities it references.
+fn spawn_chunk(
+    world: &mut World,
+    chunk: AssetId<DynamicScene>,
+    entity_map: &mut EntityHashMap<Entity>,
+) -> Result<(Vec<Entity>, EntityHashSet), SceneSpawnError> {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let scene = {
+        let scenes = world.resource::<Assets<DynamicScene>>();
+        scenes
+            .get(chunk)
+            .ok_or(SceneSpawnError::NonExistentScene { id: chunk })?
+            .resolve(scenes, &registry.read())?
+    };
+    let references = reserve_references(world, &scene, entity_map, &registry.read());
+    scene.write_to_world_with(world, entity_map, &registry)?;
+    let entities = scene.entities.iter().map(|entity| entity.entity).collect();
+    Ok((entities, references))
+}
+
+/// Spawns placeholder entities for the entities referenced by `scene` that aren't mapped yet, so
+/// that chunks spawned later reuse them for the referenced entities.
+///
+/// Returns the referenced scene entities.
+fn reserve_references(
+    world: &mut World,
+    scene: &DynamicScene,
+    entity_map: &mut EntityHashMap<Entity>,
+    type_registry: &TypeRegistry,
+) -> EntityHashSet {
+    struct ReferenceCollector(EntityHashSet);
+
+    impl EntityMapper for ReferenceCollector {
+        fn get_mapped(&mut self, source: Entity) -> Entity {
+            self.0.insert(source);
+            source
+        }
+
+        fn set_mapped(&mut self, _source: Entity, _target: Entity) {}
+    }
+
+    let mut collector = ReferenceCollector(EntityHashSet::default());
+    let values = scene
+        .entities
+        .iter()
+        .flat_map(|entity| &entity.components)
+        .chain(&scene.resources);
+    for value in values {
+        let Some(registration) = value
+            .get_represented_type_info()
+            .and_then(|info| type_registry.get(info.type_id()))
+        else {
+            continue;
+        };
+        let mut value = clone_reflect_value(value.as_partial_reflect(), registration);
+        map_entity_references(value.as_partial_reflect_mut(), registration, &mut collector);
+    }
+    for &entity in &collector.0 {
+        entity_map
+            .entry(entity)
+            .or_insert_with(|| world.spawn_empty().id());
+    }
+    collector.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DynamicSceneBuilder, ScenePlugin};
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId,
+        },
+        AssetApp, AssetPlugin, AssetServer, LoadState,
+    };
+    use bevy_ecs::reflect::ReflectComponent;
+    use bevy_reflect::Reflect;
+    use std::path::Path;
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Tree(u32);
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Road(#[entities] Entity);
+
+    fn test_app(dir: Dir) -> App {
+        let mut app = App::new();
+        let reader = MemoryAssetReader { root: dir };
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader(move || Box::new(reader.clone())),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .register_type::<Tree>()
+        .register_type::<Road>()
+        .register_type::<Transform>()
+        .register_type::<ChildOf>();
+        app
+    }
+
+    /// Builds a scene with a tree in each of the chunks `(0, 0, 0)`, `(1, 0, 0)` and `(3, 0, 0)`,
+    /// a child of the first tree and a road from the first tree to the tree in `(3, 0, 0)`.
+    fn level(app: &mut App) -> HashMap<IVec3, DynamicScene> {
+        let mut source = World::new();
+        source.insert_resource(app.world().resource::<AppTypeRegistry>().clone());
+        let far = source
+            .spawn((Tree(3), Transform::from_xyz(35.0, 0.0, 0.0)))
+            .id();
+        source
+            .spawn((Tree(0), Transform::from_xyz(5.0, 0.0, 0.0), Road(far)))
+            .with_child((Tree(10), Transform::from_xyz(10.0, 0.0, 0.0)));
+        source.spawn((Tree(1), Transform::from_xyz(15.0, 0.0, 0.0)));
+        let scene = DynamicSceneBuilder::from_world(&source)
+            .deny_component::<bevy_ecs::hierarchy::Children>()
+            .extract_entities(source.iter_entities().map(|entity| entity.id()))
+            .build();
+        split_scene_into_chunks(scene, 10.0)
+    }
+
+    fn trees(app: &mut App) -> Vec<u32> {
+        let world = app.world_mut();
+        let mut trees: Vec<u32> = world
+            .query::<&Tree>()
+            .iter(world)
+            .map(|tree| tree.0)
+            .collect();
+        trees.sort();
+        trees
+    }
+
+    #[test]
+    fn split_by_root_translation() {
+        let mut app = test_app(Dir::default());
+        let chunks = level(&mut app);
+        let mut sizes: Vec<(IVec3, usize)> = chunks
+            .iter()
+            .map(|(coord, chunk)| (*coord, chunk.entities.len()))
+            .collect();
+        sizes.sort_by_key(|(coord, _)| coord.x);
+        // The child stays with its parent, even though it lies in another chunk.
+        assert_eq!(
+            sizes,
+            vec![
+                (IVec3::new(0, 0, 0), 2),
+                (IVec3::new(1, 0, 0), 1),
+                (IVec3::new(3, 0, 0), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn load_chunks_as_sub_assets() {
+        let dir = Dir::default();
+        let mut app = test_app(dir.clone());
+        let chunks = level(&mut app);
+        let ron = ChunkedSceneSerializer {
+            chunk_size: 10.0,
+            chunks: &chunks,
+            registry: &app.world().resource::<AppTypeRegistry>().read(),
+        }
+        .to_ron()
+        .unwrap();
+        dir.insert_asset_text(Path::new("level.chunks.ron"), &ron);
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let chunk = asset_server.load::<DynamicScene>("level.chunks.ron#chunk_1_0_0");
+        let scene = asset_server.load::<ChunkedScene>("level.chunks.ron");
+        for _ in 0..100 {
+            if asset_server.is_loaded_with_dependencies(&scene) {
+                break;
+            }
+            if let LoadState::Failed(err) = asset_server.load_state(&scene) {
+                panic!("failed to load the chunked scene: {err}");
+            }
+            app.update();
+        }
+
+        let world = app.world();
+        let scene = world
+            .resource::<Assets<ChunkedScene>>()
+            .get(&scene)
+            .unwrap();
+        assert_eq!(scene.chunk_size, 10.0);
+        assert_eq!(scene.chunks.len(), 3);
+        assert_eq!(scene.chunks[&IVec3::new(1, 0, 0)], chunk);
+        let chunk = world
+            .resource::<Assets<DynamicScene>>()
+            .get(&chunk)
+            .unwrap();
+        assert_eq!(chunk.entities.len(), 1);
+    }
+
+    #[test]
+    fn stream_chunks_around_focus() {
+        let mut app = test_app(Dir::default());
+        let chunks = level(&mut app);
+        let world = app.world_mut();
+        let chunks = chunks
+            .into_iter()
+            .map(|(coord, chunk)| {
+                (
+                    coord,
+                    world.resource_mut::<Assets<DynamicScene>>().add(chunk),
+                )
+            })
+            .collect();
+        let scene = world
+            .resource_mut::<Assets<ChunkedScene>>()
+            .add(ChunkedScene {
+                chunk_size: 10.0,
+                chunks,
+            });
+        let focus = world.spawn(GlobalTransform::from_xyz(1.0, 0.0, 0.0)).id();
+        let streamer = world.spawn(SceneStreamer::new(scene, focus, 1)).id();
+        let set_radius = |app: &mut App, radius: u32| {
+            let world = app.world_mut();
+            world.get_mut::<SceneStreamer>(streamer).unwrap().radius = radius;
+            app.update();
+        };
+
+        app.update();
+        assert_eq!(trees(&mut app), vec![0, 1, 10]);
+        // The road leads to an empty placeholder until its chunk is spawned.
+        let world = app.world_mut();
+        let road = world.query::<&Road>().single(world).unwrap().0;
+        assert!(world.get::<Tree>(road).is_none());
+        let roots = world
+            .get::<bevy_ecs::hierarchy::Children>(streamer)
+            .unwrap();
+        assert_eq!(roots.len(), 2);
+
+        set_radius(&mut app, 3);
+        assert_eq!(trees(&mut app), vec![0, 1, 3, 10]);
+        assert_eq!(app.world().get::<Tree>(road), Some(&Tree(3)));
+
+        // The road target is still referenced, so it is only emptied when its chunk is despawned.
+        set_radius(&mut app, 2);
+        assert_eq!(trees(&mut app), vec![0, 1, 10]);
+        let world = app.world();
+        assert!(world.get_entity(road).is_ok());
+        assert!(world.get::<Tree>(road).is_none());
+
+        set_radius(&mut app, 3);
+        assert_eq!(app.world().get::<Tree>(road), Some(&Tree(3)));
+
+        // Moving the focus to (2, 0, 0) with a radius of 1 despawns (0, 0, 0) and its child.
+        let world = app.world_mut();
+        let first = world
+            .query::<(Entity, &Tree)>()
+            .iter(world)
+            .filter(|(_, tree)| tree.0 == 0 || tree.0 == 10)
+            .map(|(entity, _)| entity)
+            .collect::<Vec<_>>();
+        world
+            .entity_mut(focus)
+            .insert(GlobalTransform::from_xyz(25.0, 0.0, 0.0));
+        set_radius(&mut app, 1);
+        assert_eq!(trees(&mut app), vec![1, 3]);
+        let world = app.world();
+        for entity in first {
+            assert!(world.get_entity(entity).is_err());
+        }
+        let mut spawned: Vec<i32> = world
+            .get::<SceneStreamer>(streamer)
+            .unwrap()
+            .spawned_chunks()
+            .map(|coord| coord.x)
+            .collect();
+        spawned.sort();
+        assert_eq!(spawned, vec![1, 3]);
+
+        // Removing the streamer despawns its entities.
+        app.world_mut()
+            .entity_mut(streamer)
+            .remove::<SceneStreamer>();
+        app.update();
+        assert!(trees(&mut app).is_empty());
+        assert!(app.world().get_entity(road).is_err());
+    }
+}
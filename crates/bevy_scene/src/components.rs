@@ -25,3 +25,14 @@ pub struct SceneRoot(pub Handle<Scene>);
 #[require(Transform)]
 #[cfg_attr(feature = "bevy_render", require(Visibility))]
 pub struct DynamicSceneRoot(pub Handle<DynamicScene>);
+
+/// A stable identifier for an entity of a scene, unique within the scene.
+///
+/// When a scene is serialized with [`EntityKeys::Stable`](crate::serde::EntityKeys::Stable), entities
+/// with this component are keyed by their identifier instead of the path of their [`Name`]s, so that
+/// renaming or moving them doesn't change their key.
+///
+/// [`Name`]: bevy_ecs::name::Name
+#[derive(Component, Clone, Debug, Default, Deref, DerefMut, Reflect, PartialEq, Eq, Hash, From)]
+#[reflect(Component, Default, Debug, PartialEq, Clone, Hash)]
+pub struct StableId(pub String);
@@ -1,12 +1,17 @@
 use crate::{DynamicSceneBuilder, Scene, SceneSpawnError};
-use bevy_asset::Asset;
+use bevy_asset::{Asset, AssetId, Assets, Handle};
 use bevy_ecs::reflect::{ReflectMapEntities, ReflectResource};
 use bevy_ecs::{
     entity::{Entity, EntityHashMap, SceneEntityMapper},
+    hierarchy::ChildOf,
+    name::Name,
     reflect::{AppTypeRegistry, ReflectComponent},
     world::World,
 };
-use bevy_reflect::{PartialReflect, TypePath};
+use bevy_platform::collections::HashSet;
+use bevy_reflect::{
+    FromReflect, PartialReflect, TypeInfo, TypePath, TypeRegistration, TypeRegistry,
+};
 
 use crate::reflect_utils::clone_reflect_value;
 use bevy_ecs::component::ComponentCloneBehavior;
@@ -15,7 +20,6 @@ use bevy_ecs::relationship::RelationshipHookMode;
 #[cfg(feature = "serialize")]
 use {
     crate::{ron, serde::SceneSerializer},
-    bevy_reflect::TypeRegistry,
     serde::Serialize,
 };
 
@@ -26,12 +30,35 @@ use {
 /// * [`SceneSpawner::spawn_dynamic`](crate::SceneSpawner::spawn_dynamic)
 /// * adding the [`DynamicSceneRoot`](crate::components::DynamicSceneRoot) component to an entity.
 /// * using the [`DynamicSceneBuilder`] to construct a `DynamicScene` from `World`.
+///
+/// # Scene inheritance
+///
+/// A dynamic scene can be a _variant_ of a [`parent`](Self::parent) scene. When a variant is
+/// written to a world, the entities and resources of its parent are written first, with the
+/// variant's [`overrides`](Self::overrides) applied to them. The variant's own
+/// [`entities`](Self::entities) and [`resources`](Self::resources) are then added on top.
+/// Parents can themselves be variants of other scenes.
+///
+/// Modifying the parent scene asset updates all spawned instances of its variants.
 #[derive(Asset, TypePath, Default)]
 pub struct DynamicScene {
     /// Resources stored in the dynamic scene.
     pub resources: Vec<Box<dyn PartialReflect>>,
     /// Entities contained in the dynamic scene.
+    ///
+    /// For a scene variant, these are the entities added on top of the ones of the parent scene.
     pub entities: Vec<DynamicEntity>,
+    /// The scene this scene is a variant of, if any.
+    ///
+    /// The parent is serialized as its full asset path, like `"scenes/base.scn.ron"`, not as a path
+    /// relative to the variant, so it must have been loaded from a path.
+    ///
+    /// Scene inheritance can only be serialized to human-readable formats, like RON: the layout of
+    /// scenes in other formats has no room for it without breaking existing scenes.
+    #[dependency]
+    pub parent: Option<Handle<DynamicScene>>,
+    /// Modifications applied to the entities inherited from the [`parent`](Self::parent) scene.
+    pub overrides: Vec<EntityOverride>,
 }
 
 /// A reflection-powered serializable representation of an entity and its components.
@@ -45,6 +72,50 @@ pub struct DynamicEntity {
     pub components: Vec<Box<dyn PartialReflect>>,
 }
 
+/// Modifications applied by a scene variant to an entity inherited from its parent scene.
+///
+/// See [`DynamicScene::parent`].
+pub struct EntityOverride {
+    /// The path of the overridden entity in the parent scene.
+    ///
+    /// This is made up of the [`Name`]s of the entity and its ancestors within the scene, from the
+    /// root down, separated by `/`. For example, `"Enemy/Weapon"` addresses the entity named
+    /// `Weapon` whose parent is the root entity named `Enemy`.
+    pub path: String,
+    /// Components to add to the entity.
+    ///
+    /// If the entity already has a component of the same type, the value is applied on top of it
+    /// instead, using [`PartialReflect::apply`].
+    pub components: Vec<Box<dyn PartialReflect>>,
+    /// The type paths of the components to remove from the entity.
+    ///
+    /// Both full and short type paths are accepted.
+    pub removed: Vec<String>,
+}
+
+impl EntityOverride {
+    /// Creates an empty override for the entity at the given `path`.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            components: Vec::new(),
+            removed: Vec::new(),
+        }
+    }
+
+    /// Adds or patches the `component` on the overridden entity, returning the override.
+    pub fn with_component(mut self, component: impl PartialReflect) -> Self {
+        self.components.push(Box::new(component));
+        self
+    }
+
+    /// Removes the component with the given type path from the overridden entity, returning the override.
+    pub fn without_component(mut self, type_path: impl Into<String>) -> Self {
+        self.removed.push(type_path.into());
+        self
+    }
+}
+
 impl DynamicScene {
     /// Create a new dynamic scene from a given scene.
     pub fn from_scene(scene: &Scene) -> Self {
@@ -64,12 +135,25 @@ impl DynamicScene {
     /// This method will return a [`SceneSpawnError`] if a type either is not registered
     /// in the provided [`AppTypeRegistry`] resource, or doesn't reflect the
     /// [`Component`](bevy_ecs::component::Component) or [`Resource`](bevy_ecs::prelude::Resource) trait.
+    ///
+    /// If this scene is a variant of a [`parent`](Self::parent) scene, the inheritance is resolved
+    /// using the world's [`Assets<DynamicScene>`] resource, see [`DynamicScene::resolve`].
     pub fn write_to_world_with(
         &self,
         world: &mut World,
         entity_map: &mut EntityHashMap<Entity>,
         type_registry: &AppTypeRegistry,
     ) -> Result<(), SceneSpawnError> {
+        if let Some(parent) = &self.parent {
+            let resolved = {
+                let scenes = world
+                    .get_resource::<Assets<DynamicScene>>()
+                    .ok_or(SceneSpawnError::NonExistentScene { id: parent.id() })?;
+                self.resolve(scenes, &type_registry.read())?
+            };
+            return resolved.write_to_world_with(world, entity_map, type_registry);
+        }
+
         let type_registry = type_registry.read();
 
         // First ensure that every entity in the scene has a corresponding world
@@ -187,6 +271,269 @@ impl DynamicScene {
         self.write_to_world_with(world, entity_map, &registry)
     }
 
+    /// Write the changes between the `previous` version of this scene and this one to the given world.
+    ///
+    /// This is intended to update an instance of `previous` that was written to the world using
+    /// `entity_map`, while preserving its runtime state. Entities and components that did not change
+    /// between both versions are left untouched, components that were removed from an entity are
+    /// removed from it, and entities that were removed from the scene are despawned. Entities that
+    /// remain in the scene keep their world identifiers.
+    ///
+    /// Components are compared using [`PartialReflect::reflect_partial_eq`]. Components that can't
+    /// be compared are treated as changed.
+    ///
+    /// Neither scene may have a [`parent`](Self::parent): resolve them first using [`DynamicScene::resolve`].
+    pub fn write_changes_to_world(
+        &self,
+        previous: &DynamicScene,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &AppTypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        let registry = type_registry.read();
+        let mut changes = DynamicScene::default();
+
+        for entity in &self.entities {
+            // Entities missing from the instance are written in full.
+            let is_new = !entity_map.contains_key(&entity.entity);
+            let previous_entity = previous
+                .entities
+                .iter()
+                .find(|e| e.entity == entity.entity)
+                .filter(|_| !is_new);
+            let mut components = Vec::new();
+            for component in &entity.components {
+                let unchanged = previous_entity
+                    .and_then(|previous| find_same_type(&previous.components, component.as_ref()))
+                    .and_then(|previous| previous.reflect_partial_eq(component.as_ref()))
+                    .unwrap_or(false);
+                if !unchanged {
+                    let registration = registration_of(component.as_ref(), &registry)?;
+                    components.push(clone_reflect_value(component.as_ref(), registration));
+                }
+            }
+
+            // Remove the components that are no longer part of the entity.
+            if let (Some(previous_entity), Some(&world_entity)) =
+                (previous_entity, entity_map.get(&entity.entity))
+            {
+                for component in &previous_entity.components {
+                    if find_same_type(&entity.components, component.as_ref()).is_some() {
+                        continue;
+                    }
+                    let registration = registration_of(component.as_ref(), &registry)?;
+                    if let (Some(reflect_component), Ok(mut world_entity)) = (
+                        registration.data::<ReflectComponent>(),
+                        world.get_entity_mut(world_entity),
+                    ) {
+                        reflect_component.remove(&mut world_entity);
+                    }
+                }
+            }
+
+            // New entities are always part of the changes, so that they get spawned.
+            if !components.is_empty() || is_new {
+                changes.entities.push(DynamicEntity {
+                    entity: entity.entity,
+                    components,
+                });
+            }
+        }
+
+        // Despawn the entities that are no longer part of the scene.
+        for previous_entity in &previous.entities {
+            if self
+                .entities
+                .iter()
+                .any(|e| e.entity == previous_entity.entity)
+            {
+                continue;
+            }
+            let world_entity = entity_map.remove(&previous_entity.entity);
+            if let Some(Ok(world_entity)) = world_entity.map(|entity| world.get_entity_mut(entity))
+            {
+                world_entity.despawn();
+            }
+        }
+
+        for resource in &self.resources {
+            let unchanged = find_same_type(&previous.resources, resource.as_ref())
+                .and_then(|previous| previous.reflect_partial_eq(resource.as_ref()))
+                .unwrap_or(false);
+            if !unchanged {
+                let registration = registration_of(resource.as_ref(), &registry)?;
+                changes
+                    .resources
+                    .push(clone_reflect_value(resource.as_ref(), registration));
+            }
+        }
+        for resource in &previous.resources {
+            if find_same_type(&self.resources, resource.as_ref()).is_some() {
+                continue;
+            }
+            let registration = registration_of(resource.as_ref(), &registry)?;
+            if let Some(reflect_resource) = registration.data::<ReflectResource>() {
+                reflect_resource.remove(world);
+            }
+        }
+
+        drop(registry);
+        changes.write_to_world_with(world, entity_map, type_registry)
+    }
+
+    /// Resolves the inheritance of this scene, returning a copy of it that has no parent.
+    ///
+    /// The entities of the resolved scene are those of the (resolved) parent scene, with the
+    /// [`overrides`](Self::overrides) of this scene applied, followed by the entities of this scene.
+    /// Inherited entities keep their identifiers. The entities added by a variant keep the index of
+    /// their identifier, with a generation reserved to the variant's depth in the inheritance
+    /// chain (`u32::MAX` for the first variant, `u32::MAX - 1` for its variants, and so on). This
+    /// way, they keep their identifiers when an ancestor gains or loses entities, so that spawned
+    /// instances are updated in place. The resources of this scene replace the inherited resources
+    /// of the same type.
+    ///
+    /// This will return a [`SceneSpawnError`] if an ancestor scene isn't loaded, an override
+    /// doesn't match any inherited entity, the scenes reference each other in a cycle, or two
+    /// entities added by the same variant have the same index.
+    pub fn resolve(
+        &self,
+        scenes: &Assets<DynamicScene>,
+        type_registry: &TypeRegistry,
+    ) -> Result<DynamicScene, SceneSpawnError> {
+        self.resolve_internal(scenes, type_registry, &mut HashSet::default())
+            .map(|(resolved, _)| resolved)
+    }
+
+    /// Resolves this scene, also returning its number of ancestors.
+    fn resolve_internal(
+        &self,
+        scenes: &Assets<DynamicScene>,
+        type_registry: &TypeRegistry,
+        visited: &mut HashSet<AssetId<DynamicScene>>,
+    ) -> Result<(DynamicScene, u32), SceneSpawnError> {
+        let Some(parent) = &self.parent else {
+            let resolved = DynamicScene {
+                resources: clone_values(&self.resources, type_registry)?,
+                entities: self
+                    .entities
+                    .iter()
+                    .map(|entity| {
+                        Ok(DynamicEntity {
+                            entity: entity.entity,
+                            components: clone_values(&entity.components, type_registry)?,
+                        })
+                    })
+                    .collect::<Result<_, SceneSpawnError>>()?,
+                parent: None,
+                overrides: Vec::new(),
+            };
+            return Ok((resolved, 0));
+        };
+
+        let parent_id = parent.id();
+        if !visited.insert(parent_id) {
+            return Err(SceneSpawnError::CyclicInheritance { id: parent_id });
+        }
+        let (mut resolved, parent_depth) = scenes
+            .get(parent_id)
+            .ok_or(SceneSpawnError::NonExistentScene { id: parent_id })?
+            .resolve_internal(scenes, type_registry, visited)?;
+        let depth = parent_depth + 1;
+
+        // Apply the overrides to the inherited entities.
+        let paths = entity_paths(&resolved.entities);
+        for entity_override in &self.overrides {
+            let index = paths
+                .iter()
+                .position(|path| path.as_deref() == Some(entity_override.path.as_str()))
+                .ok_or_else(|| SceneSpawnError::OverrideTargetNotFound {
+                    path: entity_override.path.clone(),
+                })?;
+            let components = &mut resolved.entities[index].components;
+
+            for type_path in &entity_override.removed {
+                let registration = type_registry
+                    .get_with_type_path(type_path)
+                    .or_else(|| type_registry.get_with_short_type_path(type_path))
+                    .ok_or_else(|| SceneSpawnError::UnregisteredButReflectedType {
+                        type_path: type_path.clone(),
+                    })?;
+                components.retain(|component| {
+                    component
+                        .get_represented_type_info()
+                        .is_none_or(|info| info.type_id() != registration.type_id())
+                });
+            }
+
+            for component in &entity_override.components {
+                let registration = registration_of(component.as_ref(), type_registry)?;
+                let existing = components.iter_mut().find(|existing| {
+                    existing
+                        .get_represented_type_info()
+                        .is_some_and(|info| info.type_id() == registration.type_id())
+                });
+                match existing {
+                    Some(existing) => {
+                        if existing.try_apply(component.as_ref()).is_err() {
+                            *existing = clone_reflect_value(component.as_ref(), registration);
+                        }
+                    }
+                    None => components.push(clone_reflect_value(component.as_ref(), registration)),
+                }
+            }
+        }
+
+        // Add the entities of this scene in the generation reserved to its depth, so that their
+        // identifiers neither collide with the inherited ones nor depend on them.
+        let generation = u64::from(u32::MAX - depth) << 32;
+        let mut added_entities = EntityHashMap::default();
+        let mut added_indices: HashSet<u32> = HashSet::default();
+        for entity in &self.entities {
+            if !added_indices.insert(entity.entity.index()) {
+                return Err(SceneSpawnError::DuplicateVariantEntityIndex {
+                    entity: entity.entity,
+                });
+            }
+            // The row keeps the index, and the generation bits of a freshly created entity are zero.
+            let row = Entity::from_raw(entity.entity.row()).to_bits();
+            let added = Entity::from_bits(row | generation);
+            added_entities.insert(entity.entity, added);
+        }
+        let mut map_entities = |values: &mut Vec<Box<dyn PartialReflect>>| {
+            for value in values {
+                let registration = registration_of(value.as_ref(), type_registry)?;
+                if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+                    map_entities.map_entities(value.as_partial_reflect_mut(), &mut added_entities);
+                }
+            }
+            Ok::<_, SceneSpawnError>(())
+        };
+        let mut entities = Vec::with_capacity(self.entities.len());
+        for entity in &self.entities {
+            let mut components = clone_values(&entity.components, type_registry)?;
+            map_entities(&mut components)?;
+            entities.push((entity.entity, components));
+        }
+        let mut resources = clone_values(&self.resources, type_registry)?;
+        map_entities(&mut resources)?;
+        for (entity, components) in entities {
+            resolved.entities.push(DynamicEntity {
+                entity: added_entities[&entity],
+                components,
+            });
+        }
+
+        for resource in resources {
+            let type_id = resource.get_represented_type_info().map(TypeInfo::type_id);
+            resolved.resources.retain(|existing| {
+                existing.get_represented_type_info().map(TypeInfo::type_id) != type_id
+            });
+            resolved.resources.push(resource);
+        }
+
+        Ok((resolved, depth))
+    }
+
     // TODO: move to AssetSaver when it is implemented
     /// Serialize this dynamic scene into the official Bevy scene format (`.scn` / `.scn.ron`).
     ///
@@ -201,6 +548,87 @@ impl DynamicScene {
     }
 }
 
+/// Returns the type registration of the type represented by `value`.
+fn registration_of<'a>(
+    value: &dyn PartialReflect,
+    type_registry: &'a TypeRegistry,
+) -> Result<&'a TypeRegistration, SceneSpawnError> {
+    let type_info =
+        value
+            .get_represented_type_info()
+            .ok_or_else(|| SceneSpawnError::NoRepresentedType {
+                type_path: value.reflect_type_path().to_string(),
+            })?;
+    type_registry.get(type_info.type_id()).ok_or_else(|| {
+        SceneSpawnError::UnregisteredButReflectedType {
+            type_path: type_info.type_path().to_string(),
+        }
+    })
+}
+
+/// Returns the value in `values` of the same type as `value`, if any.
+fn find_same_type<'a>(
+    values: &'a [Box<dyn PartialReflect>],
+    value: &dyn PartialReflect,
+) -> Option<&'a dyn PartialReflect> {
+    let type_id = value.get_represented_type_info()?.type_id();
+    values
+        .iter()
+        .find(|v| {
+            v.get_represented_type_info()
+                .is_some_and(|info| info.type_id() == type_id)
+        })
+        .map(AsRef::as_ref)
+}
+
+fn clone_values(
+    values: &[Box<dyn PartialReflect>],
+    type_registry: &TypeRegistry,
+) -> Result<Vec<Box<dyn PartialReflect>>, SceneSpawnError> {
+    values
+        .iter()
+        .map(|value| {
+            let registration = registration_of(value.as_ref(), type_registry)?;
+            Ok(clone_reflect_value(value.as_ref(), registration))
+        })
+        .collect()
+}
+
+/// Computes the [`Name`] path of each entity within the scene, see [`EntityOverride::path`].
+///
+/// Entities without a name, or with an unnamed ancestor, have no path.
+fn entity_paths(entities: &[DynamicEntity]) -> Vec<Option<String>> {
+    (0..entities.len())
+        .map(|index| {
+            let mut segments = Vec::new();
+            let mut current = Some(&entities[index]);
+            while let Some(entity) = current {
+                // Guard against malformed hierarchies containing cycles.
+                if segments.len() == entities.len() {
+                    return None;
+                }
+                segments.push(find_component::<Name>(entity)?);
+                current = find_component::<ChildOf>(entity)
+                    .and_then(|child_of| entities.iter().find(|e| e.entity == child_of.parent()));
+            }
+            let segments: Vec<&str> = segments.iter().rev().map(Name::as_str).collect();
+            Some(segments.join("/"))
+        })
+        .collect()
+}
+
+pub(crate) fn find_component<T: FromReflect>(entity: &DynamicEntity) -> Option<T> {
+    entity
+        .components
+        .iter()
+        .find(|component| {
+            component
+                .get_represented_type_info()
+                .is_some_and(TypeInfo::is::<T>)
+        })
+        .and_then(|component| T::from_reflect(component.as_partial_reflect()))
+}
+
 /// Serialize a given Rust data structure into rust object notation (ron).
 #[cfg(feature = "serialize")]
 pub fn serialize_ron<S>(serialize: S) -> Result<String, ron::Error>
@@ -228,6 +656,9 @@ mod tests {
 
     use crate::dynamic_scene::DynamicScene;
     use crate::dynamic_scene_builder::DynamicSceneBuilder;
+    use crate::{EntityOverride, SceneSpawnError};
+    use bevy_asset::{Assets, Handle};
+    use bevy_ecs::name::Name;
 
     #[derive(Resource, Reflect, MapEntities, Debug)]
     #[reflect(Resource, MapEntities)]
@@ -396,4 +827,101 @@ mod tests {
             .write_to_world(&mut dst_world, &mut Default::default())
             .unwrap();
     }
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Patrol;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Boss;
+
+    fn inheritance_world() -> (World, Handle<DynamicScene>) {
+        let type_registry = AppTypeRegistry::default();
+        {
+            let mut registry = type_registry.write();
+            registry.register::<Name>();
+            registry.register::<ChildOf>();
+            registry.register::<Health>();
+            registry.register::<Patrol>();
+            registry.register::<Boss>();
+        }
+
+        let mut parent_world = World::new();
+        parent_world.insert_resource(type_registry.clone());
+        let enemy = parent_world
+            .spawn((Name::new("Enemy"), Health(100), Patrol))
+            .id();
+        parent_world.spawn((Name::new("Weapon"), ChildOf(enemy)));
+        let parent = DynamicScene::from_world(&parent_world);
+
+        let mut world = World::new();
+        world.insert_resource(type_registry);
+        world.init_resource::<Assets<DynamicScene>>();
+        let handle = world.resource_mut::<Assets<DynamicScene>>().add(parent);
+        (world, handle)
+    }
+
+    #[test]
+    fn variant_overrides_inherited_entities() {
+        let (mut world, parent) = inheritance_world();
+
+        let mut variant_world = World::new();
+        variant_world.insert_resource(world.resource::<AppTypeRegistry>().clone());
+        variant_world.spawn(Name::new("Minion"));
+        let mut variant = DynamicScene::from_world(&variant_world);
+        variant.parent = Some(parent);
+        variant.overrides.push(
+            EntityOverride::new("Enemy")
+                .with_component(Health(200))
+                .with_component(Boss)
+                .without_component("Patrol"),
+        );
+        variant
+            .overrides
+            .push(EntityOverride::new("Enemy/Weapon").with_component(Health(5)));
+
+        let mut entity_map = EntityHashMap::default();
+        variant.write_to_world(&mut world, &mut entity_map).unwrap();
+        assert_eq!(entity_map.len(), 3);
+
+        let mut query = world.query::<(&Name, Option<&Health>)>();
+        let find = |world: &World, name: &str| {
+            entity_map
+                .values()
+                .copied()
+                .find(|&entity| world.get::<Name>(entity).unwrap().as_str() == name)
+                .unwrap()
+        };
+        let enemy = find(&world, "Enemy");
+        let weapon = find(&world, "Weapon");
+        find(&world, "Minion");
+
+        assert_eq!(query.get(&world, enemy).unwrap().1, Some(&Health(200)));
+        assert!(world.get::<Boss>(enemy).is_some());
+        assert!(world.get::<Patrol>(enemy).is_none());
+        assert_eq!(query.get(&world, weapon).unwrap().1, Some(&Health(5)));
+        assert_eq!(world.get::<ChildOf>(weapon).unwrap().parent(), enemy);
+    }
+
+    #[test]
+    fn variant_override_must_match_an_entity() {
+        let (mut world, parent) = inheritance_world();
+
+        let variant = DynamicScene {
+            parent: Some(parent),
+            overrides: vec![EntityOverride::new("Weapon").with_component(Boss)],
+            ..Default::default()
+        };
+
+        let result = variant.write_to_world(&mut world, &mut EntityHashMap::default());
+        assert!(matches!(
+            result,
+            Err(SceneSpawnError::OverrideTargetNotFound { path }) if path == "Weapon"
+        ));
+    }
 }
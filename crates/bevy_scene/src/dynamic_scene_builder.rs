@@ -4,14 +4,15 @@ use crate::reflect_utils::clone_reflect_value;
 use crate::{DynamicEntity, DynamicScene, SceneFilter};
 use alloc::collections::BTreeMap;
 use bevy_ecs::{
-    component::{Component, ComponentId},
+    component::{Component, ComponentId, ComponentInfo},
     entity_disabling::DefaultQueryFilters,
     prelude::Entity,
     reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
     resource::Resource,
     world::World,
 };
-use bevy_reflect::PartialReflect;
+use bevy_platform::collections::HashSet;
+use bevy_reflect::{PartialReflect, ReflectFromPtr, TypeRegistry};
 use bevy_utils::default;
 
 /// A [`DynamicScene`] builder, used to build a scene from a [`World`] by extracting some entities and resources.
@@ -213,6 +214,8 @@ impl<'w> DynamicSceneBuilder<'w> {
         DynamicScene {
             resources: self.extracted_resources.into_values().collect(),
             entities: self.extracted_scene.into_values().collect(),
+            parent: None,
+            overrides: Vec::new(),
         }
     }
 
@@ -235,6 +238,77 @@ impl<'w> DynamicSceneBuilder<'w> {
         self
     }
 
+    /// Removes the extracted components that are [required](Component#required-components) by another
+    /// extracted component of the same entity, and equal to the value constructed for them when they
+    /// are required.
+    ///
+    /// When the scene is spawned, these components are added back by the components requiring them,
+    /// so removing them makes serialized scenes shorter and easier to diff. The constructed values,
+    /// including the ones of custom constructors such as `#[require(T(expr))]`, are obtained from the
+    /// [`RequiredComponents`](bevy_ecs::component::RequiredComponents) of the requiring components,
+    /// without running any hooks or observers. Components whose type isn't registered with
+    /// [`ReflectFromPtr`] type data, and components required with constructors that can only write
+    /// to a [`World`], are kept.
+    #[must_use]
+    pub fn remove_default_required_components(mut self) -> Self {
+        let type_registry = self.original_world.resource::<AppTypeRegistry>().read();
+        let components = self.original_world.components();
+
+        for entity in self.extracted_scene.values_mut() {
+            let infos: Vec<Option<&ComponentInfo>> = entity
+                .components
+                .iter()
+                .map(|component| {
+                    component
+                        .get_represented_type_info()
+                        .and_then(|info| components.get_id(info.type_id()))
+                        .and_then(|id| components.get_info(id))
+                })
+                .collect();
+            let required: HashSet<ComponentId> = infos
+                .iter()
+                .flatten()
+                .flat_map(|info| info.required_components().iter_ids())
+                .collect();
+
+            // Start by removing all the required components, and keep the ones that don't match the
+            // constructed values until the remaining ones all do.
+            let mut removed: Vec<bool> = infos
+                .iter()
+                .map(|info| info.is_some_and(|info| required.contains(&info.id())))
+                .collect();
+            loop {
+                let mut changed = false;
+                for index in 0..removed.len() {
+                    if removed[index]
+                        && !matches_required_value(
+                            &entity.components,
+                            &infos,
+                            &removed,
+                            index,
+                            &type_registry,
+                        )
+                    {
+                        removed[index] = false;
+                        changed = true;
+                    }
+                }
+                if !changed {
+                    break;
+                }
+            }
+
+            entity.components = core::mem::take(&mut entity.components)
+                .into_iter()
+                .zip(removed)
+                .filter(|(_, removed)| !removed)
+                .map(|(component, _)| component)
+                .collect();
+        }
+
+        self
+    }
+
     /// Extract entities from the builder's [`World`].
     ///
     /// Re-extracting an entity that was already extracted will have no effect.
@@ -388,22 +462,61 @@ impl<'w> DynamicSceneBuilder<'w> {
             extract_and_push();
         }
 
-        drop(type_registry);
         self
     }
 }
 
+/// Returns whether the component at `index` in `components` is equal to the value constructed for
+/// it by the components that are kept, the ones not flagged in `removed`.
+///
+/// Components are inserted in order when a scene is spawned, so the value of a required component
+/// is constructed by the first kept component requiring it.
+fn matches_required_value(
+    components: &[Box<dyn PartialReflect>],
+    infos: &[Option<&ComponentInfo>],
+    removed: &[bool],
+    index: usize,
+    type_registry: &TypeRegistry,
+) -> bool {
+    let Some(info) = infos[index] else {
+        return false;
+    };
+    let Some(reflect_from_ptr) = info
+        .type_id()
+        .and_then(|type_id| type_registry.get_type_data::<ReflectFromPtr>(type_id))
+    else {
+        return false;
+    };
+    let Some(required) = infos
+        .iter()
+        .zip(removed)
+        .filter(|(_, removed)| !**removed)
+        .find_map(|(requiree, _)| {
+            requiree.and_then(|requiree| requiree.required_components().get(info.id()))
+        })
+    else {
+        return false;
+    };
+
+    let mut matches = false;
+    required.with_reflect_value(reflect_from_ptr, |value| {
+        matches = components[index].reflect_partial_eq(value.as_partial_reflect()) == Some(true);
+    });
+    matches
+}
+
 #[cfg(test)]
 mod tests {
     use bevy_ecs::{
         component::Component,
+        lifecycle::HookContext,
         prelude::{Entity, Resource},
         query::With,
         reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
-        world::World,
+        world::{DeferredWorld, World},
     };
 
-    use bevy_reflect::Reflect;
+    use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 
     use super::DynamicSceneBuilder;
 
@@ -415,6 +528,34 @@ mod tests {
     #[reflect(Component)]
     struct ComponentB;
 
+    #[derive(Component, Reflect, Default, Eq, PartialEq, Debug)]
+    #[reflect(Component, Default)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Default, Eq, PartialEq, Debug)]
+    #[reflect(Component, Default)]
+    #[require(Health)]
+    struct Enemy;
+
+    #[derive(Component, Reflect, Default, Eq, PartialEq, Debug)]
+    #[reflect(Component, Default)]
+    #[require(Health(10))]
+    struct Boss;
+
+    #[derive(Component, Reflect, Default, Eq, PartialEq, Debug)]
+    #[reflect(Component, Default)]
+    #[component(on_add = scale_health)]
+    #[require(Health(10))]
+    struct Elite;
+
+    #[derive(Resource)]
+    struct Difficulty(u32);
+
+    fn scale_health(mut world: DeferredWorld, context: HookContext) {
+        let difficulty = world.resource::<Difficulty>().0;
+        world.get_mut::<Health>(context.entity).unwrap().0 *= difficulty;
+    }
+
     #[derive(Resource, Reflect, Default, Eq, PartialEq, Debug)]
     #[reflect(Resource)]
     struct ResourceA;
@@ -735,4 +876,78 @@ mod tests {
             .expect("resource should be concrete due to `FromReflect`")
             .is::<SomeType>());
     }
+
+    #[test]
+    fn remove_default_required_components() {
+        let mut world = World::default();
+
+        let atr = AppTypeRegistry::default();
+        atr.write().register::<Enemy>();
+        atr.write().register::<Boss>();
+        atr.write().register::<Health>();
+        world.insert_resource(atr);
+
+        let default_health = world.spawn(Enemy).id();
+        let custom_health = world.spawn((Enemy, Health(5))).id();
+        let not_required = world.spawn(Health(0)).id();
+        let constructed_health = world.spawn(Boss).id();
+        let boss_default_health = world.spawn((Boss, Health(0))).id();
+
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entities(
+                [
+                    default_health,
+                    custom_health,
+                    not_required,
+                    constructed_health,
+                    boss_default_health,
+                ]
+                .into_iter(),
+            )
+            .remove_default_required_components()
+            .build();
+
+        let components = |entity| {
+            let mut components = scene
+                .entities
+                .iter()
+                .find(|scene_entity| scene_entity.entity == entity)
+                .unwrap()
+                .components
+                .iter()
+                .map(|component| component.reflect_short_type_path())
+                .collect::<Vec<_>>();
+            components.sort();
+            components
+        };
+        assert_eq!(components(default_health), vec!["Enemy"]);
+        assert_eq!(components(custom_health), vec!["Enemy", "Health"]);
+        assert_eq!(components(not_required), vec!["Health"]);
+        // The required component is compared to the value of its constructor, not to its default.
+        assert_eq!(components(constructed_health), vec!["Boss"]);
+        assert_eq!(components(boss_default_health), vec!["Boss", "Health"]);
+    }
+
+    #[test]
+    fn remove_default_required_components_without_hooks() {
+        let mut world = World::default();
+
+        let atr = AppTypeRegistry::default();
+        atr.write().register::<Elite>();
+        atr.write().register::<Health>();
+        world.insert_resource(atr);
+        world.insert_resource(Difficulty(2));
+
+        // The hook of `Elite` needs a resource, and changes the constructed health.
+        let elite = world.spawn(Elite).id();
+        assert_eq!(world.get::<Health>(elite), Some(&Health(20)));
+
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entity(elite)
+            .remove_default_required_components()
+            .build();
+
+        // The health is compared to the value of the constructor, without running the hook.
+        assert_eq!(scene.entities[0].components.len(), 2);
+    }
 }
@@ -12,6 +12,8 @@
 
 extern crate alloc;
 
+#[cfg(feature = "serialize")]
+mod chunked_scene;
 mod components;
 mod dynamic_scene;
 mod dynamic_scene_builder;
@@ -21,12 +23,16 @@ mod scene_filter;
 mod scene_loader;
 mod scene_spawner;
 
+#[cfg(feature = "serialize")]
+mod save_game;
 #[cfg(feature = "serialize")]
 pub mod serde;
 
 /// Rusty Object Notation, a crate used to serialize and deserialize bevy scenes.
 pub use bevy_asset::ron;
 
+#[cfg(feature = "serialize")]
+pub use chunked_scene::*;
 pub use components::*;
 pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
@@ -35,6 +41,9 @@ pub use scene_filter::*;
 pub use scene_loader::*;
 pub use scene_spawner::*;
 
+#[cfg(feature = "serialize")]
+pub use save_game::*;
+
 /// The scene prelude.
 ///
 /// This includes the most common types in this crate, re-exported for your convenience.
@@ -60,11 +69,17 @@ impl Plugin for ScenePlugin {
     fn build(&self, app: &mut App) {
         app.init_asset::<DynamicScene>()
             .init_asset::<Scene>()
+            .init_asset::<ChunkedScene>()
             .init_asset_loader::<SceneLoader>()
+            .init_asset_loader::<ChunkedSceneLoader>()
             .init_resource::<SceneSpawner>()
             .register_type::<SceneRoot>()
             .register_type::<DynamicSceneRoot>()
-            .add_systems(SpawnScene, (scene_spawner, scene_spawner_system).chain());
+            .register_type::<StableId>()
+            .add_systems(
+                SpawnScene,
+                (scene_spawner, scene_spawner_system, scene_streamer_system).chain(),
+            );
 
         // Register component hooks for DynamicSceneRoot
         app.world_mut()
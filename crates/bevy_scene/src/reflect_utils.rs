@@ -1,3 +1,7 @@
+use bevy_ecs::{
+    entity::EntityMapper,
+    reflect::{ReflectComponent, ReflectMapEntities},
+};
 use bevy_reflect::{PartialReflect, ReflectFromReflect, TypeRegistration};
 
 /// Attempts to clone a [`PartialReflect`] value using various methods.
@@ -23,3 +27,21 @@ pub(super) fn clone_reflect_value(
                 .unwrap_or_else(|| value.to_dynamic())
         })
 }
+
+/// Maps the entities referenced by `value` with `mapper`.
+///
+/// Components are mapped with [`ReflectComponent::map_entities`], like when they are written to a
+/// world, and other values with [`ReflectMapEntities`].
+pub(super) fn map_entity_references(
+    value: &mut dyn PartialReflect,
+    type_registration: &TypeRegistration,
+    mapper: &mut dyn EntityMapper,
+) {
+    if let Some(reflect_component) = type_registration.data::<ReflectComponent>() {
+        if let Some(value) = value.try_as_reflect_mut() {
+            reflect_component.map_entities(value, mapper);
+        }
+    } else if let Some(map_entities) = type_registration.data::<ReflectMapEntities>() {
+        map_entities.map_entities(value, mapper);
+    }
+}
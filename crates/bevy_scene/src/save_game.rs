@@ -0,0 +1,788 @@
+//! Saving and loading the state of a game to named save slots.
+
+use crate::{
+    ron,
+    serde::{SceneDeserializer, SceneSerializer},
+    serialize_ron, DynamicScene, DynamicSceneBuilder, SceneFilter, SceneSpawnError,
+};
+use alloc::sync::Arc;
+use bevy_app::{App, Last, Plugin};
+use bevy_asset::{
+    io::{
+        AssetReaderError, AssetSourceId, AssetWriterError, MissingAssetSourceError,
+        MissingAssetWriterError,
+    },
+    AssetServer,
+};
+use bevy_ecs::{
+    component::Component,
+    entity::{Entity, EntityHashMap},
+    event::{BufferedEvent, Event, EventCursor, Events},
+    query::With,
+    reflect::{AppTypeRegistry, ReflectComponent},
+    resource::Resource,
+    system::Local,
+    world::World,
+};
+use bevy_reflect::{prelude::ReflectDefault, Reflect, TypeRegistry};
+use bevy_tasks::{futures::check_ready, IoTaskPool, Task};
+use core::fmt::Formatter;
+use serde::{
+    de::{DeserializeSeed, Error as _, MapAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::path::PathBuf;
+use thiserror::Error;
+use tracing::error;
+
+/// Marks an entity to be included in saved games.
+///
+/// When a game is loaded, all entities with this component are despawned and replaced by the
+/// entities of the save.
+#[derive(Component, Reflect, Default, Clone, Copy, Debug)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct Persist;
+
+/// Adds saving and loading games to named save slots.
+///
+/// Send a [`SaveGame`] event to save all entities marked with [`Persist`] and the resources allowed by
+/// [`SaveGameSettings::resource_filter`], and a [`LoadGame`] event to restore them. Saves are written
+/// and read through the [`AssetWriter`](bevy_asset::io::AssetWriter) and
+/// [`AssetReader`](bevy_asset::io::AssetReader) of [`SaveGameSettings::source`], in the background.
+/// The outcome is reported with the [`GameSaved`], [`GameLoaded`] and [`SaveGameFailed`] events.
+///
+/// This requires the [`AssetPlugin`](bevy_asset::AssetPlugin). The saved components and resources
+/// must be registered in the [`AppTypeRegistry`], and reflect [`Component`] or [`Resource`] respectively.
+#[derive(Default)]
+pub struct SaveGamePlugin {
+    /// The initial [`SaveGameSettings`].
+    pub settings: SaveGameSettings,
+}
+
+impl Plugin for SaveGamePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Persist>()
+            .insert_resource(self.settings.clone())
+            .init_resource::<SaveGameTasks>()
+            .add_event::<SaveGame>()
+            .add_event::<LoadGame>()
+            .add_event::<GameSaved>()
+            .add_event::<GameLoaded>()
+            .add_event::<SaveGameFailed>()
+            .add_systems(Last, save_game_system);
+    }
+}
+
+/// Configures how games are saved and loaded by the [`SaveGamePlugin`].
+#[derive(Resource, Clone, Debug)]
+pub struct SaveGameSettings {
+    /// The asset source the saves are written to and read from.
+    ///
+    /// The source needs an [`AssetWriter`](bevy_asset::io::AssetWriter) to save games.
+    pub source: AssetSourceId<'static>,
+    /// The directory of the saves within [`source`](Self::source).
+    pub directory: PathBuf,
+    /// The version of the game's save format, stored in the [`SaveMetadata`] of each save.
+    ///
+    /// Loading a save with a greater version fails with [`SaveGameError::UnsupportedVersion`].
+    pub version: u32,
+    /// Selects the components of [`Persist`] entities that are saved.
+    pub component_filter: SceneFilter,
+    /// Selects the resources that are saved. No resources are saved by default.
+    pub resource_filter: SceneFilter,
+}
+
+impl Default for SaveGameSettings {
+    fn default() -> Self {
+        Self {
+            source: AssetSourceId::Default,
+            directory: PathBuf::from("saves"),
+            version: 0,
+            component_filter: SceneFilter::allow_all(),
+            resource_filter: SceneFilter::deny_all(),
+        }
+    }
+}
+
+impl SaveGameSettings {
+    /// Returns the path of the file the save `slot` is stored in, within [`source`](Self::source).
+    ///
+    /// This fails with [`SaveGameError::InvalidSlot`] if the slot name is empty or contains a path
+    /// separator or `..`, so that saves can't be written outside of [`directory`](Self::directory).
+    pub fn slot_path(&self, slot: &str) -> Result<PathBuf, SaveGameError> {
+        if slot.is_empty() || slot.contains(['/', '\\', '\0']) || slot.contains("..") {
+            return Err(SaveGameError::InvalidSlot(slot.into()));
+        }
+        Ok(self.directory.join(format!("{slot}.save.ron")))
+    }
+
+    /// Reads the [`SaveMetadata`] of the save `slot`, without deserializing the saved entities and
+    /// resources. The whole save file is still read.
+    ///
+    /// This is useful to display the available saves to the player.
+    pub async fn read_metadata(
+        &self,
+        asset_server: &AssetServer,
+        slot: &str,
+    ) -> Result<SaveMetadata, SaveGameError> {
+        let bytes = read_save(asset_server, &self.source, self.slot_path(slot)?).await?;
+        let header: SaveHeader = ron::de::from_bytes(&bytes)?;
+        Ok(header.metadata)
+    }
+}
+
+/// Information stored at the start of each save.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaveMetadata {
+    /// The [`SaveGameSettings::version`] the game was saved with.
+    pub version: u32,
+    /// When the game was saved, in seconds since the Unix epoch.
+    ///
+    /// This is always `0` on platforms without access to the system time.
+    pub timestamp: u64,
+    /// An encoded image of the game at the time it was saved, such as a PNG.
+    pub screenshot: Option<Vec<u8>>,
+}
+
+/// Requests the [`SaveGamePlugin`] to save the game to a slot, replacing any previous save in it.
+#[derive(Event, BufferedEvent, Clone, Debug)]
+pub struct SaveGame {
+    /// The name of the save slot.
+    pub slot: String,
+    /// The screenshot stored in the [`SaveMetadata`].
+    pub screenshot: Option<Vec<u8>>,
+}
+
+impl SaveGame {
+    /// Creates a request to save the game to the given `slot`, without a screenshot.
+    pub fn new(slot: impl Into<String>) -> Self {
+        Self {
+            slot: slot.into(),
+            screenshot: None,
+        }
+    }
+}
+
+/// Requests the [`SaveGamePlugin`] to load the game saved in a slot.
+#[derive(Event, BufferedEvent, Clone, Debug)]
+pub struct LoadGame {
+    /// The name of the save slot.
+    pub slot: String,
+}
+
+impl LoadGame {
+    /// Creates a request to load the game saved in the given `slot`.
+    pub fn new(slot: impl Into<String>) -> Self {
+        Self { slot: slot.into() }
+    }
+}
+
+/// Sent when a [`SaveGame`] request has been written.
+#[derive(Event, BufferedEvent, Clone, Debug)]
+pub struct GameSaved {
+    /// The name of the save slot.
+    pub slot: String,
+}
+
+/// Sent when a [`LoadGame`] request has been applied to the world.
+#[derive(Event, BufferedEvent, Clone, Debug)]
+pub struct GameLoaded {
+    /// The name of the save slot.
+    pub slot: String,
+    /// The metadata of the loaded save.
+    pub metadata: SaveMetadata,
+    /// The entities spawned from the save.
+    pub entities: Vec<Entity>,
+}
+
+/// Sent when a [`SaveGame`] or [`LoadGame`] request fails.
+#[derive(Event, BufferedEvent, Clone, Debug)]
+pub struct SaveGameFailed {
+    /// The name of the save slot.
+    pub slot: String,
+    /// Whether the game was being saved or loaded.
+    pub operation: SaveGameOperation,
+    /// The error that occurred.
+    pub error: SaveGameError,
+}
+
+/// An operation of the [`SaveGamePlugin`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SaveGameOperation {
+    /// Saving a game.
+    Save,
+    /// Loading a game.
+    Load,
+}
+
+/// An error that occurs while saving or loading a game.
+#[derive(Error, Debug, Clone)]
+pub enum SaveGameError {
+    /// The name of the save slot is empty, or contains a path separator or `..`.
+    #[error("invalid save slot name `{0}`")]
+    InvalidSlot(String),
+    /// The [`SaveGameSettings::source`] does not exist.
+    #[error(transparent)]
+    MissingAssetSource(#[from] MissingAssetSourceError),
+    /// The [`SaveGameSettings::source`] can't be written to.
+    #[error(transparent)]
+    MissingAssetWriter(#[from] MissingAssetWriterError),
+    /// Writing the save failed.
+    #[error("failed to write the save: {0}")]
+    AssetWriterError(Arc<AssetWriterError>),
+    /// Reading the save failed.
+    #[error("failed to read the save: {0}")]
+    AssetReaderError(#[from] AssetReaderError),
+    /// Reading the save failed.
+    #[error("failed to read the save: {0}")]
+    Io(Arc<std::io::Error>),
+    /// The saved game could not be serialized.
+    #[error("failed to serialize the save: {0}")]
+    Serialize(#[from] ron::Error),
+    /// The save could not be deserialized.
+    #[error("failed to deserialize the save: {0}")]
+    Deserialize(#[from] ron::error::SpannedError),
+    /// The save was made by a newer version of the game.
+    #[error("the save has version {found}, but only versions up to {supported} are supported")]
+    UnsupportedVersion {
+        /// The version of the save.
+        found: u32,
+        /// The current [`SaveGameSettings::version`].
+        supported: u32,
+    },
+    /// The saved entities could not be spawned.
+    #[error("failed to spawn the save: {0}")]
+    Spawn(Arc<SceneSpawnError>),
+}
+
+impl From<AssetWriterError> for SaveGameError {
+    fn from(error: AssetWriterError) -> Self {
+        Self::AssetWriterError(Arc::new(error))
+    }
+}
+
+impl From<std::io::Error> for SaveGameError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+impl From<SceneSpawnError> for SaveGameError {
+    fn from(error: SceneSpawnError) -> Self {
+        Self::Spawn(Arc::new(error))
+    }
+}
+
+/// The saves and loads that are in progress.
+#[derive(Resource, Default)]
+struct SaveGameTasks {
+    saves: Vec<(String, Task<Result<(), SaveGameError>>)>,
+    loads: Vec<(String, Task<Result<Vec<u8>, SaveGameError>>)>,
+}
+
+/// System that starts the requested saves and loads, and applies the loads that have been read.
+pub fn save_game_system(
+    world: &mut World,
+    mut save_requests: Local<EventCursor<SaveGame>>,
+    mut load_requests: Local<EventCursor<LoadGame>>,
+) {
+    let settings = world.resource::<SaveGameSettings>().clone();
+    let asset_server = world.resource::<AssetServer>().clone();
+
+    let saves: Vec<SaveGame> = save_requests
+        .read(world.resource::<Events<SaveGame>>())
+        .cloned()
+        .collect();
+    for request in saves {
+        let path = match settings.slot_path(&request.slot) {
+            Ok(path) => path,
+            Err(error) => {
+                report_failure(world, request.slot, SaveGameOperation::Save, error);
+                continue;
+            }
+        };
+        let bytes = serialize_save(world, &settings, request.screenshot);
+        let source = settings.source.clone();
+        let server = asset_server.clone();
+        let task = IoTaskPool::get().spawn(async move {
+            let bytes = bytes?;
+            let writer = server.get_source(&source)?.writer()?;
+            writer.write_bytes(&path, &bytes).await?;
+            Ok(())
+        });
+        world
+            .resource_mut::<SaveGameTasks>()
+            .saves
+            .push((request.slot, task));
+    }
+
+    let loads: Vec<LoadGame> = load_requests
+        .read(world.resource::<Events<LoadGame>>())
+        .cloned()
+        .collect();
+    for request in loads {
+        let path = match settings.slot_path(&request.slot) {
+            Ok(path) => path,
+            Err(error) => {
+                report_failure(world, request.slot, SaveGameOperation::Load, error);
+                continue;
+            }
+        };
+        let source = settings.source.clone();
+        let server = asset_server.clone();
+        let task = IoTaskPool::get().spawn(async move { read_save(&server, &source, path).await });
+        world
+            .resource_mut::<SaveGameTasks>()
+            .loads
+            .push((request.slot, task));
+    }
+
+    let mut tasks = world.resource_mut::<SaveGameTasks>();
+    let mut finished_saves = Vec::new();
+    tasks
+        .saves
+        .retain_mut(|(slot, task)| match check_ready(task) {
+            Some(result) => {
+                finished_saves.push((core::mem::take(slot), result));
+                false
+            }
+            None => true,
+        });
+    let mut finished_loads = Vec::new();
+    tasks
+        .loads
+        .retain_mut(|(slot, task)| match check_ready(task) {
+            Some(result) => {
+                finished_loads.push((core::mem::take(slot), result));
+                false
+            }
+            None => true,
+        });
+
+    for (slot, result) in finished_saves {
+        match result {
+            Ok(()) => {
+                world.send_event(GameSaved { slot });
+            }
+            Err(error) => report_failure(world, slot, SaveGameOperation::Save, error),
+        }
+    }
+    for (slot, result) in finished_loads {
+        match result.and_then(|bytes| apply_save(world, &settings, &bytes)) {
+            Ok((metadata, entities)) => {
+                world.send_event(GameLoaded {
+                    slot,
+                    metadata,
+                    entities,
+                });
+            }
+            Err(error) => report_failure(world, slot, SaveGameOperation::Load, error),
+        }
+    }
+}
+
+fn report_failure(
+    world: &mut World,
+    slot: String,
+    operation: SaveGameOperation,
+    error: SaveGameError,
+) {
+    let verb = match operation {
+        SaveGameOperation::Save => "save",
+        SaveGameOperation::Load => "load",
+    };
+    error!("Failed to {verb} the game in slot `{slot}`: {error}");
+    world.send_event(SaveGameFailed {
+        slot,
+        operation,
+        error,
+    });
+}
+
+async fn read_save(
+    asset_server: &AssetServer,
+    source: &AssetSourceId<'static>,
+    path: PathBuf,
+) -> Result<Vec<u8>, SaveGameError> {
+    let mut reader = asset_server
+        .get_source(source)?
+        .reader()
+        .read(&path)
+        .await?;
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).await?;
+    Ok(bytes)
+}
+
+/// Serializes the [`Persist`] entities and the saved resources of the `world`.
+fn serialize_save(
+    world: &mut World,
+    settings: &SaveGameSettings,
+    screenshot: Option<Vec<u8>>,
+) -> Result<Vec<u8>, SaveGameError> {
+    let entities: Vec<Entity> = world
+        .query_filtered::<Entity, With<Persist>>()
+        .iter(world)
+        .collect();
+    let scene = DynamicSceneBuilder::from_world(world)
+        .with_component_filter(settings.component_filter.clone())
+        .with_resource_filter(settings.resource_filter.clone())
+        .extract_entities(entities.into_iter())
+        .extract_resources()
+        .build();
+
+    let metadata = SaveMetadata {
+        version: settings.version,
+        timestamp: current_timestamp(),
+        screenshot,
+    };
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let serialized = serialize_ron(SaveSerializer {
+        metadata: &metadata,
+        scene: SceneSerializer::new(&scene, &registry),
+    })?;
+    Ok(serialized.into_bytes())
+}
+
+/// Replaces the [`Persist`] entities of the `world` with the ones of the save, returning the spawned entities.
+///
+/// The `world` is left untouched if the save can't be spawned.
+fn apply_save(
+    world: &mut World,
+    settings: &SaveGameSettings,
+    bytes: &[u8],
+) -> Result<(SaveMetadata, Vec<Entity>), SaveGameError> {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let (metadata, scene) = {
+        let registry = registry.read();
+        let mut deserializer = ron::de::Deserializer::from_bytes(bytes)?;
+        SaveDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .map_err(|error| deserializer.span_error(error))?
+    };
+    if metadata.version > settings.version {
+        return Err(SaveGameError::UnsupportedVersion {
+            found: metadata.version,
+            supported: settings.version,
+        });
+    }
+
+    // Spawn the save in an empty world first, so that the current game isn't lost if it fails.
+    scene.write_to_world_with(&mut World::new(), &mut EntityHashMap::default(), &registry)?;
+
+    let persisted: Vec<Entity> = world
+        .query_filtered::<Entity, With<Persist>>()
+        .iter(world)
+        .collect();
+    for entity in persisted {
+        if let Ok(entity) = world.get_entity_mut(entity) {
+            entity.despawn();
+        }
+    }
+
+    // Entity references in the saved components and resources are remapped to the new entities
+    // through their `MapEntities` implementation.
+    let mut entity_map = EntityHashMap::default();
+    scene.write_to_world_with(world, &mut entity_map, &registry)?;
+    let entities: Vec<Entity> = scene
+        .entities
+        .iter()
+        .map(|entity| entity_map[&entity.entity])
+        .collect();
+    for &entity in &entities {
+        world.entity_mut(entity).insert(Persist);
+    }
+    Ok((metadata, entities))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn current_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn current_timestamp() -> u64 {
+    0
+}
+
+/// Name of the serialized save struct type.
+const SAVE_STRUCT: &str = "SaveGame";
+/// Name of the serialized metadata field in a save struct.
+const SAVE_METADATA: &str = "metadata";
+/// Name of the serialized scene field in a save struct.
+const SAVE_SCENE: &str = "scene";
+
+struct SaveSerializer<'a> {
+    metadata: &'a SaveMetadata,
+    scene: SceneSerializer<'a>,
+}
+
+impl<'a> Serialize for SaveSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(SAVE_STRUCT, 2)?;
+        state.serialize_field(SAVE_METADATA, self.metadata)?;
+        state.serialize_field(SAVE_SCENE, &self.scene)?;
+        state.end()
+    }
+}
+
+/// The start of a save, used to read its metadata only.
+#[derive(Deserialize)]
+struct SaveHeader {
+    metadata: SaveMetadata,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum SaveField {
+    Metadata,
+    Scene,
+}
+
+struct SaveDeserializer<'a> {
+    type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SaveDeserializer<'a> {
+    type Value = (SaveMetadata, DynamicScene);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(SAVE_STRUCT, &[SAVE_METADATA, SAVE_SCENE], self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for SaveDeserializer<'a> {
+    type Value = (SaveMetadata, DynamicScene);
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("save struct")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut metadata = None;
+        let mut scene = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                SaveField::Metadata => {
+                    if metadata.is_some() {
+                        return Err(A::Error::duplicate_field(SAVE_METADATA));
+                    }
+                    metadata = Some(map.next_value()?);
+                }
+                SaveField::Scene => {
+                    if scene.is_some() {
+                        return Err(A::Error::duplicate_field(SAVE_SCENE));
+                    }
+                    scene = Some(map.next_value_seed(SceneDeserializer {
+                        type_registry: self.type_registry,
+                        load_parent: None,
+                    })?);
+                }
+            }
+        }
+
+        let metadata = metadata.ok_or_else(|| A::Error::missing_field(SAVE_METADATA))?;
+        let scene = scene.ok_or_else(|| A::Error::missing_field(SAVE_SCENE))?;
+        Ok((metadata, scene))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader, MemoryAssetWriter},
+            AssetSource, AssetSourceId,
+        },
+        AssetApp, AssetPlugin,
+    };
+    use bevy_ecs::{
+        entity::MapEntities,
+        event::BufferedEvent,
+        reflect::{ReflectMapEntities, ReflectResource},
+    };
+    use bevy_tasks::block_on;
+    use std::path::Path;
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, MapEntities)]
+    #[reflect(Component, MapEntities)]
+    struct Target(#[entities] Entity);
+
+    #[derive(Resource, Reflect, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    /// A registered type that can't be spawned as a component.
+    #[derive(Reflect)]
+    struct NotAComponent(u32);
+
+    fn test_app(dir: Dir) -> App {
+        let mut app = App::new();
+        let reader = MemoryAssetReader { root: dir.clone() };
+        let writer = MemoryAssetWriter { root: dir };
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(reader.clone()))
+                .with_writer(move |_| Some(Box::new(writer.clone()))),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            SaveGamePlugin {
+                settings: SaveGameSettings {
+                    version: 2,
+                    resource_filter: SceneFilter::deny_all().allow::<Score>(),
+                    ..Default::default()
+                },
+            },
+        ))
+        .register_type::<Health>()
+        .register_type::<Target>()
+        .register_type::<Score>()
+        .register_type::<NotAComponent>();
+        app
+    }
+
+    /// Updates the app until an event of type `E` is sent.
+    fn run_until<E: BufferedEvent + Clone>(app: &mut App) -> E {
+        let mut cursor = app.world().resource::<Events<E>>().get_cursor_current();
+        for _ in 0..100 {
+            app.update();
+            if let Some(event) = cursor.read(app.world().resource::<Events<E>>()).next() {
+                return event.clone();
+            }
+        }
+        panic!("the expected event was not sent");
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let dir = Dir::default();
+        let mut app = test_app(dir.clone());
+
+        let world = app.world_mut();
+        let player = world.spawn((Persist, Health(10))).id();
+        world.spawn((Persist, Health(5), Target(player)));
+        let unsaved = world.spawn(Health(1)).id();
+        world.insert_resource(Score(42));
+
+        app.world_mut().send_event(SaveGame {
+            slot: "slot_1".into(),
+            screenshot: Some(vec![1, 2, 3]),
+        });
+        let saved = run_until::<GameSaved>(&mut app);
+        assert_eq!(saved.slot, "slot_1");
+        assert!(dir.get_asset(Path::new("saves/slot_1.save.ron")).is_some());
+
+        // Change the world after saving.
+        let world = app.world_mut();
+        world.get_mut::<Health>(player).unwrap().0 = 0;
+        world.spawn((Persist, Health(99)));
+        world.insert_resource(Score(0));
+
+        app.world_mut().send_event(LoadGame::new("slot_1"));
+        let loaded = run_until::<GameLoaded>(&mut app);
+        assert_eq!(loaded.metadata.version, 2);
+        assert_eq!(loaded.metadata.screenshot, Some(vec![1, 2, 3]));
+        assert_eq!(loaded.entities.len(), 2);
+
+        let world = app.world_mut();
+        // Persisted entities are replaced, other entities are left untouched.
+        assert!(world.get_entity(player).is_err());
+        assert_eq!(world.get::<Health>(unsaved), Some(&Health(1)));
+        let mut healths: Vec<u32> = world
+            .query_filtered::<&Health, With<Persist>>()
+            .iter(world)
+            .map(|health| health.0)
+            .collect();
+        healths.sort();
+        assert_eq!(healths, vec![5, 10]);
+        assert_eq!(world.resource::<Score>(), &Score(42));
+
+        // Entity references are remapped to the loaded entities.
+        let target = world.query::<&Target>().single(world).unwrap().0;
+        assert!(loaded.entities.contains(&target));
+        assert_eq!(world.get::<Health>(target), Some(&Health(10)));
+
+        let settings = world.resource::<SaveGameSettings>().clone();
+        let asset_server = world.resource::<AssetServer>().clone();
+        let metadata = block_on(settings.read_metadata(&asset_server, "slot_1")).unwrap();
+        assert_eq!(metadata, loaded.metadata);
+    }
+
+    #[test]
+    fn load_failures() {
+        let dir = Dir::default();
+        let mut app = test_app(dir.clone());
+
+        app.world_mut().send_event(LoadGame::new("missing"));
+        let failed = run_until::<SaveGameFailed>(&mut app);
+        assert_eq!(failed.operation, SaveGameOperation::Load);
+        assert!(matches!(
+            failed.error,
+            SaveGameError::AssetReaderError(AssetReaderError::NotFound(_))
+        ));
+
+        dir.insert_asset(
+            Path::new("saves/future.save.ron"),
+            r#"(metadata: (version: 3, timestamp: 0, screenshot: None), scene: (resources: {}, entities: {}))"#
+                .as_bytes()
+                .to_vec(),
+        );
+        app.world_mut().send_event(LoadGame::new("future"));
+        let failed = run_until::<SaveGameFailed>(&mut app);
+        assert!(matches!(
+            failed.error,
+            SaveGameError::UnsupportedVersion {
+                found: 3,
+                supported: 2
+            }
+        ));
+
+        // A save that can't be spawned leaves the current game untouched.
+        let player = app.world_mut().spawn((Persist, Health(7))).id();
+        dir.insert_asset(
+            Path::new("saves/broken.save.ron"),
+            r#"(metadata: (version: 2, timestamp: 0, screenshot: None), scene: (resources: {}, entities: {
+                4294967295: (components: {"bevy_scene::save_game::tests::NotAComponent": (1)}),
+            }))"#
+                .as_bytes()
+                .to_vec(),
+        );
+        app.world_mut().send_event(LoadGame::new("broken"));
+        let failed = run_until::<SaveGameFailed>(&mut app);
+        assert!(matches!(failed.error, SaveGameError::Spawn(_)));
+        assert_eq!(app.world().get::<Health>(player), Some(&Health(7)));
+
+        for slot in ["../escape", "a/b", "a\\b", ".."] {
+            app.world_mut().send_event(SaveGame {
+                slot: slot.into(),
+                screenshot: None,
+            });
+            let failed = run_until::<SaveGameFailed>(&mut app);
+            assert!(matches!(failed.error, SaveGameError::InvalidSlot(_)));
+        }
+    }
+}
@@ -56,13 +56,16 @@ impl AssetLoader for SceneLoader {
         &self,
         reader: &mut dyn Reader,
         _settings: &(),
-        _load_context: &mut LoadContext<'_>,
+        load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
         let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
+        // Parents are written as full asset paths, see `DynamicScene::parent`.
+        let mut load_parent = |path: &str| load_context.load(path.to_owned());
         let scene_deserializer = SceneDeserializer {
             type_registry: &self.type_registry.read(),
+            load_parent: Some(&mut load_parent),
         };
         Ok(scene_deserializer
             .deserialize(&mut deserializer)
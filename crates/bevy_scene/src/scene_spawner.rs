@@ -40,6 +40,24 @@ pub struct InstanceInfo {
     pub entity_map: EntityHashMap<Entity>,
 }
 
+/// How [`SceneSpawner::update_spawned_scenes`] updates the instances of a modified dynamic scene.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SceneUpdateMode {
+    /// Writes the whole scene to the instances again.
+    ///
+    /// Every component of the scene is overwritten, including those that did not change. Components
+    /// and entities that were removed from the scene are left in the world.
+    #[default]
+    Reapply,
+    /// Only writes the differences between the previous and the new version of the scene to the
+    /// instances, see [`DynamicScene::write_changes_to_world`].
+    ///
+    /// Runtime state stored in components that did not change in the scene is preserved, and the
+    /// entities of the instances keep their identifiers. This keeps a copy of each spawned dynamic
+    /// scene around to compare it with its next version.
+    Reconcile,
+}
+
 /// Unique id identifying a scene instance.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Reflect)]
 #[reflect(Debug, PartialEq, Hash, Clone)]
@@ -81,6 +99,10 @@ pub struct SceneSpawner {
     instances_to_despawn: Vec<InstanceId>,
     scenes_with_parent: Vec<(InstanceId, Entity)>,
     instances_ready: Vec<(InstanceId, Option<Entity>)>,
+    update_mode: SceneUpdateMode,
+    /// The versions of the spawned dynamic scenes that their instances currently reflect, used by
+    /// [`SceneUpdateMode::Reconcile`].
+    scene_snapshots: HashMap<AssetId<DynamicScene>, DynamicScene>,
 }
 
 /// Errors that can occur when spawning a scene.
@@ -135,9 +157,46 @@ pub enum SceneSpawnError {
         /// Id of the non-existent scene.
         id: AssetId<Scene>,
     },
+    /// A scene override targets an entity path that does not exist in the parent scene.
+    #[error(
+        "scene override targets `{path}`, but the parent scene has no entity with this name path"
+    )]
+    OverrideTargetNotFound {
+        /// The name path targeted by the override.
+        path: String,
+    },
+    /// Two entities added by the same scene variant have the same index, so they can't be given
+    /// distinct identifiers when resolving the variant.
+    #[error("the scene variant contains several entities with the index of {entity}")]
+    DuplicateVariantEntityIndex {
+        /// One of the entities sharing the index.
+        entity: Entity,
+    },
+    /// A scene is (indirectly) a variant of itself.
+    #[error("scene inheritance contains a cycle through the scene {id}")]
+    CyclicInheritance {
+        /// Id of a dynamic scene that is part of the cycle.
+        id: AssetId<DynamicScene>,
+    },
 }
 
 impl SceneSpawner {
+    /// Returns how instances of modified dynamic scenes are updated.
+    pub fn update_mode(&self) -> SceneUpdateMode {
+        self.update_mode
+    }
+
+    /// Sets how instances of modified dynamic scenes are updated.
+    ///
+    /// When switching to [`SceneUpdateMode::Reconcile`], scenes that are already spawned are
+    /// reapplied on their next update, and reconciled on the following ones.
+    pub fn set_update_mode(&mut self, update_mode: SceneUpdateMode) {
+        self.update_mode = update_mode;
+        if update_mode != SceneUpdateMode::Reconcile {
+            self.scene_snapshots.clear();
+        }
+    }
+
     /// Schedule the spawn of a new instance of the provided dynamic scene.
     pub fn spawn_dynamic(&mut self, id: impl Into<Handle<DynamicScene>>) -> InstanceId {
         let instance_id = InstanceId::new();
@@ -200,7 +259,9 @@ impl SceneSpawner {
         world: &mut World,
         id: impl Into<AssetId<DynamicScene>>,
     ) -> Result<(), SceneSpawnError> {
-        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id.into()) {
+        let id = id.into();
+        self.scene_snapshots.remove(&id);
+        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id) {
             for instance_id in instance_ids {
                 self.despawn_instance_sync(world, &instance_id);
             }
@@ -228,6 +289,7 @@ impl SceneSpawner {
         let mut entity_map = EntityHashMap::default();
         let id = id.into();
         Self::spawn_dynamic_internal(world, id, &mut entity_map)?;
+        self.snapshot_scene(world, id);
         let instance_id = InstanceId::new();
         self.spawned_instances
             .insert(instance_id, InstanceInfo { entity_map });
@@ -241,6 +303,17 @@ impl SceneSpawner {
         id: AssetId<DynamicScene>,
         entity_map: &mut EntityHashMap<Entity>,
     ) -> Result<(), SceneSpawnError> {
+        let scenes = world.resource::<Assets<DynamicScene>>();
+        let scene = scenes
+            .get(id)
+            .ok_or(SceneSpawnError::NonExistentScene { id })?;
+        if scene.parent.is_some() {
+            // Variants are resolved into a standalone scene, which needs access to their ancestors.
+            let registry = world.resource::<AppTypeRegistry>().clone();
+            let resolved = scene.resolve(scenes, &registry.read())?;
+            return resolved.write_to_world_with(world, entity_map, &registry);
+        }
+
         world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
             let scene = scenes
                 .get(id)
@@ -250,6 +323,20 @@ impl SceneSpawner {
         })
     }
 
+    /// Stores the current version of the dynamic scene `id` if it is needed to reconcile its
+    /// instances later on and isn't stored yet.
+    fn snapshot_scene(&mut self, world: &World, id: AssetId<DynamicScene>) {
+        if self.update_mode != SceneUpdateMode::Reconcile || self.scene_snapshots.contains_key(&id)
+        {
+            return;
+        }
+        let scenes = world.resource::<Assets<DynamicScene>>();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        if let Some(Ok(scene)) = scenes.get(id).map(|scene| scene.resolve(scenes, &registry)) {
+            self.scene_snapshots.insert(id, scene);
+        }
+    }
+
     /// Immediately spawns a new instance of the provided scene.
     pub fn spawn_sync(
         &mut self,
@@ -286,19 +373,49 @@ impl SceneSpawner {
     /// Iterate through all instances of the provided scenes and update those immediately.
     ///
     /// Useful for updating already spawned scene instances after their corresponding scene has been modified.
+    /// How the instances are updated depends on the [`SceneUpdateMode`].
     pub fn update_spawned_scenes(
         &mut self,
         world: &mut World,
         scene_ids: &[AssetId<DynamicScene>],
     ) -> Result<(), SceneSpawnError> {
         for id in scene_ids {
-            if let Some(spawned_instances) = self.spawned_dynamic_scenes.get(id) {
+            let Some(spawned_instances) = self.spawned_dynamic_scenes.get(id) else {
+                continue;
+            };
+            let previous = match self.update_mode {
+                SceneUpdateMode::Reapply => None,
+                SceneUpdateMode::Reconcile => self.scene_snapshots.remove(id),
+            };
+            let Some(previous) = previous else {
                 for instance_id in spawned_instances {
                     if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
                         Self::spawn_dynamic_internal(world, *id, &mut instance_info.entity_map)?;
                     }
                 }
+                self.snapshot_scene(world, *id);
+                continue;
+            };
+
+            let registry = world.resource::<AppTypeRegistry>().clone();
+            let scene = {
+                let scenes = world.resource::<Assets<DynamicScene>>();
+                scenes
+                    .get(*id)
+                    .ok_or(SceneSpawnError::NonExistentScene { id: *id })?
+                    .resolve(scenes, &registry.read())?
+            };
+            for instance_id in spawned_instances {
+                if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
+                    scene.write_changes_to_world(
+                        &previous,
+                        world,
+                        &mut instance_info.entity_map,
+                        &registry,
+                    )?;
+                }
             }
+            self.scene_snapshots.insert(*id, scene);
         }
         Ok(())
     }
@@ -331,6 +448,7 @@ impl SceneSpawner {
 
             match Self::spawn_dynamic_internal(world, handle.id(), &mut entity_map) {
                 Ok(_) => {
+                    self.snapshot_scene(world, handle.id());
                     self.spawned_instances
                         .insert(instance_id, InstanceInfo { entity_map });
                     let spawned = self.spawned_dynamic_scenes.entry(handle.id()).or_default();
@@ -472,15 +590,23 @@ pub fn scene_spawner_system(world: &mut World) {
 
         let scene_asset_events = world.resource::<Events<AssetEvent<DynamicScene>>>();
 
-        let mut updated_spawned_scenes = Vec::new();
         let scene_spawner = &mut *scene_spawner;
-        for event in scene_spawner
+        let modified_scenes: HashSet<_> = scene_spawner
             .scene_asset_event_reader
             .read(scene_asset_events)
-        {
-            if let AssetEvent::Modified { id } = event {
-                if scene_spawner.spawned_dynamic_scenes.contains_key(id) {
-                    updated_spawned_scenes.push(*id);
+            .filter_map(|event| match event {
+                AssetEvent::Modified { id } => Some(*id),
+                _ => None,
+            })
+            .collect();
+
+        // Instances of scene variants need to be updated whenever one of their ancestors changes.
+        let mut updated_spawned_scenes = Vec::new();
+        if !modified_scenes.is_empty() {
+            let scenes = world.resource::<Assets<DynamicScene>>();
+            for &id in scene_spawner.spawned_dynamic_scenes.keys() {
+                if inherits_from_any(scenes, id, &modified_scenes) {
+                    updated_spawned_scenes.push(id);
                 }
             }
         }
@@ -498,6 +624,29 @@ pub fn scene_spawner_system(world: &mut World) {
     });
 }
 
+/// Returns `true` if the scene `id` or one of its ancestors is part of `scene_ids`.
+fn inherits_from_any(
+    scenes: &Assets<DynamicScene>,
+    id: AssetId<DynamicScene>,
+    scene_ids: &HashSet<AssetId<DynamicScene>>,
+) -> bool {
+    let mut visited = <HashSet<_>>::default();
+    let mut current = Some(id);
+    while let Some(id) = current {
+        if scene_ids.contains(&id) {
+            return true;
+        }
+        if !visited.insert(id) {
+            return false;
+        }
+        current = scenes
+            .get(id)
+            .and_then(|scene| scene.parent.as_ref())
+            .map(Handle::id);
+    }
+    false
+}
+
 /// [`InstanceId`] of a spawned scene. It can be used with the [`SceneSpawner`] to
 /// interact with the spawned scene.
 #[derive(Component, Deref, DerefMut)]
@@ -540,6 +689,7 @@ pub fn scene_spawner(
 mod tests {
     use bevy_app::App;
     use bevy_asset::{AssetPlugin, AssetServer, Handle};
+    use bevy_ecs::name::Name;
     use bevy_ecs::{
         component::Component,
         hierarchy::Children,
@@ -548,9 +698,9 @@ mod tests {
         query::With,
         system::{Commands, Query, Res, ResMut, RunSystemOnce},
     };
-    use bevy_reflect::Reflect;
+    use bevy_reflect::{DynamicStruct, Reflect, Typed};
 
-    use crate::{DynamicSceneBuilder, DynamicSceneRoot, ScenePlugin};
+    use crate::{DynamicSceneBuilder, DynamicSceneRoot, EntityOverride, ScenePlugin};
 
     use super::*;
     use crate::{DynamicScene, SceneSpawner};
@@ -680,6 +830,134 @@ mod tests {
         assert_eq!(old_a, new_a);
     }
 
+    #[test]
+    fn variant_instances_update_when_parent_is_modified() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<Name>()
+            .register_type::<ComponentA>();
+        app.update();
+
+        let mut parent_world = World::new();
+        parent_world.insert_resource(app.world().resource::<AppTypeRegistry>().clone());
+        parent_world.spawn((Name::new("Root"), ComponentA { x: 1.0, y: 1.0 }));
+        let parent = DynamicScene::from_world(&parent_world);
+
+        // Only override the `y` field of the inherited component.
+        let mut patch = DynamicStruct::default();
+        patch.set_represented_type(Some(<ComponentA as Typed>::type_info()));
+        patch.insert("y", 5.0f32);
+
+        let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+        let parent = scenes.add(parent);
+        let variant = scenes.add(DynamicScene {
+            parent: Some(parent.clone()),
+            overrides: vec![EntityOverride::new("Root").with_component(patch)],
+            ..Default::default()
+        });
+
+        let instance_id = app
+            .world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(variant.clone());
+        app.update();
+
+        let entity = app
+            .world()
+            .resource::<SceneSpawner>()
+            .iter_instance_entities(instance_id)
+            .next()
+            .unwrap();
+        let component = app.world().get::<ComponentA>(entity).unwrap();
+        assert_eq!((component.x, component.y), (1.0, 5.0));
+
+        // Modifying the parent updates the already spawned instances of the variant in place.
+        let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+        scenes.get_mut(&parent).unwrap().entities[0]
+            .components
+            .retain(|component| !component.represents::<ComponentA>());
+        scenes.get_mut(&parent).unwrap().entities[0]
+            .components
+            .push(Box::new(ComponentA { x: 2.0, y: 2.0 }));
+        // Asset events are only flushed at the end of the frame, so the instance updates on the next one.
+        app.update();
+        app.update();
+
+        assert_eq!(
+            app.world()
+                .resource::<SceneSpawner>()
+                .iter_instance_entities(instance_id)
+                .collect::<Vec<_>>(),
+            vec![entity]
+        );
+        let component = app.world().get::<ComponentA>(entity).unwrap();
+        assert_eq!((component.x, component.y), (2.0, 5.0));
+    }
+
+    #[test]
+    fn variant_entities_are_stable_when_parent_gains_entities() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<Name>()
+            .register_type::<ComponentA>();
+        app.update();
+
+        let registry = app.world().resource::<AppTypeRegistry>().clone();
+        let scene_of = |names: &[&'static str]| {
+            let mut world = World::new();
+            world.insert_resource(registry.clone());
+            for name in names {
+                world.spawn((Name::new(*name), ComponentA { x: 0.0, y: 0.0 }));
+            }
+            DynamicScene::from_world(&world)
+        };
+
+        let parent = scene_of(&["Base"]);
+        let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+        let parent = scenes.add(parent);
+        let variant = scenes.add(DynamicScene {
+            parent: Some(parent.clone()),
+            ..scene_of(&["Added"])
+        });
+
+        let instance_id = app
+            .world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(variant.clone());
+        app.update();
+
+        let entities_by_name = |app: &mut App| {
+            let spawner = app.world().resource::<SceneSpawner>();
+            let mut entities: Vec<_> = spawner
+                .iter_instance_entities(instance_id)
+                .map(|entity| {
+                    let name = app.world().get::<Name>(entity).unwrap().to_string();
+                    (name, entity)
+                })
+                .collect();
+            entities.sort();
+            entities
+        };
+        let before = entities_by_name(&mut app);
+        assert_eq!(before.len(), 2);
+
+        // The parent gains an entity, which must not take over the variant's own entity.
+        let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+        *scenes.get_mut(&parent).unwrap() = scene_of(&["Base", "Extra"]);
+        app.update();
+        app.update();
+
+        let after = entities_by_name(&mut app);
+        assert_eq!(after.len(), 3);
+        for (name, entity) in &before {
+            assert!(
+                after.contains(&(name.clone(), *entity)),
+                "{name} was respawned"
+            );
+        }
+        assert!(after.iter().any(|(name, _)| name == "Extra"));
+    }
+
     #[derive(Component, Reflect, Default)]
     #[reflect(Component)]
     struct ComponentF;
@@ -844,6 +1122,88 @@ mod tests {
         observe_trigger(&mut app, scene_id, Some(scene_entity));
     }
 
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Velocity(f32);
+
+    #[test]
+    fn reconcile_updates_preserve_runtime_state() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<ComponentA>()
+            .register_type::<ComponentF>()
+            .register_type::<Velocity>();
+        app.world_mut()
+            .resource_mut::<SceneSpawner>()
+            .set_update_mode(SceneUpdateMode::Reconcile);
+
+        let mut scene_world = World::new();
+        scene_world.insert_resource(app.world().resource::<AppTypeRegistry>().clone());
+        let a = scene_world
+            .spawn((ComponentA { x: 1.0, y: 1.0 }, ComponentF))
+            .id();
+        let b = scene_world.spawn(Velocity(0.0)).id();
+        let scene = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(DynamicScene::from_world(&scene_world));
+
+        let instance_id = app
+            .world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(scene.clone());
+        app.update();
+
+        let instance = |app: &App, scene_entity: Entity| {
+            app.world().resource::<SceneSpawner>().spawned_instances[&instance_id].entity_map
+                [&scene_entity]
+        };
+        let instance_a = instance(&app, a);
+        let instance_b = instance(&app, b);
+
+        // Change the runtime state of the instance.
+        app.world_mut().entity_mut(instance_a).insert(Velocity(3.0));
+        app.world_mut().get_mut::<Velocity>(instance_b).unwrap().0 = 7.0;
+
+        // Modify `ComponentA` and remove `ComponentF` on `a`, and add a new entity to the scene.
+        scene_world.entity_mut(a).remove::<ComponentF>();
+        scene_world.get_mut::<ComponentA>(a).unwrap().x = 2.0;
+        let c = scene_world.spawn(ComponentF).id();
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(&scene, DynamicScene::from_world(&scene_world));
+        app.update();
+        app.update();
+
+        assert_eq!(instance(&app, a), instance_a);
+        assert_eq!(instance(&app, b), instance_b);
+        let world = app.world();
+        assert_eq!(world.get::<ComponentA>(instance_a).unwrap().x, 2.0);
+        assert!(world.get::<ComponentF>(instance_a).is_none());
+        assert_eq!(world.get::<Velocity>(instance_a), Some(&Velocity(3.0)));
+        // `b` did not change in the scene, so its runtime state is preserved.
+        assert_eq!(world.get::<Velocity>(instance_b), Some(&Velocity(7.0)));
+        assert!(world.get::<ComponentF>(instance(&app, c)).is_some());
+
+        // Remove `b` from the scene.
+        scene_world.despawn(b);
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(&scene, DynamicScene::from_world(&scene_world));
+        app.update();
+        app.update();
+
+        assert!(app.world().get_entity(instance_b).is_err());
+        assert!(app.world().get_entity(instance_a).is_ok());
+        assert_eq!(
+            app.world()
+                .resource::<SceneSpawner>()
+                .iter_instance_entities(instance_id)
+                .count(),
+            2
+        );
+    }
+
     #[test]
     fn despawn_scene() {
         let mut app = App::new();
@@ -1,8 +1,17 @@
 //! `serde` serialization and deserialization implementation for Bevy scenes.
 
-use crate::{DynamicEntity, DynamicScene};
-use bevy_ecs::entity::Entity;
-use bevy_platform::collections::HashSet;
+use crate::{
+    dynamic_scene::find_component,
+    reflect_utils::{clone_reflect_value, map_entity_references},
+    DynamicEntity, DynamicScene, EntityOverride, StableId,
+};
+use bevy_asset::Handle;
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap},
+    hierarchy::ChildOf,
+    name::Name,
+};
+use bevy_platform::collections::{HashMap, HashSet};
 use bevy_reflect::{
     serde::{
         ReflectDeserializer, TypeRegistrationDeserializer, TypedReflectDeserializer,
@@ -13,7 +22,7 @@ use bevy_reflect::{
 use core::fmt::Formatter;
 use serde::{
     de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
-    ser::{SerializeMap, SerializeStruct},
+    ser::{Error as _, SerializeMap, SerializeStruct},
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
@@ -23,12 +32,21 @@ pub const SCENE_STRUCT: &str = "Scene";
 pub const SCENE_RESOURCES: &str = "resources";
 /// Name of the serialized entities field in a scene struct.
 pub const SCENE_ENTITIES: &str = "entities";
+/// Name of the serialized parent scene field in a scene struct.
+pub const SCENE_PARENT: &str = "parent";
+/// Name of the serialized entity overrides field in a scene struct.
+pub const SCENE_OVERRIDES: &str = "overrides";
 
 /// Name of the serialized entity struct type.
 pub const ENTITY_STRUCT: &str = "Entity";
 /// Name of the serialized component field in an entity struct.
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 
+/// Name of the serialized entity override struct type.
+pub const OVERRIDE_STRUCT: &str = "EntityOverride";
+/// Name of the serialized removed components field in an entity override struct.
+pub const OVERRIDE_FIELD_REMOVED: &str = "removed";
+
 /// Serializer for a [`DynamicScene`].
 ///
 /// Helper object defining Bevy's serialize format for a [`DynamicScene`] and implementing
@@ -71,6 +89,45 @@ impl<'a> SceneSerializer<'a> {
     pub fn new(scene: &'a DynamicScene, registry: &'a TypeRegistry) -> Self {
         SceneSerializer { scene, registry }
     }
+
+    /// Returns a serializer writing the entities with stable keys, so that re-exporting the same
+    /// scene produces the same output. See [`StableSceneSerializer`].
+    pub fn with_stable_keys(self) -> StableSceneSerializer<'a> {
+        StableSceneSerializer(self)
+    }
+}
+
+/// Serializer for a [`DynamicScene`] keying its entities by strings that are stable across
+/// re-exports of the same scene, created with [`SceneSerializer::with_stable_keys`].
+///
+/// A [`SceneSerializer`] keys the entities by their [`Entity::to_bits`] identifier, which changes
+/// every time a scene is extracted from a world, so serializing the same scene twice yields
+/// different files. This serializer sorts the entities by key instead.
+///
+/// The key of an entity is its [`StableId`] if it has one. Otherwise, it is the `/`-separated path
+/// of its [`Name`] and the names of its ancestors within the scene, starting from the closest
+/// ancestor with a [`StableId`] or from the root. Unnamed entities are written as `_` in paths.
+/// When several entities share a key, the key of all but the first of them is suffixed with `#2`,
+/// `#3` and so on, in the order of the scene, skipping the suffixed keys that are already taken.
+///
+/// References to the entities of the scene within components and resources are replaced by
+/// identifiers derived from the keys, so that they don't change either.
+///
+/// This is only supported by human-readable formats.
+pub struct StableSceneSerializer<'a>(SceneSerializer<'a>);
+
+impl<'a> Serialize for StableSceneSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if !serializer.is_human_readable() {
+            return Err(S::Error::custom(
+                "stable entity keys can only be serialized to human-readable formats",
+            ));
+        }
+        serialize_scene(&self.0, true, serializer)
+    }
 }
 
 impl<'a> Serialize for SceneSerializer<'a> {
@@ -78,23 +135,81 @@ impl<'a> Serialize for SceneSerializer<'a> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct(SCENE_STRUCT, 2)?;
+        serialize_scene(self, false, serializer)
+    }
+}
+
+/// Serializes the scene of `scene_serializer`, keying the entities with stable keys if `stable_keys`
+/// is set.
+fn serialize_scene<S: Serializer>(
+    scene_serializer: &SceneSerializer,
+    stable_keys: bool,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let SceneSerializer { scene, registry } = *scene_serializer;
+    let parent = scene
+        .parent
+        .as_ref()
+        .map(|parent| {
+            parent.path().map(ToString::to_string).ok_or_else(|| {
+                S::Error::custom("the parent of a serialized scene must have an asset path")
+            })
+        })
+        .transpose()?;
+    let has_overrides = !scene.overrides.is_empty();
+    if (parent.is_some() || has_overrides) && !serializer.is_human_readable() {
+        return Err(S::Error::custom(
+            "scene inheritance can only be serialized to human-readable formats",
+        ));
+    }
+
+    let len = 2 + usize::from(parent.is_some()) + usize::from(has_overrides);
+    let mut state = serializer.serialize_struct(SCENE_STRUCT, len)?;
+    if let Some(parent) = &parent {
+        state.serialize_field(SCENE_PARENT, parent)?;
+    }
+    if stable_keys {
+        let (resources, entities) = stable_entities(scene, registry);
         state.serialize_field(
             SCENE_RESOURCES,
             &SceneMapSerializer {
-                entries: &self.scene.resources,
-                registry: self.registry,
+                entries: &resources,
+                registry,
+            },
+        )?;
+        state.serialize_field(
+            SCENE_ENTITIES,
+            &StableEntitiesSerializer {
+                entities: &entities,
+                registry,
+            },
+        )?;
+    } else {
+        state.serialize_field(
+            SCENE_RESOURCES,
+            &SceneMapSerializer {
+                entries: &scene.resources,
+                registry,
             },
         )?;
         state.serialize_field(
             SCENE_ENTITIES,
             &EntitiesSerializer {
-                entities: &self.scene.entities,
-                registry: self.registry,
+                entities: &scene.entities,
+                registry,
             },
         )?;
-        state.end()
     }
+    if has_overrides {
+        state.serialize_field(
+            SCENE_OVERRIDES,
+            &OverridesSerializer {
+                overrides: &scene.overrides,
+                registry,
+            },
+        )?;
+    }
+    state.end()
 }
 
 /// Handles serialization of multiple entities as a map of entity id to serialized entity.
@@ -124,6 +239,149 @@ impl<'a> Serialize for EntitiesSerializer<'a> {
     }
 }
 
+/// Handles serialization of entities as a map of stable key to serialized entity, see
+/// [`StableSceneSerializer`].
+struct StableEntitiesSerializer<'a> {
+    entities: &'a [(String, DynamicEntity)],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for StableEntitiesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.entities.len()))?;
+        for (key, entity) in self.entities {
+            state.serialize_entry(
+                key,
+                &EntitySerializer {
+                    entity,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Computes the [stable keys](StableSceneSerializer) of the entities of `scene`, and returns its
+/// resources and its entities sorted by key, with entity references replaced by stable identifiers.
+fn stable_entities(
+    scene: &DynamicScene,
+    registry: &TypeRegistry,
+) -> (Vec<Box<dyn PartialReflect>>, Vec<(String, DynamicEntity)>) {
+    let entities = &scene.entities;
+    let mut keys: Vec<(String, usize)> = (0..entities.len())
+        .map(|index| {
+            let mut segments = Vec::new();
+            let mut current = Some(&entities[index]);
+            while let Some(entity) = current {
+                if let Some(id) = find_component::<StableId>(entity) {
+                    segments.push(id.0);
+                    break;
+                }
+                segments.push(
+                    find_component::<Name>(entity)
+                        .map(|name| name.as_str().to_string())
+                        .unwrap_or_else(|| "_".to_string()),
+                );
+                // Guard against malformed hierarchies containing cycles.
+                if segments.len() > entities.len() {
+                    break;
+                }
+                current = find_component::<ChildOf>(entity)
+                    .and_then(|child_of| entities.iter().find(|e| e.entity == child_of.parent()));
+            }
+            segments.reverse();
+            (segments.join("/"), index)
+        })
+        .collect();
+
+    // Suffix the duplicate keys, without colliding with the names that already look suffixed.
+    let mut taken: HashSet<String> = HashSet::default();
+    let mut duplicates = Vec::new();
+    for (position, (key, _)) in keys.iter().enumerate() {
+        if !taken.insert(key.clone()) {
+            duplicates.push(position);
+        }
+    }
+    let mut counts: HashMap<String, usize> = HashMap::default();
+    for position in duplicates {
+        let key = &mut keys[position].0;
+        let count = counts.entry(key.clone()).or_insert(1);
+        let suffixed = loop {
+            *count += 1;
+            let suffixed = format!("{key}#{count}");
+            if !taken.contains(&suffixed) {
+                break suffixed;
+            }
+        };
+        taken.insert(suffixed.clone());
+        *key = suffixed;
+    }
+    keys.sort();
+
+    let mut used = HashSet::default();
+    let mut entity_map: EntityHashMap<Entity> = keys
+        .iter()
+        .map(|(key, index)| (entities[*index].entity, stable_entity(key, &mut used)))
+        .collect();
+    let resources = remap_values(&scene.resources, &mut entity_map, registry);
+    let entities = keys
+        .into_iter()
+        .map(|(key, index)| {
+            let entity = DynamicEntity {
+                entity: entity_map[&entities[index].entity],
+                components: remap_values(&entities[index].components, &mut entity_map, registry),
+            };
+            (key, entity)
+        })
+        .collect();
+    (resources, entities)
+}
+
+/// Clones `values`, mapping the entities they reference with `entity_map`.
+fn remap_values(
+    values: &[Box<dyn PartialReflect>],
+    entity_map: &mut EntityHashMap<Entity>,
+    registry: &TypeRegistry,
+) -> Vec<Box<dyn PartialReflect>> {
+    values
+        .iter()
+        .map(|value| {
+            let Some(registration) = value
+                .get_represented_type_info()
+                .and_then(|info| registry.get(info.type_id()))
+            else {
+                return value.to_dynamic();
+            };
+            let mut value = clone_reflect_value(value.as_partial_reflect(), registration);
+            map_entity_references(value.as_partial_reflect_mut(), registration, entity_map);
+            value
+        })
+        .collect()
+}
+
+/// Returns the identifier of the entity with the given [stable key](StableSceneSerializer), skipping
+/// the identifiers in `used`.
+///
+/// The identifier is derived from a FNV-1a hash of the key, so that it doesn't depend on the
+/// standard library or on the other entities of the scene, except for collisions.
+fn stable_entity(key: &str, used: &mut HashSet<Entity>) -> Entity {
+    let mut hash: u32 = 0x811c_9dc5;
+    for byte in key.bytes() {
+        hash ^= u32::from(byte);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    loop {
+        match Entity::from_raw_u32(hash) {
+            Some(entity) if used.insert(entity) => return entity,
+            _ => hash = hash.wrapping_add(1),
+        }
+    }
+}
+
 /// Handles entity serialization as a map of component type to component value.
 pub struct EntitySerializer<'a> {
     /// The entity to serialize.
@@ -149,6 +407,59 @@ impl<'a> Serialize for EntitySerializer<'a> {
     }
 }
 
+/// Handles serialization of scene overrides as a map of entity path to serialized override.
+pub struct OverridesSerializer<'a> {
+    /// The overrides to serialize.
+    pub overrides: &'a [EntityOverride],
+    /// Type registry in which the component types used by the overrides are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for OverridesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.overrides.len()))?;
+        for entity_override in self.overrides {
+            state.serialize_entry(
+                &entity_override.path,
+                &OverrideSerializer {
+                    entity_override,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Handles serialization of a single entity override.
+pub struct OverrideSerializer<'a> {
+    /// The override to serialize.
+    pub entity_override: &'a EntityOverride,
+    /// Type registry in which the component types used by the override are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for OverrideSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(OVERRIDE_STRUCT, 2)?;
+        state.serialize_field(
+            ENTITY_FIELD_COMPONENTS,
+            &SceneMapSerializer {
+                entries: &self.entity_override.components,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(OVERRIDE_FIELD_REMOVED, &self.entity_override.removed)?;
+        state.end()
+    }
+}
+
 /// Handles serializing a list of values with a unique type as a map of type to value.
 ///
 /// Used to serialize scene resources in [`SceneSerializer`] and entity components in [`EntitySerializer`].
@@ -197,8 +508,10 @@ impl<'a> Serialize for SceneMapSerializer<'a> {
 #[derive(Deserialize)]
 #[serde(field_identifier, rename_all = "lowercase")]
 enum SceneField {
+    Parent,
     Resources,
     Entities,
+    Overrides,
 }
 
 #[derive(Deserialize)]
@@ -207,10 +520,21 @@ enum EntityField {
     Components,
 }
 
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum OverrideField {
+    Components,
+    Removed,
+}
+
 /// Handles scene deserialization.
 pub struct SceneDeserializer<'a> {
     /// Type registry in which the components and resources types used in the scene to deserialize are registered.
     pub type_registry: &'a TypeRegistry,
+    /// Loads the parent of a scene variant, given its full asset path as written in the scene.
+    ///
+    /// Deserializing a scene with a parent fails if this is `None`.
+    pub load_parent: Option<&'a mut dyn FnMut(&str) -> Handle<DynamicScene>>,
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
@@ -225,6 +549,7 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
             &[SCENE_RESOURCES, SCENE_ENTITIES],
             SceneVisitor {
                 type_registry: self.type_registry,
+                load_parent: self.load_parent,
             },
         )
     }
@@ -232,6 +557,7 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
 
 struct SceneVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
+    pub load_parent: Option<&'a mut dyn FnMut(&str) -> Handle<DynamicScene>>,
 }
 
 impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
@@ -260,17 +586,39 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
         Ok(DynamicScene {
             resources,
             entities,
+            parent: None,
+            overrides: Vec::new(),
         })
     }
 
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: MapAccess<'de>,
     {
+        let mut parent = None;
         let mut resources = None;
         let mut entities = None;
+        let mut overrides = None;
         while let Some(key) = map.next_key()? {
             match key {
+                SceneField::Parent => {
+                    if parent.is_some() {
+                        return Err(Error::duplicate_field(SCENE_PARENT));
+                    }
+                    let path = map.next_value::<String>()?;
+                    let load_parent = self.load_parent.as_mut().ok_or_else(|| {
+                        Error::custom("scene has a parent, but no parent loader was provided")
+                    })?;
+                    parent = Some(load_parent(&path));
+                }
+                SceneField::Overrides => {
+                    if overrides.is_some() {
+                        return Err(Error::duplicate_field(SCENE_OVERRIDES));
+                    }
+                    overrides = Some(map.next_value_seed(SceneOverridesDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
                 SceneField::Resources => {
                     if resources.is_some() {
                         return Err(Error::duplicate_field(SCENE_RESOURCES));
@@ -296,6 +644,8 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
         Ok(DynamicScene {
             resources,
             entities,
+            parent,
+            overrides: overrides.unwrap_or_default(),
         })
     }
 }
@@ -335,7 +685,8 @@ impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
         A: MapAccess<'de>,
     {
         let mut entities = Vec::new();
-        while let Some(entity) = map.next_key::<Entity>()? {
+        let mut used = HashSet::default();
+        while let Some(entity) = map.next_key_seed(EntityKeyDeserializer { used: &mut used })? {
             let entity = map.next_value_seed(SceneEntityDeserializer {
                 entity,
                 type_registry: self.type_registry,
@@ -347,6 +698,59 @@ impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
     }
 }
 
+/// Deserializes an entity key, either an [`Entity::to_bits`] identifier or a
+/// [stable key](EntityKeys::Stable).
+struct EntityKeyDeserializer<'a> {
+    used: &'a mut HashSet<Entity>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EntityKeyDeserializer<'a> {
+    type Value = Entity;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(self)
+        } else {
+            Entity::deserialize(deserializer)
+        }
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for EntityKeyDeserializer<'a> {
+    type Value = Entity;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("entity identifier or stable key")
+    }
+
+    fn visit_u64<E>(self, bits: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Entity::try_from_bits(bits)
+            .ok_or_else(|| E::custom("Attempting to deserialize an invalid entity."))
+    }
+
+    fn visit_i64<E>(self, bits: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let bits = u64::try_from(bits)
+            .map_err(|_| E::custom("Attempting to deserialize an invalid entity."))?;
+        self.visit_u64(bits)
+    }
+
+    fn visit_str<E>(self, key: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(stable_entity(key, self.used))
+    }
+}
+
 /// Handle deserialization of an entity and its components.
 pub struct SceneEntityDeserializer<'a> {
     /// Id of the deserialized entity.
@@ -430,6 +834,124 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
     }
 }
 
+/// Handles deserialization for a collection of entity overrides.
+pub struct SceneOverridesDeserializer<'a> {
+    /// Type registry in which the component types used by the overrides to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneOverridesDeserializer<'a> {
+    type Value = Vec<EntityOverride>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(SceneOverridesVisitor {
+            type_registry: self.type_registry,
+        })
+    }
+}
+
+struct SceneOverridesVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneOverridesVisitor<'a> {
+    type Value = Vec<EntityOverride>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of entity overrides")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut overrides = Vec::new();
+        while let Some(path) = map.next_key::<String>()? {
+            let entity_override = map.next_value_seed(SceneOverrideDeserializer {
+                path,
+                type_registry: self.type_registry,
+            })?;
+            overrides.push(entity_override);
+        }
+
+        Ok(overrides)
+    }
+}
+
+/// Handles deserialization of a single entity override.
+pub struct SceneOverrideDeserializer<'a> {
+    /// Path of the overridden entity.
+    pub path: String,
+    /// Type registry in which the component types used by the override to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneOverrideDeserializer<'a> {
+    type Value = EntityOverride;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            OVERRIDE_STRUCT,
+            &[ENTITY_FIELD_COMPONENTS, OVERRIDE_FIELD_REMOVED],
+            SceneOverrideVisitor {
+                path: self.path,
+                registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct SceneOverrideVisitor<'a> {
+    pub path: String,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneOverrideVisitor<'a> {
+    type Value = EntityOverride;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("entity override")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut components = None;
+        let mut removed = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                OverrideField::Components => {
+                    if components.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
+                    }
+                    components = Some(map.next_value_seed(SceneMapDeserializer {
+                        registry: self.registry,
+                    })?);
+                }
+                OverrideField::Removed => {
+                    if removed.is_some() {
+                        return Err(Error::duplicate_field(OVERRIDE_FIELD_REMOVED));
+                    }
+                    removed = Some(map.next_value()?);
+                }
+            }
+        }
+
+        Ok(EntityOverride {
+            path: self.path,
+            components: components.unwrap_or_default(),
+            removed: removed.unwrap_or_default(),
+        })
+    }
+}
+
 /// Handles deserialization of a sequence of values with unique types.
 pub struct SceneMapDeserializer<'a> {
     /// Type registry in which the types of the values to deserialize are registered.
@@ -512,10 +1034,13 @@ mod tests {
     use crate::{
         ron,
         serde::{SceneDeserializer, SceneSerializer},
-        DynamicScene, DynamicSceneBuilder,
+        serialize_ron, DynamicScene, DynamicSceneBuilder, EntityOverride, StableId,
     };
+    use bevy_asset::Handle;
     use bevy_ecs::{
         entity::{Entity, EntityHashMap},
+        hierarchy::ChildOf,
+        name::Name,
         prelude::{Component, ReflectComponent, ReflectResource, Resource, World},
         query::{With, Without},
         reflect::AppTypeRegistry,
@@ -698,6 +1223,7 @@ mod tests {
         let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
         let scene_deserializer = SceneDeserializer {
             type_registry: &world.resource::<AppTypeRegistry>().read(),
+            load_parent: None,
         };
         let scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
 
@@ -726,6 +1252,233 @@ mod tests {
         assert_eq!(1, dst_world.query::<&Baz>().iter(&dst_world).count());
     }
 
+    #[test]
+    fn should_deserialize_variant() {
+        let world = create_world();
+
+        let input = r#"(
+  parent: "base.scn.ron",
+  resources: {},
+  entities: {},
+  overrides: {
+    "Enemy/Weapon": (
+      components: {
+        "bevy_scene::serde::tests::Foo": (200),
+      },
+      removed: [
+        "bevy_scene::serde::tests::Bar",
+      ],
+    ),
+  },
+)"#;
+        let mut parent_paths = Vec::new();
+        let mut load_parent = |path: &str| {
+            parent_paths.push(path.to_string());
+            Handle::default()
+        };
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &world.resource::<AppTypeRegistry>().read(),
+            load_parent: Some(&mut load_parent),
+        };
+        let scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(parent_paths, vec!["base.scn.ron".to_string()]);
+        assert!(scene.parent.is_some());
+        assert_eq!(scene.overrides.len(), 1);
+        assert_eq!(scene.overrides[0].path, "Enemy/Weapon");
+        assert_eq!(scene.overrides[0].components.len(), 1);
+        assert_eq!(
+            scene.overrides[0].removed,
+            vec!["bevy_scene::serde::tests::Bar"]
+        );
+
+        // Scenes with a parent can't be deserialized without a way to load it.
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &world.resource::<AppTypeRegistry>().read(),
+            load_parent: None,
+        };
+        assert!(scene_deserializer.deserialize(&mut deserializer).is_err());
+    }
+
+    #[test]
+    fn variant_in_subdirectory_round_trip() {
+        use crate::ScenePlugin;
+        use bevy_app::{App, TaskPoolPlugin};
+        use bevy_asset::{
+            io::{
+                memory::{Dir, MemoryAssetReader},
+                AssetSource, AssetSourceId,
+            },
+            AssetApp, AssetPath, AssetPlugin, AssetServer, Assets, LoadState,
+        };
+        use std::path::Path;
+
+        let dir = Dir::default();
+        let mut app = App::new();
+        let reader = MemoryAssetReader { root: dir.clone() };
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader(move || Box::new(reader.clone())),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .register_type::<Foo>();
+
+        let base = {
+            let mut world = World::new();
+            world.insert_resource(app.world().resource::<AppTypeRegistry>().clone());
+            world.spawn((Name::new("Enemy"), Foo(1)));
+            let scene = DynamicScene::from_world(&world);
+            scene
+                .serialize(&app.world().resource::<AppTypeRegistry>().read())
+                .unwrap()
+        };
+        dir.insert_asset_text(Path::new("scenes/base.scn.ron"), &base);
+
+        // The variant lives in another directory than its parent.
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let variant = DynamicScene {
+            parent: Some(asset_server.load("scenes/base.scn.ron")),
+            ..DynamicScene::default()
+        };
+        let variant = variant
+            .serialize(&app.world().resource::<AppTypeRegistry>().read())
+            .unwrap();
+        dir.insert_asset_text(Path::new("scenes/variants/variant.scn.ron"), &variant);
+
+        let handle = asset_server.load::<DynamicScene>("scenes/variants/variant.scn.ron");
+        for _ in 0..100 {
+            if asset_server.is_loaded_with_dependencies(&handle) {
+                break;
+            }
+            if let LoadState::Failed(err) = asset_server.load_state(&handle) {
+                panic!("failed to load the variant: {err}");
+            }
+            app.update();
+        }
+        assert!(asset_server.is_loaded_with_dependencies(&handle));
+
+        let scenes = app.world().resource::<Assets<DynamicScene>>();
+        let parent = scenes.get(&handle).unwrap().parent.as_ref().unwrap();
+        assert_eq!(parent.path(), Some(&AssetPath::from("scenes/base.scn.ron")));
+        assert_eq!(scenes.get(parent).unwrap().entities.len(), 1);
+    }
+
+    #[test]
+    fn should_serialize_stable_keys() {
+        fn create_world_with_hierarchy() -> World {
+            let world = create_world();
+            {
+                let mut registry = world.resource::<AppTypeRegistry>().write();
+                registry.register::<Name>();
+                registry.register::<ChildOf>();
+                registry.register::<StableId>();
+            }
+            world
+        }
+
+        fn export(shift: usize) -> String {
+            let mut world = create_world_with_hierarchy();
+            // Changes the identifiers of the exported entities.
+            for _ in 0..shift {
+                world.spawn_empty();
+            }
+            let door = world.spawn((Name::new("Door"), Foo(1))).id();
+            let house = world.spawn((StableId("house".into()), Foo(2))).id();
+            world.spawn((Name::new("Window"), Bar(3), ChildOf(house)));
+            world.spawn((Name::new("Window"), Bar(4), ChildOf(house)));
+            // Collides with the suffixed key of the second window.
+            world.spawn((Name::new("Window#2"), Bar(6), ChildOf(house)));
+            world.spawn((Baz(5), MyEntityRef(door)));
+
+            let scene = DynamicSceneBuilder::from_world(&world)
+                .extract_entities(world.iter_entities().map(|entity| entity.id()))
+                .remove_empty_entities()
+                .build();
+            let registry = world.resource::<AppTypeRegistry>().read();
+            serialize_ron(SceneSerializer::new(&scene, &registry).with_stable_keys()).unwrap()
+        }
+
+        let serialized = export(0);
+        assert_eq!(serialized, export(3));
+        let keys: Vec<&str> = serialized
+            .lines()
+            .filter(|line| line.starts_with("    \"") && line.ends_with(": ("))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                r#"    "Door": ("#,
+                r#"    "_": ("#,
+                r#"    "house": ("#,
+                r#"    "house/Window": ("#,
+                r#"    "house/Window#2": ("#,
+                r#"    "house/Window#3": ("#,
+            ]
+        );
+
+        let mut world = create_world_with_hierarchy();
+        let scene = {
+            let registry = world.resource::<AppTypeRegistry>().read();
+            let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+            SceneDeserializer {
+                type_registry: &registry,
+                load_parent: None,
+            }
+            .deserialize(&mut deserializer)
+            .unwrap()
+        };
+        scene
+            .write_to_world(&mut world, &mut EntityHashMap::default())
+            .unwrap();
+
+        let (door, _) = world
+            .query::<(Entity, &Name)>()
+            .iter(&world)
+            .find(|(_, name)| name.as_str() == "Door")
+            .unwrap();
+        let reference = world.query::<&MyEntityRef>().single(&world).unwrap();
+        assert_eq!(reference.0, door);
+    }
+
+    #[test]
+    fn should_serialize_overrides() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let scene = DynamicScene {
+            overrides: vec![EntityOverride::new("Enemy")
+                .with_component(Foo(200))
+                .without_component("Bar")],
+            ..Default::default()
+        };
+
+        let expected = r#"(
+  resources: {},
+  entities: {},
+  overrides: {
+    "Enemy": (
+      components: {
+        "bevy_scene::serde::tests::Foo": (200),
+      },
+      removed: [
+        "Bar",
+      ],
+    ),
+  },
+)"#;
+        let output = scene.serialize(&registry).unwrap();
+        assert_eq!(expected, output);
+
+        // Overrides are lost by non-self-describing formats, so they are rejected.
+        assert!(postcard::to_allocvec(&SceneSerializer::new(&scene, &registry)).is_err());
+    }
+
     fn roundtrip_ron(world: &World) -> (DynamicScene, DynamicScene) {
         let scene = DynamicScene::from_world(world);
         let registry = world.resource::<AppTypeRegistry>().read();
@@ -733,6 +1486,7 @@ mod tests {
         let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
         let scene_deserializer = SceneDeserializer {
             type_registry: &registry,
+            load_parent: None,
         };
         let deserialized_scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
         (scene, deserialized_scene)
@@ -825,6 +1579,7 @@ mod tests {
 
         let scene_deserializer = SceneDeserializer {
             type_registry: registry,
+            load_parent: None,
         };
         let deserialized_scene = scene_deserializer
             .deserialize(&mut postcard::Deserializer::from_bytes(&serialized_scene))
@@ -867,6 +1622,7 @@ mod tests {
 
         let scene_deserializer = SceneDeserializer {
             type_registry: registry,
+            load_parent: None,
         };
         let mut reader = BufReader::new(buf.as_slice());
 
@@ -912,6 +1668,7 @@ mod tests {
 
         let scene_deserializer = SceneDeserializer {
             type_registry: registry,
+            load_parent: None,
         };
 
         let (deserialized_scene, _read_bytes) =
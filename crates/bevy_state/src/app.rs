@@ -322,9 +322,31 @@ impl Plugin for StatesPlugin {
         schedule.insert_after(PreUpdate, StateTransition);
         schedule.insert_startup_before(PreStartup, StateTransition);
         setup_state_transitions_in_world(app.world_mut());
+        #[cfg(all(feature = "std", feature = "bevy_reflect"))]
+        app.add_crash_report_section("States", states_crash_report);
     }
 }
 
+/// Lists the current value of the states registered with
+/// [`register_type_state`](AppExtStates::register_type_state) in a crash report.
+#[cfg(all(feature = "std", feature = "bevy_reflect"))]
+fn states_crash_report(world: &bevy_ecs::world::World) -> Option<alloc::string::String> {
+    use alloc::{format, vec::Vec};
+
+    let registry = world
+        .get_resource::<bevy_ecs::reflect::AppTypeRegistry>()?
+        .read();
+    let states: Vec<_> = registry
+        .iter_with_data::<crate::reflect::ReflectState>()
+        .filter_map(|(registration, reflect_state)| {
+            let name = registration.type_info().type_path_table().short_path();
+            let value = reflect_state.reflect(world)?;
+            Some(format!("{name}: {value:?}"))
+        })
+        .collect();
+    (!states.is_empty()).then(|| states.join("\n"))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -384,4 +406,31 @@ mod tests {
         assert_eq!(last.exited, None);
         assert_eq!(last.entered, Some(TestState::C));
     }
+
+    #[cfg(all(feature = "std", feature = "bevy_reflect"))]
+    #[test]
+    fn states_crash_report_section() {
+        use bevy_reflect::Reflect;
+
+        #[derive(States, Default, PartialEq, Eq, Hash, Debug, Clone, Reflect)]
+        enum ReflectedState {
+            #[default]
+            Menu,
+            InGame,
+        }
+
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin);
+        assert_eq!(super::states_crash_report(app.world()), None);
+
+        app.insert_state(ReflectedState::InGame)
+            .register_type_state::<ReflectedState>()
+            .init_state::<TestState>();
+        app.update();
+
+        assert_eq!(
+            super::states_crash_report(app.world()).as_deref(),
+            Some("ReflectedState: InGame")
+        );
+    }
 }